@@ -116,12 +116,119 @@ pub enum ErrorCode {
     #[msg("unstaking fee lamports must be either 0 or greater than 890880")]
     InvalidUnstakingFee, //0x17a3
 
-    Reserved52,
-    Reserved53,
-    Reserved54,
-    Reserved55,
-    Reserved56,
-    Reserved57,
-    Reserved58,
-    Reserved59,
+    #[msg("farmer needs to be staked to perform this operation")]
+    FarmerNotStaked, //0x17a4
+
+    #[msg("can't partially unstake all gems, use unstake instead")]
+    CantPartiallyUnstakeAllGems, //0x17a5
+
+    #[msg("trying to withdraw more gems than are cooling down")]
+    NotEnoughGemsCoolingDown, //0x17a6
+
+    #[msg("flash withdrawals are only possible on farms with no cooldown period")]
+    CooldownPeriodNotZero, //0x17a7
+
+    #[msg("farmer needs to be cooling down to restake")]
+    FarmerNotCoolingDown, //0x17a8
+
+    #[msg("reward needs to be ended, unlocked and fully drained before it can be replaced")]
+    RewardNotDrained, //0x17a9
+
+    #[msg("account is already on the latest layout")]
+    NothingToMigrate, //0x17aa
+
+    #[msg("scheduled reward start can't be in the past")]
+    RewardStartInPast, //0x17ab
+
+    #[msg("emission curve is misconfigured (halving needs a period > 0)")]
+    InvalidEmissionCurve, //0x17ac
+
+    #[msg("this funding mode needs a running reward with a constant emission curve")]
+    InvalidFundingMode, //0x17ad
+
+    #[msg("loyalty boost tiers need ascending tenures and multipliers of at least 1x")]
+    InvalidLoyaltyBoost, //0x17ae
+
+    #[msg(
+        "loyalty boost / lock options can only be switched on or off while no farmers are staked"
+    )]
+    LoyaltyBoostLocked, //0x17af
+
+    #[msg("lock options need a lock period and multipliers of at least 1x (or the picked option doesn't exist)")]
+    InvalidLockOptions, //0x17b0
+
+    #[msg("a lock can only be picked when starting to stake")]
+    CantChangeLock, //0x17b1
+
+    #[msg("unstake penalty can't be over 100%")]
+    InvalidUnstakePenalty, //0x17b2
+
+    #[msg("rewards can't be claimed before the early unstake penalty tenure is reached")]
+    RewardsStillVesting, //0x17b3
+
+    #[msg("token fee accounts are missing or don't match the farm's fee mint / treasury")]
+    InvalidTokenFeeAccounts, //0x17b4
+
+    #[msg("this farm charges token fees, which this ix can't collect - use stake / unstake")]
+    TokenFeeNotSupported, //0x17b5
+
+    #[msg("treasury split needs 1 to 8 recipients, with shares adding up to 100%")]
+    InvalidTreasurySplit, //0x17b6
+
+    #[msg("recipient accounts don't match the treasury split")]
+    TreasuryRecipientMismatch, //0x17b7
+
+    #[msg("this funder's authorization has expired")]
+    FunderExpired, //0x17b8
+
+    #[msg("this funder isn't authorized to fund this reward")]
+    FunderNotAllowedReward, //0x17b9
+
+    #[msg("this would take the funder over their max funding amount")]
+    FundingCapExceeded, //0x17ba
+
+    #[msg("funders from the last cancelled round still have refunds to claim")]
+    RefundsPending, //0x17bb
+
+    #[msg("nothing to refund for this funder")]
+    NoRefundDue, //0x17bc
+
+    #[msg("this farm doesn't accept public top-ups")]
+    PublicTopUpsDisabled, //0x17bd
+
+    #[msg("reward pot holds less than the farm's books say it should")]
+    RewardPotDeficit, //0x17be
+
+    #[msg("this farm's config is timelocked, changes have to be queued")]
+    ConfigTimelocked, //0x17bf
+
+    #[msg("effective_ts has to be at least config_timelock_sec from now")]
+    InvalidEffectiveTs, //0x17c0
+
+    #[msg("the queued config change isn't effective yet")]
+    PendingConfigNotReady, //0x17c1
+
+    #[msg("managers have to be unique, with a threshold between 1 and their count")]
+    InvalidManagerSet, //0x17c2
+
+    #[msg("signer isn't one of the farm's managers")]
+    NotAManager, //0x17c3
+
+    #[msg("this action needs an approved proposal from the farm's managers")]
+    ProposalNotApproved, //0x17c4
+
+    #[msg("proposal is for a different action, already executed or from an older manager set")]
+    ProposalMismatch, //0x17c5
+
+    #[msg("a reward cap period needs a max per period")]
+    InvalidRewardCap, //0x17c6
+
+    #[msg("a fixed rate schedule can have at most 10 tiers")]
+    TooManyFixedRateTiers, //0x17c7
+
+    #[msg("fixed rate tiers must be ordered by required tenure, and the denominator can't be 0")]
+    InvalidFixedRateSchedule, //0x17c8
+
+    #[msg("some of this mint's gems are cooling down, withdraw those first")]
+    GemsCoolingDown, //0x17c9
 }
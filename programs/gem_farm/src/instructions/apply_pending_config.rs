@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_pending: u8)]
+pub struct ApplyPendingConfig<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    /// CHECK: only receives the pending config's rent back, hence permissionless
+    #[account(mut)]
+    pub farm_manager: AccountInfo<'info>,
+
+    // pending
+    #[account(mut, has_one = farm, close = farm_manager, seeds = [
+            b"pending_config".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump = bump_pending)]
+    pub pending_config: Box<Account<'info, PendingFarmConfig>>,
+}
+
+pub fn handler(ctx: Context<ApplyPendingConfig>) -> Result<()> {
+    let farm = &mut ctx.accounts.farm;
+
+    farm.apply_config_change(now_ts()?, &ctx.accounts.pending_config)?;
+
+    msg!("pending config applied");
+    Ok(())
+}
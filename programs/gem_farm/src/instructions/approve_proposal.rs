@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_manager_set: u8)]
+pub struct ApproveProposal<'info> {
+    // farm
+    pub farm: Box<Account<'info, Farm>>,
+
+    // managers
+    #[account(has_one = farm, seeds = [
+            b"manager_set".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump = bump_manager_set)]
+    pub manager_set: Box<Account<'info, ManagerSet>>,
+    #[account(mut, has_one = farm)]
+    pub proposal: Box<Account<'info, Proposal>>,
+    pub manager: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+
+    ctx.accounts
+        .manager_set
+        .approve(proposal, &ctx.accounts.manager.key())?;
+
+    msg!(
+        "proposal {} approved ({} so far)",
+        proposal.index,
+        proposal.approval_count()
+    );
+    Ok(())
+}
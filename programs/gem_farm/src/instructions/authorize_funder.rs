@@ -30,7 +30,13 @@ pub struct AuthorizeFunder<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<AuthorizeFunder>) -> Result<()> {
+/// calling this again for an already authorized funder updates their limits (0 = no limit)
+pub fn handler(
+    ctx: Context<AuthorizeFunder>,
+    max_amount: u64,
+    expires_ts: u64,
+    allowed_reward: FundableReward,
+) -> Result<()> {
     // fix missing discriminator check
     {
         let acct = ctx.accounts.authorization_proof.to_account_info();
@@ -44,14 +50,18 @@ pub fn handler(ctx: Context<AuthorizeFunder>) -> Result<()> {
 
     // create/update authorization proof
     let proof = &mut ctx.accounts.authorization_proof;
+    let newly_authorized = proof.authorized_funder == Pubkey::default();
 
     proof.authorized_funder = ctx.accounts.funder_to_authorize.key();
     proof.farm = ctx.accounts.farm.key();
+    proof.set_limits(max_amount, expires_ts, allowed_reward);
 
     // update farm
     let farm = &mut ctx.accounts.farm;
 
-    farm.authorized_funder_count.try_add_assign(1)?;
+    if newly_authorized {
+        farm.authorized_funder_count.try_add_assign(1)?;
+    }
 
     msg!(
         "funder authorized: {}",
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_pending: u8)]
+pub struct CancelPendingConfig<'info> {
+    // farm
+    #[account(has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+
+    // pending
+    #[account(mut, has_one = farm, close = farm_manager, seeds = [
+            b"pending_config".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump = bump_pending)]
+    pub pending_config: Box<Account<'info, PendingFarmConfig>>,
+}
+
+pub fn handler(_ctx: Context<CancelPendingConfig>) -> Result<()> {
+    msg!("pending config cancelled");
+    Ok(())
+}
@@ -5,7 +5,7 @@ use anchor_spl::{
 };
 use gem_common::*;
 
-use crate::state::*;
+use crate::{instructions::shared::consume_approval, state::*};
 
 #[derive(Accounts)]
 #[instruction(bump_auth: u8, bump_pot: u8)]
@@ -59,6 +59,15 @@ impl<'info> CancelReward<'info> {
 }
 
 pub fn handler(ctx: Context<CancelReward>) -> Result<()> {
+    consume_approval(
+        &ctx.accounts.farm,
+        ProposalAction::CancelReward {
+            reward_mint: ctx.accounts.reward_mint.key(),
+            receiver: ctx.accounts.receiver.key(),
+        },
+        ctx.remaining_accounts,
+    )?;
+
     // update existing rewards
     let farm = &mut ctx.accounts.farm;
     let now_ts = now_ts()?;
@@ -68,18 +77,26 @@ pub fn handler(ctx: Context<CancelReward>) -> Result<()> {
     // calculate cancellation amount while recording cancellation
     let cancel_amount = farm.cancel_reward_by_mint(now_ts, ctx.accounts.reward_mint.key())?;
 
+    // tracked contributions stay in the pot for their funders to claim (see claim_refund),
+    // only funding from before contributions were tracked goes to the receiver
+    let receiver_amount =
+        farm.close_funding_round_by_mint(ctx.accounts.reward_mint.key(), cancel_amount)?;
+
     // do the transfer
-    token::transfer(
-        ctx.accounts
-            .transfer_ctx()
-            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
-        cancel_amount,
-    )?;
+    if receiver_amount > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            receiver_amount,
+        )?;
+    }
 
     msg!(
-        "{} reward cancelled, {} tokens refunded",
+        "{} reward cancelled, {} tokens refunded ({} to the receiver, the rest to funders)",
         ctx.accounts.reward_mint.key(),
         cancel_amount,
+        receiver_amount,
     );
     Ok(())
 }
@@ -93,8 +93,10 @@ pub fn handler(ctx: Context<Claim>) -> Result<()> {
     // update accrued rewards before claiming
     let farm = &mut ctx.accounts.farm;
     let farmer = &mut ctx.accounts.farmer;
+    let now_ts = now_ts()?;
 
-    farm.update_rewards(now_ts()?, Some(farmer), true)?;
+    farm.assert_can_claim(now_ts, farmer)?;
+    farm.update_rewards(now_ts, Some(farmer), true)?;
 
     // calculate claimed amounts (capped at what's available in the pot)
     let to_claim_a = farmer
@@ -104,6 +106,9 @@ pub fn handler(ctx: Context<Claim>) -> Result<()> {
         .reward_b
         .claim_reward(ctx.accounts.reward_b_pot.amount)?;
 
+    farm.reward_a.record_payout(to_claim_a)?;
+    farm.reward_b.record_payout(to_claim_b)?;
+
     // do the transfers
     if to_claim_a > 0 {
         token::transfer(
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8, bump_contribution: u8, bump_pot: u8)]
+pub struct ClaimRefund<'info> {
+    // farm
+    #[account(mut, has_one = farm_authority)]
+    pub farm: Box<Account<'info, Farm>>,
+    /// CHECK:
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+
+    // funder
+    #[account(mut, has_one = farm, has_one = funder, has_one = reward_mint, seeds = [
+            b"contribution".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+            funder.key().as_ref(),
+        ],
+        bump = bump_contribution)]
+    pub funder_contribution: Box<Account<'info, FunderContribution>>,
+    /// CHECK: refunds can only ever go to the funder, hence permissionless
+    pub funder: AccountInfo<'info>,
+
+    // reward
+    #[account(mut, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_pot)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    #[account(init_if_needed,
+        associated_token::mint = reward_mint,
+        associated_token::authority = funder,
+        payer = payer)]
+    pub refund_destination: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    // misc
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimRefund<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reward_pot.to_account_info(),
+                to: self.refund_destination.to_account_info(),
+                authority: self.farm_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimRefund>) -> Result<()> {
+    let farm = &mut ctx.accounts.farm;
+    let contribution = &mut ctx.accounts.funder_contribution;
+
+    let refund = farm.claim_refund_by_mint(ctx.accounts.reward_mint.key(), contribution)?;
+
+    if refund > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            refund,
+        )?;
+    }
+
+    msg!(
+        "{} tokens refunded to funder {}",
+        refund,
+        ctx.accounts.funder.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8, bump_farmer: u8, bump_slot: u8, bump_farmer_slot: u8, bump_pot: u8)]
+pub struct ClaimRewardSlot<'info> {
+    // farm
+    #[account(has_one = farm_authority)]
+    pub farm: Box<Account<'info, Farm>>,
+    /// CHECK:
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+
+    // farmer
+    #[account(has_one = farm, has_one = identity, seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)] //payer
+    pub identity: Signer<'info>,
+
+    // reward
+    #[account(mut, has_one = farm, seeds = [
+            b"reward_slot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_slot)]
+    pub reward_slot: Box<Account<'info, FarmRewardSlot>>,
+    #[account(mut, has_one = farmer, has_one = reward_slot, seeds = [
+            b"farmer_reward_slot".as_ref(),
+            reward_slot.key().as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump = bump_farmer_slot)]
+    pub farmer_reward_slot: Box<Account<'info, FarmerRewardSlot>>,
+    #[account(mut, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_pot)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+    #[account(init_if_needed,
+        associated_token::mint = reward_mint,
+        associated_token::authority = identity,
+        payer = identity)]
+    pub reward_destination: Box<Account<'info, TokenAccount>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimRewardSlot<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reward_pot.to_account_info(),
+                to: self.reward_destination.to_account_info(),
+                authority: self.farm_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<ClaimRewardSlot>) -> Result<()> {
+    // update accrued rewards before claiming
+    let reward_slot = &mut ctx.accounts.reward_slot;
+    let farmer = &ctx.accounts.farmer;
+    let farmer_reward_slot = &mut ctx.accounts.farmer_reward_slot;
+
+    reward_slot.refresh_farmer(now_ts()?, farmer, farmer_reward_slot, true)?;
+
+    // calculate claimed amount (capped at what's available in the pot)
+    let to_claim = farmer_reward_slot
+        .reward
+        .claim_reward(ctx.accounts.reward_pot.amount)?;
+
+    reward_slot.reward.record_payout(to_claim)?;
+
+    // do the transfer
+    if to_claim > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            to_claim,
+        )?;
+    }
+
+    msg!(
+        "reward claimed ({} {})",
+        to_claim,
+        ctx.accounts.reward_mint.key()
+    );
+    Ok(())
+}
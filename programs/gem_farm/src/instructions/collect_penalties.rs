@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, Mint, Token, TokenAccount, Transfer},
+};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8, bump_treasury: u8, bump_pot: u8)]
+pub struct CollectPenalties<'info> {
+    // farm
+    #[account(mut, has_one = farm_authority, has_one = farm_treasury)]
+    pub farm: Box<Account<'info, Farm>>,
+    /// CHECK:
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+    /// CHECK:
+    #[account(seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // reward
+    #[account(mut, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_pot)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    // penalties can only ever go to the treasury, hence permissionless
+    #[account(init_if_needed,
+        associated_token::mint = reward_mint,
+        associated_token::authority = farm_treasury,
+        payer = payer)]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    // misc
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CollectPenalties<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reward_pot.to_account_info(),
+                to: self.treasury_token_account.to_account_info(),
+                authority: self.farm_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<CollectPenalties>) -> Result<()> {
+    let farm = &mut ctx.accounts.farm;
+
+    let amount = farm.collect_penalties_by_mint(ctx.accounts.reward_mint.key())?;
+
+    if amount > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            amount,
+        )?;
+    }
+
+    msg!(
+        "{} {} collected from penalties into treasury",
+        amount,
+        ctx.accounts.reward_mint.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke_signed, system_instruction},
+};
+use gem_common::errors::ErrorCode;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8)]
+pub struct DistributeTreasury<'info> {
+    // farm
+    #[account(has_one = farm_treasury)]
+    pub farm: Box<Account<'info, Farm>>,
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // split
+    #[account(has_one = farm, seeds = [
+            b"treasury_split".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump)]
+    pub treasury_split: Box<Account<'info, TreasurySplit>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+    //
+    // remaining accounts: every recipient's destination, in the same order as the split
+}
+
+impl<'info> DistributeTreasury<'info> {
+    fn payout_from_treasury(
+        &self,
+        bump_treasury: u8,
+        destination: &AccountInfo<'info>,
+        lamports: u64,
+    ) -> Result<()> {
+        invoke_signed(
+            &system_instruction::transfer(self.farm_treasury.key, destination.key, lamports),
+            &[
+                self.farm_treasury.to_account_info(),
+                destination.clone(),
+                self.system_program.to_account_info(),
+            ],
+            &[&[
+                b"treasury".as_ref(),
+                self.farm.key().as_ref(),
+                &[bump_treasury],
+            ]],
+        )
+        .map_err(Into::into)
+    }
+}
+
+/// permissionless - pays out the treasury's entire balance according to the split
+/// (all of it, not just what's above rent, as a partially drained treasury would no longer be rent exempt)
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DistributeTreasury<'info>>,
+    bump_treasury: u8,
+) -> Result<()> {
+    let split = &ctx.accounts.treasury_split;
+    let destinations = ctx.remaining_accounts;
+
+    if destinations.len() != split.recipient_count as usize {
+        return Err(error!(ErrorCode::TreasuryRecipientMismatch));
+    }
+
+    let balance = ctx.accounts.farm_treasury.lamports();
+    let payouts = split.payouts(balance)?;
+
+    for ((recipient, destination), lamports) in split
+        .recipients()
+        .iter()
+        .zip(destinations.iter())
+        .zip(payouts)
+    {
+        if destination.key() != recipient.destination {
+            return Err(error!(ErrorCode::TreasuryRecipientMismatch));
+        }
+
+        if lamports > 0 {
+            ctx.accounts
+                .payout_from_treasury(bump_treasury, destination, lamports)?;
+        }
+    }
+
+    msg!("{} lamports distributed from treasury", balance);
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use gem_bank::{
+    self,
+    cpi::accounts::{DepositGem, InitVault, SetVaultLock},
+    program::GemBank,
+    state::{Bank, Vault},
+};
+use gem_common::*;
+
+use crate::{
+    instructions::shared::{collect_token_fee, split_token_fee_accounts},
+    state::*,
+};
+
+#[derive(Accounts)]
+pub struct EnterFarm<'info> {
+    // farm
+    #[account(mut, has_one = farm_authority, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    //skipping seeds verification to save compute budget, has_one check above should be enough
+    /// CHECK:
+    pub farm_authority: AccountInfo<'info>,
+
+    // farmer
+    #[account(init, seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump,
+        payer = identity,
+        space = 8 + std::mem::size_of::<Farmer>())]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    // cpi
+    #[account(mut)]
+    pub bank: Box<Account<'info, Bank>>,
+    // trying to deserialize here leads to errors (doesn't exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+    /// CHECK:
+    pub vault_authority: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (doesn't exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub gem_box: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (doesn't exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub gem_deposit_receipt: AccountInfo<'info>,
+    #[account(mut)]
+    pub gem_source: Box<Account<'info, TokenAccount>>,
+    pub gem_mint: Box<Account<'info, Mint>>,
+    /// CHECK:
+    pub gem_rarity: AccountInfo<'info>,
+    pub gem_bank: Program<'info, GemBank>,
+
+    // misc
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK:
+    #[account(mut, address = protocol_config.fee_wallet)]
+    pub fee_acc: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    //
+    // remaining accounts could be passed, in this order:
+    // - fee_source, fee_treasury, token_program <- only if the farm charges a stake token fee (see collect_token_fee)
+    // - mint_whitelist_proof
+    // - gem_metadata <- if we got to this point we can assume gem = NFT, not a fungible token
+    // - creator_whitelist_proof
+}
+
+impl<'info> EnterFarm<'info> {
+    fn init_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, InitVault<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            InitVault {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.clone(),
+                // creator = the identity of the farmer
+                creator: self.identity.to_account_info(),
+                payer: self.identity.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+            },
+        )
+    }
+
+    fn deposit_gem_ctx(&self) -> CpiContext<'_, '_, '_, 'info, DepositGem<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            DepositGem {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.clone(),
+                owner: self.identity.to_account_info(),
+                authority: self.vault_authority.clone(),
+                gem_box: self.gem_box.clone(),
+                gem_deposit_receipt: self.gem_deposit_receipt.clone(),
+                gem_source: self.gem_source.to_account_info(),
+                gem_mint: self.gem_mint.to_account_info(),
+                gem_rarity: self.gem_rarity.clone(),
+                token_program: self.token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    fn set_lock_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetVaultLock<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            SetVaultLock {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.clone(),
+                bank_manager: self.farm_authority.clone(),
+            },
+        )
+    }
+
+    fn transfer_fee(&self) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(
+                self.identity.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.enter_farm,
+            ),
+            &[
+                self.identity.to_account_info(),
+                self.fee_acc.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+/// lock_option: index into the farm's lock_options, None for the farm's min_staking_period_sec
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, EnterFarm<'info>>,
+    bump_vault_auth: u8,
+    bump_rarity: u8,
+    amount: u64,
+    lock_option: Option<u8>,
+) -> Result<()> {
+    // the token fee accounts go first, the rest are for the deposit cpi
+    let (fee_accounts, deposit_accounts) = split_token_fee_accounts(
+        ctx.accounts.farm.token_fees.stake_fee,
+        ctx.remaining_accounts,
+    )?;
+
+    // record new farmer details
+    let farm_key = ctx.accounts.farm.key();
+    let identity = ctx.accounts.identity.key();
+    let vault = ctx.accounts.vault.key();
+    ctx.accounts.farmer.init(farm_key, identity, vault);
+
+    // start a new vault, deposit the gem and lock it
+    let vault_owner = ctx.accounts.identity.key();
+    let vault_name = String::from("farm_vault");
+
+    gem_bank::cpi::init_vault(ctx.accounts.init_vault_ctx(), vault_owner, vault_name)?;
+
+    gem_bank::cpi::deposit_gem(
+        ctx.accounts
+            .deposit_gem_ctx()
+            .with_remaining_accounts(deposit_accounts.to_vec()),
+        bump_vault_auth,
+        bump_rarity,
+        amount,
+    )?;
+
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        true,
+    )?;
+
+    // the vault only exists now, so we deserialize it after the cpis
+    let vault = Account::<Vault>::try_from(&ctx.accounts.vault)?;
+
+    // update accrued rewards and begin staking
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+
+    farm.enter_farm(
+        now_ts()?,
+        vault.gem_count,
+        vault.rarity_points,
+        farmer,
+        lock_option,
+    )?;
+
+    //collect a fee for starting a farm + staking
+    ctx.accounts.transfer_fee()?;
+    collect_token_fee(
+        &ctx.accounts.farm,
+        ctx.accounts.farm.token_fees.stake_fee,
+        ctx.accounts.identity.to_account_info(),
+        fee_accounts,
+    )?;
+
+    // msg!("new farmer {} staked {} gems", farmer.key(), farmer.gems_staked);
+    Ok(())
+}
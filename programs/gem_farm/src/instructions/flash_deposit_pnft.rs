@@ -1,5 +1,3 @@
-use std::str::FromStr;
-
 use anchor_lang::{
     prelude::*,
     solana_program::{program::invoke, system_instruction},
@@ -17,10 +15,7 @@ use gem_bank::{
 };
 use gem_common::*;
 
-use crate::{instructions::FEE_WALLET, state::*};
-
-const FEE_LAMPORTS: u64 = 2_000_000; // 0.002 SOL per stake/unstake
-const FD_FEE_LAMPORTS: u64 = 1_000_000; // half of that for FDs
+use crate::{instructions::shared::assert_no_token_fee, state::*};
 
 #[derive(Accounts)]
 #[instruction(bump_farmer: u8)]
@@ -67,8 +62,10 @@ pub struct FlashDepositPnft<'info> {
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     pub gem_bank: Program<'info, GemBank>,
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
+    #[account(mut, address = protocol_config.fee_wallet)]
     pub fee_acc: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     ///CHECK: downstream
@@ -194,14 +191,18 @@ pub fn handler<'a, 'b, 'c, 'info>(
 
     // in case the command is used BEFORE farmer staked
     if farmer.gems_staked == 0 {
+        // remaining accounts are taken by the deposit cpi
+        assert_no_token_fee(farm.token_fees.stake_fee)?;
         farm.begin_staking(
             now_ts,
             ctx.accounts.vault.gem_count,
             ctx.accounts.vault.rarity_points,
             farmer,
+            None,
         )?;
         //collect a fee for staking
-        ctx.accounts.transfer_fee(FEE_LAMPORTS)?;
+        ctx.accounts
+            .transfer_fee(ctx.accounts.protocol_config.fees.stake)?;
     } else {
         let extra_rarity = calc_rarity_points(&ctx.accounts.gem_rarity, amount)?;
         farm.stake_extra_gems(
@@ -213,7 +214,8 @@ pub fn handler<'a, 'b, 'c, 'info>(
             farmer,
         )?;
         //collect a fee for staking
-        ctx.accounts.transfer_fee(FD_FEE_LAMPORTS)?;
+        ctx.accounts
+            .transfer_fee(ctx.accounts.protocol_config.fees.flash_deposit)?;
     }
 
     // msg!("{} extra gems staked for {}", amount, farmer.key());
@@ -0,0 +1,258 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use gem_bank::{
+    self,
+    cpi::accounts::{ProgNftShared, SetVaultLock, WithdrawGemPnft},
+    instructions::calc_rarity_points,
+    program::GemBank,
+    state::{Bank, Vault},
+};
+use gem_common::*;
+
+use crate::{instructions::shared::assert_no_token_fee, state::*};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_farmer: u8)]
+pub struct FlashWithdrawPnft<'info> {
+    // farm
+    #[account(mut, has_one = farm_authority, has_one = farm_treasury, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    //skipping seeds verification to save compute budget, has_one check above should be enough
+    /// CHECK:
+    pub farm_authority: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    // cpi
+    pub bank: Box<Account<'info, Bank>>,
+    #[account(mut)]
+    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK:
+    pub vault_authority: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub gem_box: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub gem_deposit_receipt: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (might not exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub gem_destination: AccountInfo<'info>,
+    pub gem_mint: Box<Account<'info, Mint>>,
+    // might not exist (if none of this mint were ever partially unstaked)
+    /// CHECK:
+    #[account(seeds = [
+            b"cooling_gem".as_ref(),
+            farmer.key().as_ref(),
+            gem_mint.key().as_ref(),
+        ],
+        bump)]
+    pub cooling_gem: AccountInfo<'info>,
+    // verified downstream by gem_bank
+    /// CHECK:
+    pub gem_rarity: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    pub gem_bank: Program<'info, GemBank>,
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK:
+    #[account(mut, address = protocol_config.fee_wallet)]
+    pub fee_acc: AccountInfo<'info>,
+    ///CHECK: downstream
+    #[account(mut)]
+    pub gem_metadata: UncheckedAccount<'info>,
+    ///CHECK: downstream
+    pub gem_edition: UncheckedAccount<'info>,
+    ///CHECK: downstream
+    #[account(mut)]
+    pub owner_token_record: UncheckedAccount<'info>,
+    ///CHECK: downstream
+    #[account(mut)]
+    pub dest_token_record: UncheckedAccount<'info>,
+    ///CHECK: downstream
+    pub token_metadata_program: UncheckedAccount<'info>,
+    ///CHECK: downstream
+    pub instructions: UncheckedAccount<'info>,
+    ///CHECK: downstream
+    pub authorization_rules_program: UncheckedAccount<'info>,
+    //
+    // remaining accounts could be passed, in this order:
+    // - rules account
+}
+
+impl<'info> FlashWithdrawPnft<'info> {
+    fn set_lock_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetVaultLock<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            SetVaultLock {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                bank_manager: self.farm_authority.clone(),
+            },
+        )
+    }
+
+    fn withdraw_gem_ctx(&self) -> CpiContext<'_, '_, '_, 'info, WithdrawGemPnft<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            WithdrawGemPnft {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                owner: self.identity.to_account_info(),
+                authority: self.vault_authority.clone(),
+                gem_box: self.gem_box.clone(),
+                gem_deposit_receipt: self.gem_deposit_receipt.clone(),
+                gem_destination: self.gem_destination.clone(),
+                gem_mint: self.gem_mint.to_account_info(),
+                gem_rarity: self.gem_rarity.clone(),
+                receiver: self.identity.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+                gem_metadata: self.gem_metadata.to_account_info(),
+                gem_edition: self.gem_edition.to_account_info(),
+                owner_token_record: self.owner_token_record.to_account_info(),
+                dest_token_record: self.dest_token_record.to_account_info(),
+                pnft_shared: ProgNftShared {
+                    token_metadata_program: self.token_metadata_program.to_account_info(),
+                    instructions: self.instructions.to_account_info(),
+                    authorization_rules_program: self.authorization_rules_program.to_account_info(),
+                },
+            },
+        )
+    }
+
+    fn cooling_gem(&self) -> Result<Option<Account<'info, CoolingGem>>> {
+        if self.cooling_gem.data_is_empty() {
+            return Ok(None);
+        }
+        Account::try_from(&self.cooling_gem).map(Some)
+    }
+
+    fn pay_treasury(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.identity.key, self.farm_treasury.key, lamports),
+            &[
+                self.identity.to_account_info(),
+                self.farm_treasury.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+
+    fn transfer_fee(&self, fee: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.identity.key, self.fee_acc.key, fee),
+            &[
+                self.identity.to_account_info(),
+                self.fee_acc.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, FlashWithdrawPnft<'info>>,
+    bump_vault_auth: u8,
+    bump_gem_box: u8,
+    bump_gdr: u8,
+    bump_rarity: u8,
+    amount: u64,
+    rules_acc_present: bool,
+) -> Result<()> {
+    // collect any unstaking fee
+    let farm = &ctx.accounts.farm;
+
+    if farm.config.unstaking_fee_lamp > 0 {
+        ctx.accounts.pay_treasury(farm.config.unstaking_fee_lamp)?
+    }
+
+    // remaining accounts are taken by the withdrawal cpi
+    assert_no_token_fee(farm.token_fees.unstake_fee)?;
+
+    // gems of this mint that are cooling down can only come out through withdraw_unstaked_gem
+    let cooling_gem = ctx.accounts.cooling_gem()?;
+
+    // update accrued rewards BEFORE we decrement the stake
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, Some(farmer), true)?;
+
+    // gem_rarity is verified by gem_bank during the withdrawal cpi below
+    let rarity_points = calc_rarity_points(&ctx.accounts.gem_rarity, amount)?;
+    let fully_unstaked = farm.flash_unstake_gems(
+        now_ts,
+        amount,
+        rarity_points,
+        farmer,
+        cooling_gem.as_deref(),
+    )?;
+
+    // flash withdraw a gem from a locked vault
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        false,
+    )?;
+
+    gem_bank::cpi::withdraw_gem_pnft(
+        ctx.accounts
+            .withdraw_gem_ctx()
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        bump_vault_auth,
+        bump_gem_box,
+        bump_gdr,
+        bump_rarity,
+        amount,
+        None,
+        rules_acc_present,
+    )?;
+
+    // if that was the last staked gem, the vault stays unlocked, same as after a normal unstake
+    if !fully_unstaked {
+        gem_bank::cpi::set_vault_lock(
+            ctx.accounts
+                .set_lock_vault_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            true,
+        )?;
+    }
+
+    //collect a fee for unstaking
+    ctx.accounts
+        .transfer_fee(ctx.accounts.protocol_config.fees.unstake)?;
+
+    // msg!("{} gems flash withdrawn for {}", amount, farmer.key());
+    Ok(())
+}
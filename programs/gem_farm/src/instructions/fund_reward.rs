@@ -12,7 +12,7 @@ pub struct FundReward<'info> {
     pub farm: Box<Account<'info, Farm>>,
 
     // funder
-    #[account(has_one = farm, has_one = authorized_funder, seeds = [
+    #[account(mut, has_one = farm, has_one = authorized_funder, seeds = [
             b"authorization".as_ref(),
             farm.key().as_ref(),
             authorized_funder.key().as_ref(),
@@ -21,6 +21,16 @@ pub struct FundReward<'info> {
     pub authorization_proof: Box<Account<'info, AuthorizationProof>>,
     #[account(mut)]
     pub authorized_funder: Signer<'info>,
+    #[account(init_if_needed, seeds = [
+            b"contribution".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+            authorized_funder.key().as_ref(),
+        ],
+        bump,
+        payer = authorized_funder,
+        space = 8 + std::mem::size_of::<FunderContribution>())]
+    pub funder_contribution: Box<Account<'info, FunderContribution>>,
 
     // reward
     #[account(mut, seeds = [
@@ -76,6 +86,26 @@ pub fn handler(
         fixed_rate_config,
     )?;
 
+    // enforce + update the funder's limits (the mint is known to be a or b by now)
+    let funded_reward = if ctx.accounts.reward_mint.key() == farm.reward_a.reward_mint {
+        FundableReward::RewardA
+    } else {
+        FundableReward::RewardB
+    };
+
+    ctx.accounts
+        .authorization_proof
+        .record_funding(now_ts, funded_reward, amount)?;
+
+    // track the contribution, so a cancellation can refund it (see claim_refund)
+    let contribution = &mut ctx.accounts.funder_contribution;
+
+    contribution.farm = farm.key();
+    contribution.funder = ctx.accounts.authorized_funder.key();
+    contribution.reward_mint = ctx.accounts.reward_mint.key();
+
+    farm.record_contribution_by_mint(ctx.accounts.reward_mint.key(), contribution, amount)?;
+
     // do the transfer
     token::transfer(
         ctx.accounts
@@ -3,13 +3,9 @@ use anchor_lang::solana_program::{program::invoke, system_instruction};
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use gem_bank::{self, cpi::accounts::InitBank, program::GemBank};
 use gem_common::errors::ErrorCode;
-use std::str::FromStr;
 
 use crate::state::*;
 
-pub const FEE_WALLET: &str = "2xhBxVVuXkdq2MRKerE9mr2s1szfHSedy21MVqf8gPoM"; //5th
-const FEE_LAMPORTS: u64 = 2_500_000_000; // 2.5 SOL per farm
-
 #[derive(Accounts)]
 #[instruction(bump_auth: u8, bump_treasury: u8)]
 pub struct InitFarm<'info> {
@@ -55,8 +51,10 @@ pub struct InitFarm<'info> {
     // misc
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
+    #[account(mut, address = protocol_config.fee_wallet)]
     pub fee_acc: AccountInfo<'info>,
     pub rent: Sysvar<'info, Rent>,
     pub token_program: Program<'info, Token>,
@@ -81,7 +79,11 @@ impl<'info> InitFarm<'info> {
 
     fn transfer_fee(&self) -> Result<()> {
         invoke(
-            &system_instruction::transfer(self.payer.key, self.fee_acc.key, FEE_LAMPORTS),
+            &system_instruction::transfer(
+                self.payer.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.init_farm,
+            ),
             &[
                 self.payer.to_account_info(),
                 self.fee_acc.clone(),
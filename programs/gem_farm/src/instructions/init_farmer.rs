@@ -1,14 +1,10 @@
-use crate::instructions::FEE_WALLET;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction};
 use gem_bank::{self, cpi::accounts::InitVault, program::GemBank, state::Bank};
 use gem_common::*;
-use std::str::FromStr;
 
 use crate::state::*;
 
-const FEE_LAMPORTS: u64 = 10_000_000; // 0.01 SOL per farmer
-
 #[derive(Accounts)]
 pub struct InitFarmer<'info> {
     // farm
@@ -39,8 +35,10 @@ pub struct InitFarmer<'info> {
     // misc
     #[account(mut)]
     pub payer: Signer<'info>,
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
+    #[account(mut, address = protocol_config.fee_wallet)]
     pub fee_acc: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -62,7 +60,11 @@ impl<'info> InitFarmer<'info> {
 
     fn transfer_fee(&self) -> Result<()> {
         invoke(
-            &system_instruction::transfer(self.payer.key, self.fee_acc.key, FEE_LAMPORTS),
+            &system_instruction::transfer(
+                self.payer.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.init_farmer,
+            ),
             &[
                 self.payer.to_account_info(),
                 self.fee_acc.clone(),
@@ -75,13 +77,10 @@ impl<'info> InitFarmer<'info> {
 
 pub fn handler(ctx: Context<InitFarmer>) -> Result<()> {
     // record new farmer details
-    let farmer = &mut ctx.accounts.farmer;
-
-    farmer.farm = ctx.accounts.farm.key();
-    farmer.identity = ctx.accounts.identity.key();
-    farmer.vault = ctx.accounts.vault.key();
-    farmer.reward_a.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
-    farmer.reward_b.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
+    let farm_key = ctx.accounts.farm.key();
+    let identity = ctx.accounts.identity.key();
+    let vault = ctx.accounts.vault.key();
+    ctx.accounts.farmer.init(farm_key, identity, vault);
 
     // update farm
     let farm = &mut ctx.accounts.farm;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitFarmerRewardSlot<'info> {
+    // farm
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(has_one = farm)]
+    pub reward_slot: Box<Account<'info, FarmRewardSlot>>,
+
+    // farmer
+    #[account(has_one = farm)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(init, seeds = [
+            b"farmer_reward_slot".as_ref(),
+            reward_slot.key().as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump,
+        payer = payer,
+        space = 8 + std::mem::size_of::<FarmerRewardSlot>())]
+    pub farmer_reward_slot: Box<Account<'info, FarmerRewardSlot>>,
+
+    // misc
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitFarmerRewardSlot>) -> Result<()> {
+    // the snapshot starts at 0 - rarity points are picked up on the first refresh
+    let farmer_reward_slot = &mut ctx.accounts.farmer_reward_slot;
+
+    farmer_reward_slot.version = LATEST_REWARD_SLOT_VERSION;
+    farmer_reward_slot.farmer = ctx.accounts.farmer.key();
+    farmer_reward_slot.reward_slot = ctx.accounts.reward_slot.key();
+    farmer_reward_slot.reward.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
+
+    msg!("new farmer reward slot initialized");
+    Ok(())
+}
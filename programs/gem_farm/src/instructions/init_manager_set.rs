@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitManagerSet<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+
+    // managers
+    #[account(init, seeds = [
+            b"manager_set".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump,
+        payer = farm_manager,
+        space = 8 + std::mem::size_of::<ManagerSet>())]
+    pub manager_set: Box<Account<'info, ManagerSet>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+/// one way - from here on the managers change through proposals (see update_manager_set)
+pub fn handler(ctx: Context<InitManagerSet>, managers: Managers) -> Result<()> {
+    let manager_set = &mut ctx.accounts.manager_set;
+
+    manager_set.farm = ctx.accounts.farm.key();
+    manager_set.set_managers(managers)?;
+
+    ctx.accounts.farm.requires_approvals = true;
+
+    msg!(
+        "manager set initialized, {} of {}",
+        managers.threshold,
+        managers.member_count
+    );
+    Ok(())
+}
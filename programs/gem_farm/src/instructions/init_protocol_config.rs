@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{program::GemFarm, state::*};
+
+#[derive(Accounts)]
+pub struct InitProtocolConfig<'info> {
+    #[account(init, seeds = [b"protocol_config".as_ref()],
+        bump,
+        payer = upgrade_authority,
+        space = 8 + std::mem::size_of::<ProtocolConfig>())]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    // see ProtocolConfig::is_upgrade_authority
+    #[account(mut)]
+    pub upgrade_authority: Signer<'info>,
+    #[account(constraint = gem_farm.programdata_address()? == Some(program_data.key()))]
+    pub gem_farm: Program<'info, GemFarm>,
+    #[account(constraint = ProtocolConfig::is_upgrade_authority(&program_data, &upgrade_authority.key()))]
+    pub program_data: Account<'info, ProgramData>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitProtocolConfig>,
+    admin: Pubkey,
+    fee_wallet: Pubkey,
+    fees: ProtocolFees,
+) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+
+    config.version = LATEST_PROTOCOL_CONFIG_VERSION;
+    config.bump = *ctx.bumps.get("protocol_config").unwrap();
+    config.admin = admin;
+    config.fee_wallet = fee_wallet;
+    config.fees = fees;
+
+    msg!("protocol config initialized");
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8)]
+pub struct InitRewardSlot<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager, has_one = farm_authority)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+
+    // reward
+    #[account(init, seeds = [
+            b"reward_slot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump,
+        payer = farm_manager,
+        space = 8 + std::mem::size_of::<FarmRewardSlot>())]
+    pub reward_slot: Box<Account<'info, FarmRewardSlot>>,
+    // same seeds as reward a/b pots, so a mint can't be used twice on the same farm
+    #[account(init, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = reward_mint,
+        token::authority = farm_authority,
+        payer = farm_manager)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitRewardSlot>, reward_type: RewardType) -> Result<()> {
+    let reward_slot = &mut ctx.accounts.reward_slot;
+
+    reward_slot.version = LATEST_REWARD_SLOT_VERSION;
+    reward_slot.farm = ctx.accounts.farm.key();
+    reward_slot.reward.reward_mint = ctx.accounts.reward_mint.key();
+    reward_slot.reward.reward_pot = ctx.accounts.reward_pot.key();
+    reward_slot.reward.reward_type = reward_type;
+    reward_slot.reward.fixed_rate.schedule = FixedRateSchedule::default(); //denom to 1
+
+    // update farm
+    let farm = &mut ctx.accounts.farm;
+
+    farm.reward_slot_count.try_add_assign(1)?;
+
+    msg!("new reward slot initialized");
+    Ok(())
+}
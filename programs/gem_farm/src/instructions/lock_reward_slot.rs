@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Farm, FarmRewardSlot};
+
+#[derive(Accounts)]
+pub struct LockRewardSlot<'info> {
+    // farm
+    #[account(has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+
+    // reward
+    #[account(mut, has_one = farm)]
+    pub reward_slot: Box<Account<'info, FarmRewardSlot>>,
+}
+
+pub fn handler(ctx: Context<LockRewardSlot>) -> Result<()> {
+    let reward_slot = &mut ctx.accounts.reward_slot;
+
+    reward_slot.lock_reward()?;
+
+    Ok(())
+}
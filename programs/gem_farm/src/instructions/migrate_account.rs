@@ -0,0 +1,98 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+    Discriminator,
+};
+use gem_common::errors::ErrorCode;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: type is picked off the discriminator, layout off the data len (see handler)
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    // misc
+    /// covers the extra rent for the bigger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    fn top_up_rent(&self, new_len: usize) -> Result<()> {
+        let account = self.account.to_account_info();
+        let top_up = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(account.lamports());
+
+        if top_up > 0 {
+            invoke(
+                &system_instruction::transfer(self.payer.key, account.key, top_up),
+                &[
+                    self.payer.to_account_info(),
+                    account,
+                    self.system_program.to_account_info(),
+                ],
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// reads the old layout, converts it, then grows the account and writes the new layout
+    fn migrate<Old, T>(&self) -> Result<()>
+    where
+        Old: AnchorDeserialize,
+        T: AccountSerialize + From<Old>,
+    {
+        let account = self.account.to_account_info();
+
+        let migrated: T = {
+            let data = account.try_borrow_data()?;
+            Old::deserialize(&mut &data[8..])?.into()
+        };
+
+        let new_len = 8 + std::mem::size_of::<T>();
+        self.top_up_rent(new_len)?;
+        account.realloc(new_len, true)?;
+
+        let mut data = account.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        migrated.try_serialize(&mut writer)
+    }
+}
+
+/// permissionless - moves farms / farmers created on the originally deployed layout onto the current one
+/// (the layout is told apart by data len, see state/legacy.rs)
+/// until migrated, these accounts fail to deserialize in every other ix
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+
+    let (discriminator, data_len): ([u8; 8], usize) = {
+        let data = accounts.account.try_borrow_data()?;
+        if data.len() < 8 {
+            return Err(error!(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound
+            ));
+        }
+        (data[..8].try_into().unwrap(), data.len())
+    };
+
+    match (discriminator, data_len) {
+        (Farm::DISCRIMINATOR, FARM_V0_LEN) => accounts.migrate::<FarmV0, Farm>()?,
+        (Farmer::DISCRIMINATOR, FARMER_V0_LEN) => accounts.migrate::<FarmerV0, Farmer>()?,
+        (Farm::DISCRIMINATOR | Farmer::DISCRIMINATOR, _) => {
+            return Err(error!(ErrorCode::NothingToMigrate))
+        }
+        _ => {
+            return Err(error!(
+                anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+            ))
+        }
+    }
+
+    msg!("{} migrated", ctx.accounts.account.key());
+    Ok(())
+}
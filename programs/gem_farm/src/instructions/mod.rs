@@ -1,44 +1,122 @@
 pub mod add_rarities_to_bank;
 pub mod add_to_bank_whitelist;
+pub mod apply_pending_config;
+pub mod approve_proposal;
 pub mod authorize_funder;
+pub mod cancel_pending_config;
 pub mod cancel_reward;
+pub mod cancel_reward_slot;
 pub mod claim;
+pub mod claim_refund;
+pub mod claim_reward_slot;
+pub mod collect_penalties;
 pub mod deauthorize_funder;
+pub mod distribute_treasury;
+pub mod enter_farm;
+pub mod enter_farm_pnft;
 pub mod flash_deposit;
 pub mod flash_deposit_pnft;
+pub mod flash_withdraw;
+pub mod flash_withdraw_pnft;
 pub mod fund_reward;
+pub mod fund_reward_slot;
 pub mod init_farm;
 pub mod init_farmer;
+pub mod init_farmer_reward_slot;
+pub mod init_manager_set;
+pub mod init_protocol_config;
+pub mod init_reward_slot;
 pub mod lock_reward;
+pub mod lock_reward_slot;
+pub mod migrate_account;
+pub mod partial_unstake;
+pub mod propose;
+pub mod queue_config_change;
 pub mod refresh_farmer;
 pub mod refresh_farmer_signed;
+pub mod refresh_reward_slot;
 pub mod remove_from_bank_whitelist;
+pub mod replace_reward;
+pub mod restake;
+pub mod set_config_timelock;
+pub mod set_public_top_ups;
+pub mod set_reward_cap;
+pub mod set_token_fees;
+pub mod set_treasury_split;
+pub mod set_unstake_penalty;
+pub mod shared;
 pub mod stake;
+pub mod swap_gem;
+pub mod sync_reward_pot;
+pub mod top_up_reward;
 pub mod treasury_payout;
+pub mod treasury_token_payout;
 pub mod unstake;
 pub mod update_farm;
+pub mod update_manager_set;
+pub mod update_protocol_config;
+pub mod withdraw_unstaked_gem;
 
 pub use add_rarities_to_bank::*;
 pub use add_to_bank_whitelist::*;
 // have to duplicate or this won't show up in IDL
 use anchor_lang::prelude::*;
+pub use apply_pending_config::*;
+pub use approve_proposal::*;
 pub use authorize_funder::*;
+pub use cancel_pending_config::*;
 pub use cancel_reward::*;
+pub use cancel_reward_slot::*;
 pub use claim::*;
+pub use claim_refund::*;
+pub use claim_reward_slot::*;
+pub use collect_penalties::*;
 pub use deauthorize_funder::*;
+pub use distribute_treasury::*;
+pub use enter_farm::*;
+pub use enter_farm_pnft::*;
 pub use flash_deposit::*;
 pub use flash_deposit_pnft::*;
+pub use flash_withdraw::*;
+pub use flash_withdraw_pnft::*;
 pub use fund_reward::*;
+pub use fund_reward_slot::*;
 pub use init_farm::*;
 pub use init_farmer::*;
+pub use init_farmer_reward_slot::*;
+pub use init_manager_set::*;
+pub use init_protocol_config::*;
+pub use init_reward_slot::*;
 pub use lock_reward::*;
+pub use lock_reward_slot::*;
+pub use migrate_account::*;
+pub use partial_unstake::*;
+pub use propose::*;
+pub use queue_config_change::*;
 pub use refresh_farmer::*;
 pub use refresh_farmer_signed::*;
+pub use refresh_reward_slot::*;
 pub use remove_from_bank_whitelist::*;
+pub use replace_reward::*;
+pub use restake::*;
+pub use set_config_timelock::*;
+pub use set_public_top_ups::*;
+pub use set_reward_cap::*;
+pub use set_token_fees::*;
+pub use set_treasury_split::*;
+pub use set_unstake_penalty::*;
+pub use shared::*;
 pub use stake::*;
+pub use swap_gem::*;
+pub use sync_reward_pot::*;
+pub use top_up_reward::*;
 pub use treasury_payout::*;
+pub use treasury_token_payout::*;
 pub use unstake::*;
 pub use update_farm::*;
+pub use update_manager_set::*;
+pub use update_protocol_config::*;
+pub use withdraw_unstaked_gem::*;
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
 pub struct RarityConfig {
     pub mint: Pubkey,
@@ -0,0 +1,157 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use gem_bank::{
+    self,
+    instructions::deposit_gem::calc_rarity_points,
+    program::GemBank,
+    state::{Bank, GemDepositReceipt, Vault},
+};
+use gem_common::*;
+
+use crate::{instructions::shared::collect_token_fee, state::*};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_farmer: u8, bump_gdr: u8, bump_rarity: u8)]
+pub struct PartialUnstake<'info> {
+    // farm
+    #[account(mut, has_one = farm_treasury, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    // gem being unstaked
+    pub bank: Box<Account<'info, Bank>>,
+    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK:
+    pub gem_mint: AccountInfo<'info>,
+    #[account(has_one = vault, has_one = gem_mint, seeds = [
+            b"gem_deposit_receipt".as_ref(),
+            vault.key().as_ref(),
+            gem_mint.key().as_ref(),
+        ],
+        seeds::program = gem_bank.key(),
+        bump = bump_gdr)]
+    pub gem_deposit_receipt: Box<Account<'info, GemDepositReceipt>>,
+    /// CHECK:
+    #[account(seeds = [
+            b"gem_rarity".as_ref(),
+            bank.key().as_ref(),
+            gem_mint.key().as_ref()
+        ],
+        seeds::program = gem_bank.key(),
+        bump = bump_rarity)]
+    pub gem_rarity: AccountInfo<'info>,
+    #[account(init_if_needed, seeds = [
+            b"cooling_gem".as_ref(),
+            farmer.key().as_ref(),
+            gem_mint.key().as_ref(),
+        ],
+        bump,
+        payer = identity,
+        space = 8 + std::mem::size_of::<CoolingGem>())]
+    pub cooling_gem: Box<Account<'info, CoolingGem>>,
+    pub gem_bank: Program<'info, GemBank>,
+
+    //misc
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK:
+    #[account(mut, address = protocol_config.fee_wallet)]
+    pub fee_acc: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    //
+    // if the farm charges token fees, remaining accounts have to be passed (see collect_token_fee)
+}
+
+impl<'info> PartialUnstake<'info> {
+    fn pay_treasury(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.identity.key, self.farm_treasury.key, lamports),
+            &[
+                self.identity.to_account_info(),
+                self.farm_treasury.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+
+    fn transfer_fee(&self) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(
+                self.identity.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.unstake,
+            ),
+            &[
+                self.identity.to_account_info(),
+                self.fee_acc.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, PartialUnstake<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // collect any unstaking fee
+    let farm = &ctx.accounts.farm;
+
+    if farm.config.unstaking_fee_lamp > 0 {
+        ctx.accounts.pay_treasury(farm.config.unstaking_fee_lamp)?
+    }
+
+    collect_token_fee(
+        farm,
+        farm.token_fees.unstake_fee,
+        ctx.accounts.identity.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    // update accrued rewards BEFORE we decrement the stake
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, Some(farmer), true)?;
+
+    // move the selected gems into cooldown, the rest of the vault stays locked & staked
+    // the cooling gem record is so withdraw_unstaked_gem knows this particular gem went through cooldown
+    let rarity_points = calc_rarity_points(&ctx.accounts.gem_rarity, amount)?;
+    let cooling_gem = &mut ctx.accounts.cooling_gem;
+    cooling_gem.farmer = farmer.key();
+    cooling_gem.gem_mint = ctx.accounts.gem_mint.key();
+
+    farm.partial_unstake_gem(
+        now_ts,
+        ctx.accounts.gem_deposit_receipt.gem_count,
+        amount,
+        rarity_points,
+        farmer,
+        cooling_gem,
+    )?;
+
+    //collect a fee for unstaking
+    ctx.accounts.transfer_fee()?;
+
+    // msg!("{} gems unstaked for {}", amount, farmer.key());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_manager_set: u8)]
+pub struct Propose<'info> {
+    // farm
+    pub farm: Box<Account<'info, Farm>>,
+
+    // managers
+    #[account(mut, has_one = farm, seeds = [
+            b"manager_set".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump = bump_manager_set)]
+    pub manager_set: Box<Account<'info, ManagerSet>>,
+    #[account(init, seeds = [
+            b"proposal".as_ref(),
+            farm.key().as_ref(),
+            manager_set.proposal_count.to_le_bytes().as_ref(),
+        ],
+        bump,
+        payer = proposer,
+        space = 8 + std::mem::size_of::<Proposal>())]
+    pub proposal: Box<Account<'info, Proposal>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Propose>, action: ProposalAction) -> Result<()> {
+    let manager_set = &mut ctx.accounts.manager_set;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.farm = ctx.accounts.farm.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.index = manager_set.proposal_count;
+    proposal.generation = manager_set.generation;
+    proposal.action = action;
+
+    // proposing counts as approving
+    manager_set.approve(proposal, &ctx.accounts.proposer.key())?;
+    manager_set.proposal_count = manager_set.proposal_count.wrapping_add(1);
+
+    msg!("proposal {} created", proposal.index);
+    Ok(())
+}
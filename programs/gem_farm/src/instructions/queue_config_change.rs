@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    // farm
+    #[account(has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+
+    // pending
+    #[account(init_if_needed, seeds = [
+            b"pending_config".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump,
+        payer = farm_manager,
+        space = 8 + std::mem::size_of::<PendingFarmConfig>())]
+    pub pending_config: Box<Account<'info, PendingFarmConfig>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<QueueConfigChange>,
+    config: Option<FarmConfig>,
+    max_counts: Option<MaxCounts>,
+    config_timelock_sec: Option<u64>,
+    effective_ts: u64,
+) -> Result<()> {
+    let farm = &ctx.accounts.farm;
+    let pending_config = &mut ctx.accounts.pending_config;
+
+    pending_config.farm = farm.key();
+    farm.queue_config_change(
+        now_ts()?,
+        effective_ts,
+        config,
+        max_counts,
+        config_timelock_sec,
+        pending_config,
+    )?;
+
+    msg!("config change queued, effective at {}", effective_ts);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_farmer_slot: u8)]
+pub struct RefreshRewardSlot<'info> {
+    // reward
+    #[account(mut)]
+    pub reward_slot: Box<Account<'info, FarmRewardSlot>>,
+
+    // farmer
+    #[account(constraint = farmer.farm == reward_slot.farm)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut, has_one = farmer, has_one = reward_slot, seeds = [
+            b"farmer_reward_slot".as_ref(),
+            reward_slot.key().as_ref(),
+            farmer.key().as_ref(),
+        ],
+        bump = bump_farmer_slot)]
+    pub farmer_reward_slot: Box<Account<'info, FarmerRewardSlot>>,
+}
+
+/// permissionless - anyone can refresh any farmer's snapshot
+/// (keeps the slot's total rarity points honest when farmers forget to refresh after unstaking)
+pub fn handler(ctx: Context<RefreshRewardSlot>) -> Result<()> {
+    let reward_slot = &mut ctx.accounts.reward_slot;
+    let farmer = &ctx.accounts.farmer;
+    let farmer_reward_slot = &mut ctx.accounts.farmer_reward_slot;
+
+    reward_slot.refresh_farmer(now_ts()?, farmer, farmer_reward_slot, true)?;
+
+    msg!("{} reward slot refreshed", farmer.key());
+    Ok(())
+}
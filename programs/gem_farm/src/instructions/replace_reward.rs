@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer},
+};
+use gem_common::*;
+
+use crate::{instructions::shared::consume_approval, state::*};
+
+#[derive(Accounts)]
+#[instruction(bump_auth: u8, bump_old_pot: u8)]
+pub struct ReplaceReward<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager, has_one = farm_authority)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [farm.key().as_ref()], bump = bump_auth)]
+    pub farm_authority: AccountInfo<'info>,
+
+    // old reward
+    #[account(mut, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            old_reward_mint.key().as_ref(),
+        ],
+        bump = bump_old_pot)]
+    pub old_reward_pot: Box<Account<'info, TokenAccount>>,
+    pub old_reward_mint: Box<Account<'info, Mint>>,
+    #[account(init_if_needed,
+        associated_token::mint = old_reward_mint,
+        associated_token::authority = receiver,
+        payer = farm_manager)]
+    pub old_reward_destination: Box<Account<'info, TokenAccount>>,
+    // whatever untracked surplus is left in the old pot can be sent anywhere
+    /// CHECK:
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    // new reward
+    #[account(init, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            new_reward_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = new_reward_mint,
+        token::authority = farm_authority,
+        payer = farm_manager)]
+    pub new_reward_pot: Box<Account<'info, TokenAccount>>,
+    pub new_reward_mint: Box<Account<'info, Mint>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ReplaceReward<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.old_reward_pot.to_account_info(),
+                to: self.old_reward_destination.to_account_info(),
+                authority: self.farm_authority.to_account_info(),
+            },
+        )
+    }
+
+    fn close_ctx(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.old_reward_pot.to_account_info(),
+                destination: self.farm_manager.to_account_info(),
+                authority: self.farm_authority.to_account_info(),
+            },
+        )
+    }
+}
+
+/// farmers have to have claimed everything first (see FarmReward::replace_reward),
+/// only untracked surplus is swept - any rounding dust stays in the old pot
+pub fn handler(ctx: Context<ReplaceReward>, new_reward_type: RewardType) -> Result<()> {
+    consume_approval(
+        &ctx.accounts.farm,
+        ProposalAction::ReplaceReward {
+            old_reward_mint: ctx.accounts.old_reward_mint.key(),
+            new_reward_mint: ctx.accounts.new_reward_mint.key(),
+            receiver: ctx.accounts.receiver.key(),
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    // settle the old reward one last time, then swap it out
+    let farm = &mut ctx.accounts.farm;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, None, true)?;
+
+    let pot_balance = ctx.accounts.old_reward_pot.amount;
+    let surplus = farm.replace_reward_by_mint(
+        now_ts,
+        ctx.accounts.old_reward_mint.key(),
+        pot_balance,
+        ctx.accounts.new_reward_mint.key(),
+        ctx.accounts.new_reward_pot.key(),
+        new_reward_type,
+    )?;
+
+    if surplus > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+            surplus,
+        )?;
+    }
+
+    // only an empty pot can be closed
+    if pot_balance == surplus {
+        token::close_account(
+            ctx.accounts
+                .close_ctx()
+                .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        )?;
+    }
+
+    msg!(
+        "{} reward replaced by {}, {} tokens swept",
+        ctx.accounts.old_reward_mint.key(),
+        ctx.accounts.new_reward_mint.key(),
+        surplus,
+    );
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction;
+use gem_bank::state::Vault;
+use gem_common::*;
+
+use crate::{instructions::shared::collect_token_fee, state::*};
+
+#[derive(Accounts)]
+#[instruction(bump_farmer: u8)]
+pub struct Restake<'info> {
+    // farm
+    #[account(mut)]
+    pub farm: Box<Account<'info, Farm>>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    // vault is still locked from when the farmer was staked, so no cpi needed
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK:
+    #[account(mut, address = protocol_config.fee_wallet)]
+    pub fee_acc: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    //
+    // if the farm charges token fees, remaining accounts have to be passed (see collect_token_fee)
+}
+
+impl<'info> Restake<'info> {
+    fn transfer_fee(&self) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(
+                self.identity.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.stake,
+            ),
+            &[
+                self.identity.to_account_info(),
+                self.fee_acc.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+pub fn handler<'a, 'b, 'c, 'info>(ctx: Context<'a, 'b, 'c, 'info, Restake<'info>>) -> Result<()> {
+    // update accrued rewards BEFORE we increment the stake
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+    let vault = &ctx.accounts.vault;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, Some(farmer), true)?;
+
+    // cancel the cooldown and go back to staking
+    farm.restake(now_ts, vault.gem_count, vault.rarity_points, farmer)?;
+
+    //collect a fee for staking
+    ctx.accounts.transfer_fee()?;
+    collect_token_fee(
+        &ctx.accounts.farm,
+        ctx.accounts.farm.token_fees.stake_fee,
+        ctx.accounts.identity.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
+
+    // msg!("{} gems restaked by {}", farmer.gems_staked, farmer.key());
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetConfigTimelock<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetConfigTimelock>, config_timelock_sec: u64) -> Result<()> {
+    ctx.accounts
+        .farm
+        .raise_config_timelock(config_timelock_sec)?;
+
+    msg!("config timelock set to {}s", config_timelock_sec);
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetPublicTopUps<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPublicTopUps>, allow_public_top_ups: bool) -> Result<()> {
+    ctx.accounts.farm.allow_public_top_ups = allow_public_top_ups;
+
+    msg!("public top-ups allowed: {}", allow_public_top_ups);
+    Ok(())
+}
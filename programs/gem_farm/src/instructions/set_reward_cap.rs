@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetRewardCap<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+
+    // reward
+    pub reward_mint: Box<Account<'info, Mint>>,
+}
+
+pub fn handler(ctx: Context<SetRewardCap>, cap: RewardCap) -> Result<()> {
+    let farm = &mut ctx.accounts.farm;
+    let now_ts = now_ts()?;
+
+    // settle the farm-level reward first, farmers are capped from their next refresh
+    farm.update_rewards(now_ts, None, true)?;
+
+    farm.set_reward_cap_by_mint(now_ts, ctx.accounts.reward_mint.key(), cap)?;
+
+    msg!(
+        "{} reward capped at {:?}",
+        ctx.accounts.reward_mint.key(),
+        cap
+    );
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8)]
+pub struct SetTokenFees<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager, has_one = farm_treasury)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // fees
+    // created here so farmers never have to pay for it
+    #[account(init_if_needed,
+        associated_token::mint = fee_mint,
+        associated_token::authority = farm_treasury,
+        payer = farm_manager)]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    pub fee_mint: Box<Account<'info, Mint>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// zero fees turn token fees off
+pub fn handler(ctx: Context<SetTokenFees>, stake_fee: u64, unstake_fee: u64) -> Result<()> {
+    let farm = &mut ctx.accounts.farm;
+
+    // see update_farm
+    farm.assert_config_not_timelocked()?;
+
+    farm.token_fees = TokenFees {
+        fee_mint: ctx.accounts.fee_mint.key(),
+        stake_fee,
+        unstake_fee,
+    };
+
+    msg!("token fees set to {:?}", farm.token_fees);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use anchor_lang::solana_program::sysvar;
+    use anchor_spl::associated_token::get_associated_token_address;
+    use gem_common::errors::ErrorCode;
+
+    use super::*;
+    use crate::instructions::shared::tests::{record_cpis, TestAccount};
+
+    fn rent_sysvar() -> TestAccount {
+        let rent = Rent::default();
+        let mut data = vec![];
+        data.extend(rent.lamports_per_byte_year.to_le_bytes());
+        data.extend(rent.exemption_threshold.to_le_bytes());
+        data.push(rent.burn_percent);
+        TestAccount::new(sysvar::rent::ID, sysvar::ID, data)
+    }
+
+    #[test]
+    fn test_set_token_fees() {
+        let farm_key = Pubkey::new_unique();
+        let manager = Pubkey::new_unique();
+        let fee_mint = Pubkey::new_unique();
+        let (treasury, bump_treasury) =
+            Pubkey::find_program_address(&[b"treasury", farm_key.as_ref()], &crate::ID);
+        let treasury_ata = get_associated_token_address(&treasury, &fee_mint);
+
+        let mut farm = Farm::deserialize(&mut &[0; 2048][..]).unwrap();
+        farm.farm_manager = manager;
+        farm.farm_treasury = treasury;
+
+        let set_fees = |farm: &Farm, signer: Pubkey, stake_fee: u64, unstake_fee: u64| {
+            let mut accounts = [
+                TestAccount::anchor(farm_key, farm),
+                TestAccount::signer(signer),
+                TestAccount::new(treasury, System::id(), vec![]),
+                // already created, so nothing to init
+                TestAccount::token_account(treasury_ata, fee_mint, treasury, 0),
+                TestAccount::mint(fee_mint),
+                TestAccount::program(Token::id()),
+                TestAccount::program(AssociatedToken::id()),
+                TestAccount::program(System::id()),
+                rent_sysvar(),
+            ];
+            let infos: Vec<AccountInfo> = accounts.iter_mut().map(|a| a.info()).collect();
+
+            let (result, cpis) = record_cpis(|| {
+                let mut ctx_accounts = SetTokenFees::try_accounts(
+                    &crate::ID,
+                    &mut &infos[..],
+                    &[bump_treasury],
+                    &mut BTreeMap::new(),
+                    &mut BTreeSet::new(),
+                )?;
+                handler(
+                    Context::new(&crate::ID, &mut ctx_accounts, &[], BTreeMap::new()),
+                    stake_fee,
+                    unstake_fee,
+                )?;
+                Ok(ctx_accounts.farm.token_fees)
+            });
+            assert!(cpis.is_empty());
+            result
+        };
+
+        // only the manager
+        assert!(set_fees(&farm, Pubkey::new_unique(), 10, 20).is_err());
+
+        let fees: Result<TokenFees> = set_fees(&farm, manager, 10, 20);
+        assert_eq!(
+            fees.unwrap(),
+            TokenFees {
+                fee_mint,
+                stake_fee: 10,
+                unstake_fee: 20,
+            }
+        );
+
+        // stakers are promised a heads up on any changes
+        farm.raise_config_timelock(100).unwrap();
+        assert_eq!(
+            set_fees(&farm, manager, 10, 20).unwrap_err(),
+            error!(ErrorCode::ConfigTimelocked)
+        );
+    }
+}
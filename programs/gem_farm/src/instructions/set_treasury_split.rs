@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{instructions::shared::consume_approval, state::*};
+
+#[derive(Accounts)]
+pub struct SetTreasurySplit<'info> {
+    // farm
+    #[account(has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    #[account(mut)]
+    pub farm_manager: Signer<'info>,
+
+    // split
+    #[account(init_if_needed, seeds = [
+            b"treasury_split".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump,
+        payer = farm_manager,
+        space = 8 + std::mem::size_of::<TreasurySplit>())]
+    pub treasury_split: Box<Account<'info, TreasurySplit>>,
+
+    // misc
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetTreasurySplit>, recipients: Vec<TreasuryRecipient>) -> Result<()> {
+    consume_approval(
+        &ctx.accounts.farm,
+        ProposalAction::set_treasury_split(&recipients)?,
+        ctx.remaining_accounts,
+    )?;
+
+    let split = &mut ctx.accounts.treasury_split;
+
+    split.version = LATEST_TREASURY_SPLIT_VERSION;
+    split.farm = ctx.accounts.farm.key();
+    split.set_recipients(&recipients)?;
+
+    msg!("treasury split set across {} recipients", recipients.len());
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetUnstakePenalty<'info> {
+    // farm
+    #[account(mut, has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetUnstakePenalty>, penalty: EarlyUnstakePenalty) -> Result<()> {
+    // see update_farm
+    ctx.accounts.farm.assert_config_not_timelocked()?;
+
+    // only affects farmers who unstake from now on, nothing already forfeited is given back
+    ctx.accounts.farm.set_early_unstake_penalty(penalty)?;
+
+    msg!("early unstake penalty set to {:?}", penalty);
+    Ok(())
+}
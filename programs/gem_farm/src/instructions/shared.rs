@@ -0,0 +1,356 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::{self, Token, Transfer},
+};
+use gem_common::errors::ErrorCode;
+
+use crate::state::*;
+
+/// token fees (see TokenFees) are paid through remaining accounts, in this order:
+/// - fee_source: the payer's token account for the fee mint
+/// - fee_treasury: the farm treasury's associated token account for the fee mint
+/// - token_program
+pub fn collect_token_fee<'info>(
+    farm: &Farm,
+    fee: u64,
+    payer: AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    if fee == 0 {
+        return Ok(());
+    }
+
+    let (fee_source, fee_treasury, token_program) = match remaining_accounts {
+        [fee_source, fee_treasury, token_program, ..] => (fee_source, fee_treasury, token_program),
+        _ => return Err(error!(ErrorCode::InvalidTokenFeeAccounts)),
+    };
+
+    // the token program makes sure fee_source holds the same mint
+    let treasury_ata = get_associated_token_address(&farm.farm_treasury, &farm.token_fees.fee_mint);
+    if fee_treasury.key() != treasury_ata || token_program.key() != Token::id() {
+        return Err(error!(ErrorCode::InvalidTokenFeeAccounts));
+    }
+
+    token::transfer(
+        CpiContext::new(
+            token_program.clone(),
+            Transfer {
+                from: fee_source.clone(),
+                to: fee_treasury.clone(),
+                authority: payer,
+            },
+        ),
+        fee,
+    )
+}
+
+/// for ixs whose remaining accounts also go to the bank: if there's a fee to pay, the fee accounts
+/// (see collect_token_fee) come first, and everything after them is left for the bank
+pub fn split_token_fee_accounts<'a, 'info>(
+    fee: u64,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    if fee == 0 {
+        return Ok((&[], remaining_accounts));
+    }
+
+    if remaining_accounts.len() < 3 {
+        return Err(error!(ErrorCode::InvalidTokenFeeAccounts));
+    }
+    Ok(remaining_accounts.split_at(3))
+}
+
+/// for ixs whose remaining accounts already go to the bank - better to fail than to skip the fee
+pub fn assert_no_token_fee(fee: u64) -> Result<()> {
+    if fee > 0 {
+        return Err(error!(ErrorCode::TokenFeeNotSupported));
+    }
+    Ok(())
+}
+
+/// farms with a manager set (see ManagerSet) need an approved proposal for exactly this action,
+/// passed through remaining accounts, in this order:
+/// - manager_set: the farm's ManagerSet
+/// - proposal: the approved Proposal, marked executed here so it can't be replayed
+pub fn consume_approval(
+    farm: &Account<Farm>,
+    action: ProposalAction,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if !farm.requires_approvals {
+        return Ok(());
+    }
+
+    let (manager_set_info, proposal_info) = match remaining_accounts {
+        [manager_set_info, proposal_info, ..] => (manager_set_info, proposal_info),
+        _ => return Err(error!(ErrorCode::ProposalNotApproved)),
+    };
+
+    if manager_set_info.owner != &crate::id()
+        || proposal_info.owner != &crate::id()
+        || !proposal_info.is_writable
+    {
+        return Err(error!(ErrorCode::ProposalNotApproved));
+    }
+
+    // only ever one manager set per farm (it's a PDA), so checking its farm is enough
+    let manager_set = ManagerSet::try_deserialize(&mut &manager_set_info.try_borrow_data()?[..])?;
+    if manager_set.farm != farm.key() {
+        return Err(error!(ErrorCode::ProposalMismatch));
+    }
+
+    let mut proposal = Proposal::try_deserialize(&mut &proposal_info.try_borrow_data()?[..])?;
+    manager_set.execute(&mut proposal, farm.key(), &action)?;
+    proposal.try_serialize(&mut &mut proposal_info.try_borrow_mut_data()?[..])
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::Mutex;
+
+    use anchor_lang::solana_program::{
+        entrypoint::ProgramResult, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+        program_pack::Pack, program_stubs::SyscallStubs,
+    };
+    use anchor_spl::token::spl_token;
+
+    use super::*;
+
+    /// a cpi, as the program would have made it on-chain
+    #[derive(Debug)]
+    pub(crate) struct RecordedCpi {
+        pub instruction: Instruction,
+        pub signer_seeds: Vec<Vec<Vec<u8>>>,
+    }
+
+    static CPIS: Mutex<Vec<RecordedCpi>> = Mutex::new(vec![]);
+    static STUBS_IN_USE: Mutex<()> = Mutex::new(());
+
+    struct RecordingStubs;
+
+    impl SyscallStubs for RecordingStubs {
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            _account_infos: &[AccountInfo],
+            signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            CPIS.lock().unwrap().push(RecordedCpi {
+                instruction: instruction.clone(),
+                signer_seeds: signers_seeds
+                    .iter()
+                    .map(|seeds| seeds.iter().map(|seed| seed.to_vec()).collect())
+                    .collect(),
+            });
+            Ok(())
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+    }
+
+    /// runs f, returning whatever cpis it made instead of executing them (the rent sysvar is available too)
+    pub(crate) fn record_cpis<R>(f: impl FnOnce() -> R) -> (R, Vec<RecordedCpi>) {
+        let _guard = STUBS_IN_USE.lock().unwrap_or_else(|e| e.into_inner());
+        let previous =
+            anchor_lang::solana_program::program_stubs::set_syscall_stubs(Box::new(RecordingStubs));
+        CPIS.lock().unwrap().clear();
+
+        let result = f();
+
+        anchor_lang::solana_program::program_stubs::set_syscall_stubs(previous);
+        let cpis = std::mem::take(&mut *CPIS.lock().unwrap());
+        (result, cpis)
+    }
+
+    /// owns everything an AccountInfo borrows
+    pub(crate) struct TestAccount {
+        pub key: Pubkey,
+        pub owner: Pubkey,
+        pub lamports: u64,
+        pub data: Vec<u8>,
+        pub is_signer: bool,
+        pub executable: bool,
+    }
+
+    impl TestAccount {
+        pub fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+            Self {
+                key,
+                owner,
+                // plenty to be rent exempt
+                lamports: LAMPORTS_PER_SOL,
+                data,
+                is_signer: false,
+                executable: false,
+            }
+        }
+
+        pub fn signer(key: Pubkey) -> Self {
+            Self {
+                is_signer: true,
+                ..Self::new(key, System::id(), vec![])
+            }
+        }
+
+        pub fn program(key: Pubkey) -> Self {
+            Self {
+                executable: true,
+                ..Self::new(key, Pubkey::default(), vec![])
+            }
+        }
+
+        pub fn anchor<T: AccountSerialize + Owner>(key: Pubkey, account: &T) -> Self {
+            let mut data = vec![];
+            account.try_serialize(&mut data).unwrap();
+            Self::new(key, T::owner(), data)
+        }
+
+        pub fn token_account(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+            let mut data = vec![0; spl_token::state::Account::LEN];
+            spl_token::state::Account {
+                mint,
+                owner,
+                amount,
+                state: spl_token::state::AccountState::Initialized,
+                ..Default::default()
+            }
+            .pack_into_slice(&mut data);
+            Self::new(key, Token::id(), data)
+        }
+
+        pub fn mint(key: Pubkey) -> Self {
+            let mut data = vec![0; spl_token::state::Mint::LEN];
+            spl_token::state::Mint {
+                is_initialized: true,
+                ..Default::default()
+            }
+            .pack_into_slice(&mut data);
+            Self::new(key, Token::id(), data)
+        }
+
+        pub fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                self.is_signer,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                self.executable,
+                0,
+            )
+        }
+    }
+
+    fn farm_with_token_fees(fee_mint: Pubkey) -> Farm {
+        let mut farm = Farm::deserialize(&mut &[0; 2048][..]).unwrap();
+        farm.farm_treasury = Pubkey::new_unique();
+        farm.token_fees = TokenFees {
+            fee_mint,
+            stake_fee: 10,
+            unstake_fee: 20,
+        };
+        farm
+    }
+
+    #[test]
+    fn test_collect_token_fee() {
+        let fee_mint = Pubkey::new_unique();
+        let farm = farm_with_token_fees(fee_mint);
+        let treasury_ata = get_associated_token_address(&farm.farm_treasury, &fee_mint);
+
+        let mut payer = TestAccount::signer(Pubkey::new_unique());
+        let mut fee_source = TestAccount::new(Pubkey::new_unique(), Token::id(), vec![]);
+        let mut fee_treasury = TestAccount::new(treasury_ata, Token::id(), vec![]);
+        let mut token_program = TestAccount::program(Token::id());
+        let payer = payer.info();
+        let remaining_accounts = [fee_source.info(), fee_treasury.info(), token_program.info()];
+
+        let (result, cpis) = record_cpis(|| {
+            collect_token_fee(
+                &farm,
+                farm.token_fees.unstake_fee,
+                payer.clone(),
+                &remaining_accounts,
+            )
+        });
+        result.unwrap();
+
+        // exactly the fee, from the payer to the treasury
+        assert_eq!(cpis.len(), 1);
+        let expected = spl_token::instruction::transfer(
+            &Token::id(),
+            remaining_accounts[0].key,
+            &treasury_ata,
+            payer.key,
+            &[],
+            20,
+        )
+        .unwrap();
+        assert_eq!(cpis[0].instruction, expected);
+        assert!(cpis[0].signer_seeds.is_empty());
+    }
+
+    #[test]
+    fn test_collect_token_fee_checks_accounts() {
+        let fee_mint = Pubkey::new_unique();
+        let farm = farm_with_token_fees(fee_mint);
+        let treasury_ata = get_associated_token_address(&farm.farm_treasury, &fee_mint);
+        let someone_elses_ata = get_associated_token_address(&Pubkey::new_unique(), &fee_mint);
+
+        let mut payer = TestAccount::signer(Pubkey::new_unique());
+        let mut fee_source = TestAccount::new(Pubkey::new_unique(), Token::id(), vec![]);
+        let mut fee_treasury = TestAccount::new(treasury_ata, Token::id(), vec![]);
+        let mut wrong_treasury = TestAccount::new(someone_elses_ata, Token::id(), vec![]);
+        let mut token_program = TestAccount::program(Token::id());
+        let mut fake_program = TestAccount::program(Pubkey::new_unique());
+
+        let payer = payer.info();
+        let fee_source = fee_source.info();
+        let fee_treasury = fee_treasury.info();
+        let wrong_treasury = wrong_treasury.info();
+        let token_program = token_program.info();
+        let fake_program = fake_program.info();
+
+        let missing_accounts = [fee_source.clone(), fee_treasury.clone()];
+        let wrong_treasury = [fee_source.clone(), wrong_treasury, token_program];
+        let wrong_program = [fee_source, fee_treasury, fake_program];
+
+        let (_, cpis) = record_cpis(|| {
+            // no fee = nothing to pass
+            collect_token_fee(&farm, 0, payer.clone(), &[]).unwrap();
+
+            for remaining_accounts in [&[][..], &missing_accounts, &wrong_treasury, &wrong_program]
+            {
+                assert!(collect_token_fee(&farm, 20, payer.clone(), remaining_accounts).is_err());
+            }
+        });
+
+        assert!(cpis.is_empty());
+    }
+
+    #[test]
+    fn test_split_token_fee_accounts() {
+        let mut accounts: Vec<TestAccount> = (0..5)
+            .map(|_| TestAccount::new(Pubkey::new_unique(), Token::id(), vec![]))
+            .collect();
+        let keys: Vec<Pubkey> = accounts.iter().map(|acc| acc.key).collect();
+        let infos: Vec<AccountInfo> = accounts.iter_mut().map(|acc| acc.info()).collect();
+
+        // no fee - everything goes to the bank
+        let (fee_accounts, rest) = split_token_fee_accounts(0, &infos).unwrap();
+        assert!(fee_accounts.is_empty());
+        assert_eq!(rest.len(), 5);
+
+        let (fee_accounts, rest) = split_token_fee_accounts(20, &infos).unwrap();
+        assert_eq!(*fee_accounts[0].key, keys[0]);
+        assert_eq!(*rest[0].key, keys[3]);
+        assert_eq!(rest.len(), 2);
+
+        assert!(split_token_fee_accounts(20, &infos[..2]).is_err());
+    }
+}
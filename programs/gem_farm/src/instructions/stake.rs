@@ -1,4 +1,3 @@
-use crate::instructions::FEE_WALLET;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction;
@@ -9,11 +8,8 @@ use gem_bank::{
     state::{Bank, Vault},
 };
 use gem_common::{errors::ErrorCode, *};
-use std::str::FromStr;
 
-use crate::state::*;
-
-const FEE_LAMPORTS: u64 = 2_000_000; // 0.002 SOL per stake/unstake
+use crate::{instructions::shared::collect_token_fee, state::*};
 
 #[derive(Accounts)]
 #[instruction(bump_auth: u8, bump_farmer: u8)]
@@ -44,10 +40,14 @@ pub struct Stake<'info> {
     pub vault: Box<Account<'info, Vault>>,
     pub gem_bank: Program<'info, GemBank>,
 
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
+    #[account(mut, address = protocol_config.fee_wallet)]
     pub fee_acc: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
+    //
+    // if the farm charges token fees, remaining accounts have to be passed (see collect_token_fee)
 }
 
 impl<'info> Stake<'info> {
@@ -64,7 +64,11 @@ impl<'info> Stake<'info> {
 
     fn transfer_fee(&self) -> Result<()> {
         invoke(
-            &system_instruction::transfer(self.identity.key, self.fee_acc.key, FEE_LAMPORTS),
+            &system_instruction::transfer(
+                self.identity.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.stake,
+            ),
             &[
                 self.identity.to_account_info(),
                 self.fee_acc.clone(),
@@ -75,7 +79,11 @@ impl<'info> Stake<'info> {
     }
 }
 
-pub fn handler(ctx: Context<Stake>) -> Result<()> {
+/// lock_option: index into the farm's lock_options, None for the farm's min_staking_period_sec
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, Stake<'info>>,
+    lock_option: Option<u8>,
+) -> Result<()> {
     if ctx.accounts.vault.gem_count == 0 {
         return Err(error!(ErrorCode::VaultIsEmpty));
     }
@@ -97,10 +105,22 @@ pub fn handler(ctx: Context<Stake>) -> Result<()> {
     farm.update_rewards(now_ts, Some(farmer), true)?;
 
     // begin staking
-    farm.begin_staking(now_ts, vault.gem_count, vault.rarity_points, farmer)?;
+    farm.begin_staking(
+        now_ts,
+        vault.gem_count,
+        vault.rarity_points,
+        farmer,
+        lock_option,
+    )?;
 
     //collect a fee for staking
     ctx.accounts.transfer_fee()?;
+    collect_token_fee(
+        &ctx.accounts.farm,
+        ctx.accounts.farm.token_fees.stake_fee,
+        ctx.accounts.identity.to_account_info(),
+        ctx.remaining_accounts,
+    )?;
 
     // msg!("{} gems staked by {}", farmer.gems_staked, farmer.key());
     Ok(())
@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use gem_bank::{
+    self,
+    cpi::accounts::{DepositGem, SetVaultLock, WithdrawGem},
+    program::GemBank,
+    state::{Bank, Vault},
+};
+use gem_common::*;
+
+use crate::{
+    instructions::shared::{collect_token_fee, split_token_fee_accounts},
+    state::*,
+};
+
+#[derive(Accounts)]
+#[instruction(bump_farmer: u8)]
+pub struct SwapGem<'info> {
+    // farm
+    #[account(mut, has_one = farm_authority, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    //skipping seeds verification to save compute budget, has_one check above should be enough
+    /// CHECK:
+    pub farm_authority: AccountInfo<'info>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    // cpi
+    pub bank: Box<Account<'info, Bank>>,
+    #[account(mut)]
+    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK:
+    pub vault_authority: AccountInfo<'info>,
+    pub gem_bank: Program<'info, GemBank>,
+
+    // gem going out (verified downstream)
+    /// CHECK:
+    #[account(mut)]
+    pub old_gem_box: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub old_gem_deposit_receipt: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (might not exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub old_gem_destination: AccountInfo<'info>,
+    pub old_gem_mint: Box<Account<'info, Mint>>,
+    /// CHECK:
+    pub old_gem_rarity: AccountInfo<'info>,
+
+    // gem coming in (verified downstream)
+    // trying to deserialize here leads to errors (doesn't exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub new_gem_box: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (doesn't exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub new_gem_deposit_receipt: AccountInfo<'info>,
+    #[account(mut)]
+    pub new_gem_source: Box<Account<'info, TokenAccount>>,
+    pub new_gem_mint: Box<Account<'info, Mint>>,
+    /// CHECK:
+    pub new_gem_rarity: AccountInfo<'info>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    //
+    // remaining accounts could be passed, in this order:
+    // - fee_source, fee_treasury, token_program <- only if the farm charges an unstake token fee (see collect_token_fee)
+    // - mint_whitelist_proof (for the new gem)
+    // - gem_metadata (for the new gem)
+    // - creator_whitelist_proof (for the new gem)
+}
+
+impl<'info> SwapGem<'info> {
+    fn set_lock_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetVaultLock<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            SetVaultLock {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                bank_manager: self.farm_authority.clone(),
+            },
+        )
+    }
+
+    fn withdraw_gem_ctx(&self) -> CpiContext<'_, '_, '_, 'info, WithdrawGem<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            WithdrawGem {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                owner: self.identity.to_account_info(),
+                authority: self.vault_authority.clone(),
+                gem_box: self.old_gem_box.clone(),
+                gem_deposit_receipt: self.old_gem_deposit_receipt.clone(),
+                gem_destination: self.old_gem_destination.clone(),
+                gem_mint: self.old_gem_mint.to_account_info(),
+                gem_rarity: self.old_gem_rarity.clone(),
+                // the old gem always goes back to the farmer
+                receiver: self.identity.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    fn deposit_gem_ctx(&self) -> CpiContext<'_, '_, '_, 'info, DepositGem<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            DepositGem {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                owner: self.identity.to_account_info(),
+                authority: self.vault_authority.clone(),
+                gem_box: self.new_gem_box.clone(),
+                gem_deposit_receipt: self.new_gem_deposit_receipt.clone(),
+                gem_source: self.new_gem_source.to_account_info(),
+                gem_mint: self.new_gem_mint.to_account_info(),
+                gem_rarity: self.new_gem_rarity.clone(),
+                token_program: self.token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SwapGem<'info>>,
+    bump_vault_auth: u8,
+    bump_old_gem_box: u8,
+    bump_old_gdr: u8,
+    bump_old_rarity: u8,
+    bump_new_rarity: u8,
+    old_amount: u64,
+    new_amount: u64,
+) -> Result<()> {
+    // the old gem comes out, same as on unstake
+    // the token fee accounts go first, the rest are for the deposit cpi
+    let (fee_accounts, deposit_accounts) = split_token_fee_accounts(
+        ctx.accounts.farm.token_fees.unstake_fee,
+        ctx.remaining_accounts,
+    )?;
+
+    // update accrued rewards BEFORE we change the stake
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, Some(farmer), true)?;
+
+    // swap the gems inside the locked vault
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        false,
+    )?;
+
+    gem_bank::cpi::withdraw_gem(
+        ctx.accounts.withdraw_gem_ctx(),
+        bump_vault_auth,
+        bump_old_gem_box,
+        bump_old_gdr,
+        bump_old_rarity,
+        old_amount,
+    )?;
+
+    gem_bank::cpi::deposit_gem(
+        ctx.accounts
+            .deposit_gem_ctx()
+            .with_remaining_accounts(deposit_accounts.to_vec()),
+        bump_vault_auth,
+        bump_new_rarity,
+        new_amount,
+    )?;
+
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        true,
+    )?;
+
+    // record the new stake
+    ctx.accounts.vault.reload()?;
+
+    let farm = &mut ctx.accounts.farm;
+    let farmer = &mut ctx.accounts.farmer;
+
+    farm.swap_gems(
+        now_ts,
+        ctx.accounts.vault.gem_count,
+        ctx.accounts.vault.rarity_points,
+        farmer,
+    )?;
+
+    collect_token_fee(
+        &ctx.accounts.farm,
+        ctx.accounts.farm.token_fees.unstake_fee,
+        ctx.accounts.identity.to_account_info(),
+        fee_accounts,
+    )?;
+
+    // msg!("gem swapped for {}", farmer.key());
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_pot: u8)]
+pub struct SyncRewardPot<'info> {
+    // farm
+    #[account(mut)]
+    pub farm: Box<Account<'info, Farm>>,
+
+    // reward
+    #[account(seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_pot)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+}
+
+/// permissionless - tokens sent straight to the pot are folded into the reward,
+/// a pot holding less than it should fails loudly instead of being hidden by claims capping at the pot
+pub fn handler(ctx: Context<SyncRewardPot>) -> Result<()> {
+    // update existing rewards + fold in the surplus
+    let farm = &mut ctx.accounts.farm;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, None, true)?;
+
+    let surplus = farm.sync_pot_by_mint(
+        now_ts,
+        ctx.accounts.reward_mint.key(),
+        ctx.accounts.reward_pot.amount,
+    )?;
+
+    msg!(
+        "{} untracked tokens synced into {} pot",
+        surplus,
+        ctx.accounts.reward_pot.key()
+    );
+    Ok(())
+}
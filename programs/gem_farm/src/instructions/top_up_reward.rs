@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_pot: u8)]
+pub struct TopUpReward<'info> {
+    // farm
+    #[account(mut)]
+    pub farm: Box<Account<'info, Farm>>,
+
+    // donor - no authorization needed, see Farm.allow_public_top_ups
+    pub donor: Signer<'info>,
+
+    // reward
+    #[account(mut, seeds = [
+            b"reward_pot".as_ref(),
+            farm.key().as_ref(),
+            reward_mint.key().as_ref(),
+        ],
+        bump = bump_pot)]
+    pub reward_pot: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub reward_source: Box<Account<'info, TokenAccount>>,
+    pub reward_mint: Box<Account<'info, Mint>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> TopUpReward<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.reward_source.to_account_info(),
+                to: self.reward_pot.to_account_info(),
+                authority: self.donor.to_account_info(),
+            },
+        )
+    }
+}
+
+/// donors can't change the schedule, and aren't tracked as funders - if the reward is later
+/// cancelled, their unaccrued tokens are refunded along with the rest (see RefundTracker.close_round)
+pub fn handler(ctx: Context<TopUpReward>, amount: u64) -> Result<()> {
+    // update existing rewards + record the top-up
+    let farm = &mut ctx.accounts.farm;
+    let now_ts = now_ts()?;
+
+    farm.update_rewards(now_ts, None, true)?;
+
+    farm.top_up_reward_by_mint(now_ts, ctx.accounts.reward_mint.key(), amount)?;
+
+    // do the transfer
+    token::transfer(ctx.accounts.transfer_ctx(), amount)?;
+
+    msg!(
+        "{} reward tokens donated into {} pot",
+        amount,
+        ctx.accounts.reward_pot.key()
+    );
+    Ok(())
+}
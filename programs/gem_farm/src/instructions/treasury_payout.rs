@@ -3,7 +3,7 @@ use anchor_lang::{
     solana_program::{program::invoke_signed, system_instruction},
 };
 
-use crate::state::*;
+use crate::{instructions::shared::consume_approval, state::*};
 
 #[derive(Accounts)]
 #[instruction(bump_auth: u8, bump_treasury: u8)]
@@ -48,6 +48,15 @@ impl<'info> TreasuryPayout<'info> {
 }
 
 pub fn handler(ctx: Context<TreasuryPayout>, bump: u8, lamports: u64) -> Result<()> {
+    consume_approval(
+        &ctx.accounts.farm,
+        ProposalAction::PayoutFromTreasury {
+            destination: ctx.accounts.destination.key(),
+            lamports,
+        },
+        ctx.remaining_accounts,
+    )?;
+
     ctx.accounts.payout_from_treasury(bump, lamports)?;
 
     msg!("{} lamports paid out from treasury", lamports);
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::{instructions::shared::consume_approval, state::*};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8)]
+pub struct TreasuryTokenPayout<'info> {
+    // farm
+    #[account(has_one = farm_manager, has_one = farm_treasury)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [b"treasury".as_ref(), farm.key().as_ref()], bump = bump_treasury)]
+    pub farm_treasury: AccountInfo<'info>,
+
+    // token fees, collected penalties or anything else sent to the treasury
+    #[account(mut,
+        associated_token::mint = mint,
+        associated_token::authority = farm_treasury)]
+    pub treasury_token_account: Box<Account<'info, TokenAccount>>,
+    pub mint: Box<Account<'info, Mint>>,
+
+    // destination
+    #[account(mut, token::mint = mint)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    // misc
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> TreasuryTokenPayout<'info> {
+    fn transfer_ctx(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.token_program.to_account_info(),
+            Transfer {
+                from: self.treasury_token_account.to_account_info(),
+                to: self.destination.to_account_info(),
+                authority: self.farm_treasury.clone(),
+            },
+        )
+    }
+}
+
+pub fn handler(ctx: Context<TreasuryTokenPayout>, bump_treasury: u8, amount: u64) -> Result<()> {
+    consume_approval(
+        &ctx.accounts.farm,
+        ProposalAction::PayoutTokensFromTreasury {
+            destination: ctx.accounts.destination.key(),
+            amount,
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    let farm_key = ctx.accounts.farm.key();
+
+    token::transfer(
+        ctx.accounts.transfer_ctx().with_signer(&[&[
+            b"treasury".as_ref(),
+            farm_key.as_ref(),
+            &[bump_treasury],
+        ]]),
+        amount,
+    )?;
+
+    msg!(
+        "{} {} paid out from treasury",
+        amount,
+        ctx.accounts.mint.key()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
+
+    use super::*;
+    use crate::instructions::shared::tests::{record_cpis, TestAccount};
+
+    #[test]
+    fn test_treasury_token_payout() {
+        let farm_key = Pubkey::new_unique();
+        let manager = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let (treasury, bump_treasury) =
+            Pubkey::find_program_address(&[b"treasury", farm_key.as_ref()], &crate::ID);
+        let treasury_ata = get_associated_token_address(&treasury, &mint);
+        let destination = Pubkey::new_unique();
+
+        let mut farm = Farm::deserialize(&mut &[0; 2048][..]).unwrap();
+        farm.farm_manager = manager;
+        farm.farm_treasury = treasury;
+
+        let payout = |signer: Pubkey, amount: u64| {
+            let mut accounts = [
+                TestAccount::anchor(farm_key, &farm),
+                TestAccount::signer(signer),
+                TestAccount::new(treasury, System::id(), vec![]),
+                TestAccount::token_account(treasury_ata, mint, treasury, 100),
+                TestAccount::mint(mint),
+                TestAccount::token_account(destination, mint, Pubkey::new_unique(), 0),
+                TestAccount::program(Token::id()),
+            ];
+            let infos: Vec<AccountInfo> = accounts.iter_mut().map(|a| a.info()).collect();
+
+            record_cpis(|| {
+                let mut ctx_accounts = TreasuryTokenPayout::try_accounts(
+                    &crate::ID,
+                    &mut &infos[..],
+                    &[bump_treasury],
+                    &mut BTreeMap::new(),
+                    &mut BTreeSet::new(),
+                )?;
+                handler(
+                    Context::new(&crate::ID, &mut ctx_accounts, &[], BTreeMap::new()),
+                    bump_treasury,
+                    amount,
+                )
+            })
+        };
+
+        // only the manager
+        let (result, cpis) = payout(Pubkey::new_unique(), 40);
+        assert!(result.is_err());
+        assert!(cpis.is_empty());
+
+        // the treasury signs for its own token account
+        let (result, cpis) = payout(manager, 40);
+        result.unwrap();
+        assert_eq!(cpis.len(), 1);
+        let expected = spl_token::instruction::transfer(
+            &Token::id(),
+            &treasury_ata,
+            &destination,
+            &treasury,
+            &[],
+            40,
+        )
+        .unwrap();
+        assert_eq!(cpis[0].instruction, expected);
+        assert_eq!(
+            cpis[0].signer_seeds,
+            vec![vec![
+                b"treasury".to_vec(),
+                farm_key.to_bytes().to_vec(),
+                vec![bump_treasury]
+            ]]
+        );
+    }
+}
@@ -1,4 +1,3 @@
-use crate::instructions::FEE_WALLET;
 use anchor_lang::{
     prelude::*,
     solana_program::{program::invoke, system_instruction},
@@ -10,11 +9,8 @@ use gem_bank::{
     state::{Bank, Vault},
 };
 use gem_common::*;
-use std::str::FromStr;
 
-use crate::state::*;
-
-const FEE_LAMPORTS: u64 = 1_000_000; // 0.002 SOL per entire unstake op (charged twice, so 0.001 2x)
+use crate::{instructions::shared::collect_token_fee, state::*};
 
 #[derive(Accounts)]
 #[instruction(bump_auth: u8, bump_treasury: u8, bump_farmer: u8)]
@@ -49,10 +45,14 @@ pub struct Unstake<'info> {
     pub gem_bank: Program<'info, GemBank>,
 
     //misc
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
     /// CHECK:
-    #[account(mut, address = Pubkey::from_str(FEE_WALLET).unwrap())]
+    #[account(mut, address = protocol_config.fee_wallet)]
     pub fee_acc: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
+    //
+    // if the farm charges token fees, remaining accounts have to be passed (see collect_token_fee)
 }
 
 impl<'info> Unstake<'info> {
@@ -81,7 +81,11 @@ impl<'info> Unstake<'info> {
 
     fn transfer_fee(&self) -> Result<()> {
         invoke(
-            &system_instruction::transfer(self.identity.key, self.fee_acc.key, FEE_LAMPORTS),
+            &system_instruction::transfer(
+                self.identity.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.unstake,
+            ),
             &[
                 self.identity.to_account_info(),
                 self.fee_acc.clone(),
@@ -92,12 +96,24 @@ impl<'info> Unstake<'info> {
     }
 }
 
-pub fn handler(ctx: Context<Unstake>, skip_rewards: bool) -> Result<()> {
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, Unstake<'info>>,
+    skip_rewards: bool,
+) -> Result<()> {
     // collect any unstaking fee
     let farm = &ctx.accounts.farm;
 
-    if ctx.accounts.farmer.state == FarmerState::Staked && farm.config.unstaking_fee_lamp > 0 {
-        ctx.accounts.pay_treasury(farm.config.unstaking_fee_lamp)?
+    if ctx.accounts.farmer.state == FarmerState::Staked {
+        if farm.config.unstaking_fee_lamp > 0 {
+            ctx.accounts.pay_treasury(farm.config.unstaking_fee_lamp)?
+        }
+
+        collect_token_fee(
+            farm,
+            farm.token_fees.unstake_fee,
+            ctx.accounts.identity.to_account_info(),
+            ctx.remaining_accounts,
+        )?;
     }
 
     // update accrued rewards BEFORE we decrement the stake
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::*;
+use crate::{instructions::shared::consume_approval, state::*};
 
 #[derive(Accounts)]
 pub struct UpdateFarm<'info> {
@@ -15,14 +15,33 @@ pub fn handler(
     config: Option<FarmConfig>,
     manager: Option<Pubkey>,
     max_counts: Option<MaxCounts>,
+    restake_keeps_tenure: Option<bool>,
+    loyalty_boost: Option<LoyaltyBoost>,
+    lock_options: Option<LockOptions>,
 ) -> Result<()> {
     let farm = &mut ctx.accounts.farm;
 
+    // see queue_config_change
+    // the rest of what stakers signed up for can't be queued, only changed once the timelock is lowered to 0
+    if config.is_some()
+        || max_counts.is_some()
+        || restake_keeps_tenure.is_some()
+        || loyalty_boost.is_some()
+        || lock_options.is_some()
+    {
+        farm.assert_config_not_timelocked()?;
+    }
+
     if let Some(config) = config {
         farm.config = config;
     }
 
     if let Some(manager) = manager {
+        consume_approval(
+            farm,
+            ProposalAction::SetFarmManager { manager },
+            ctx.remaining_accounts,
+        )?;
         farm.farm_manager = manager;
     }
 
@@ -30,6 +49,18 @@ pub fn handler(
         farm.max_counts = max_counts;
     }
 
+    if let Some(restake_keeps_tenure) = restake_keeps_tenure {
+        farm.restake_keeps_tenure = restake_keeps_tenure;
+    }
+
+    if let Some(loyalty_boost) = loyalty_boost {
+        farm.set_loyalty_boost(loyalty_boost)?;
+    }
+
+    if let Some(lock_options) = lock_options {
+        farm.set_lock_options(lock_options)?;
+    }
+
     msg!("updated farm");
     Ok(())
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use gem_common::errors::ErrorCode;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_manager_set: u8)]
+pub struct UpdateManagerSet<'info> {
+    // farm
+    #[account(has_one = farm_manager)]
+    pub farm: Box<Account<'info, Farm>>,
+    pub farm_manager: Signer<'info>,
+
+    // managers
+    #[account(mut, has_one = farm, seeds = [
+            b"manager_set".as_ref(),
+            farm.key().as_ref(),
+        ],
+        bump = bump_manager_set)]
+    pub manager_set: Box<Account<'info, ManagerSet>>,
+    #[account(mut, has_one = farm)]
+    pub proposal: Box<Account<'info, Proposal>>,
+}
+
+pub fn handler(ctx: Context<UpdateManagerSet>) -> Result<()> {
+    let manager_set = &mut ctx.accounts.manager_set;
+    let proposal = &mut ctx.accounts.proposal;
+
+    let action = proposal.action;
+    let managers = match action {
+        ProposalAction::SetManagers { managers } => managers,
+        _ => return Err(error!(ErrorCode::ProposalMismatch)),
+    };
+
+    manager_set.execute(proposal, ctx.accounts.farm.key(), &action)?;
+    manager_set.set_managers(managers)?;
+
+    msg!(
+        "managers updated, {} of {}",
+        managers.threshold,
+        managers.member_count
+    );
+    Ok(())
+}
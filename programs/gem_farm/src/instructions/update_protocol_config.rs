@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    #[account(mut, has_one = admin, seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateProtocolConfig>,
+    admin: Option<Pubkey>,
+    fee_wallet: Option<Pubkey>,
+    fees: Option<ProtocolFees>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+
+    if let Some(admin) = admin {
+        config.admin = admin;
+    }
+
+    if let Some(fee_wallet) = fee_wallet {
+        config.fee_wallet = fee_wallet;
+    }
+
+    if let Some(fees) = fees {
+        config.fees = fees;
+    }
+
+    msg!("protocol config updated");
+    Ok(())
+}
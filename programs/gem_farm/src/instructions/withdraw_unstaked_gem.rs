@@ -0,0 +1,181 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{program::invoke, system_instruction},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use gem_bank::{
+    self,
+    cpi::accounts::{SetVaultLock, WithdrawGem},
+    instructions::deposit_gem::calc_rarity_points,
+    program::GemBank,
+    state::{Bank, Vault},
+};
+use gem_common::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(bump_farmer: u8, bump_vault_auth: u8, bump_gem_box: u8, bump_gdr: u8, bump_rarity: u8, bump_cooling_gem: u8)]
+pub struct WithdrawUnstakedGem<'info> {
+    // farm
+    #[account(has_one = farm_authority, has_one = bank)]
+    pub farm: Box<Account<'info, Farm>>,
+    //skipping seeds verification to save compute budget, has_one check above should be enough
+    /// CHECK:
+    pub farm_authority: AccountInfo<'info>,
+
+    // farmer
+    #[account(mut, has_one = farm, has_one = identity, has_one = vault,
+        seeds = [
+            b"farmer".as_ref(),
+            farm.key().as_ref(),
+            identity.key().as_ref(),
+        ],
+        bump = bump_farmer)]
+    pub farmer: Box<Account<'info, Farmer>>,
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    // cpi
+    pub bank: Box<Account<'info, Bank>>,
+    #[account(mut)]
+    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK:
+    pub vault_authority: AccountInfo<'info>,
+    pub gem_bank: Program<'info, GemBank>,
+
+    // gem (verified downstream)
+    /// CHECK:
+    #[account(mut)]
+    pub gem_box: AccountInfo<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub gem_deposit_receipt: AccountInfo<'info>,
+    // trying to deserialize here leads to errors (might not exist yet)
+    /// CHECK:
+    #[account(mut)]
+    pub gem_destination: AccountInfo<'info>,
+    pub gem_mint: Box<Account<'info, Mint>>,
+    /// CHECK:
+    pub gem_rarity: AccountInfo<'info>,
+    #[account(mut, has_one = farmer, has_one = gem_mint, close = identity, seeds = [
+            b"cooling_gem".as_ref(),
+            farmer.key().as_ref(),
+            gem_mint.key().as_ref(),
+        ],
+        bump = bump_cooling_gem)]
+    pub cooling_gem: Box<Account<'info, CoolingGem>>,
+
+    // misc
+    #[account(seeds = [b"protocol_config".as_ref()], bump = protocol_config.bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+    /// CHECK:
+    #[account(mut, address = protocol_config.fee_wallet)]
+    pub fee_acc: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> WithdrawUnstakedGem<'info> {
+    fn set_lock_vault_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetVaultLock<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            SetVaultLock {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                bank_manager: self.farm_authority.clone(),
+            },
+        )
+    }
+
+    fn withdraw_gem_ctx(&self) -> CpiContext<'_, '_, '_, 'info, WithdrawGem<'info>> {
+        CpiContext::new(
+            self.gem_bank.to_account_info(),
+            WithdrawGem {
+                bank: self.bank.to_account_info(),
+                vault: self.vault.to_account_info(),
+                owner: self.identity.to_account_info(),
+                authority: self.vault_authority.clone(),
+                gem_box: self.gem_box.clone(),
+                gem_deposit_receipt: self.gem_deposit_receipt.clone(),
+                gem_destination: self.gem_destination.clone(),
+                gem_mint: self.gem_mint.to_account_info(),
+                gem_rarity: self.gem_rarity.clone(),
+                receiver: self.identity.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                associated_token_program: self.associated_token_program.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        )
+    }
+
+    fn transfer_fee(&self) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(
+                self.identity.key,
+                self.fee_acc.key,
+                self.protocol_config.fees.unstake,
+            ),
+            &[
+                self.identity.to_account_info(),
+                self.fee_acc.clone(),
+                self.system_program.to_account_info(),
+            ],
+        )
+        .map_err(Into::into)
+    }
+}
+
+pub fn handler(
+    ctx: Context<WithdrawUnstakedGem>,
+    bump_vault_auth: u8,
+    bump_gem_box: u8,
+    bump_gdr: u8,
+    bump_rarity: u8,
+    amount: u64,
+) -> Result<()> {
+    // gem_rarity is verified by gem_bank during the withdrawal cpi below
+    let rarity_points = calc_rarity_points(&ctx.accounts.gem_rarity, amount)?;
+
+    let farmer = &mut ctx.accounts.farmer;
+    let now_ts = now_ts()?;
+
+    // only gems that went through partial_unstake, and only once their own cooldown is over
+    ctx.accounts.cooling_gem.withdraw(now_ts, farmer, amount)?;
+    farmer.withdraw_cooled_down_gems(amount, rarity_points)?;
+
+    // the rest of the vault stays staked, so we only unlock it for the duration of the withdrawal
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        false,
+    )?;
+
+    gem_bank::cpi::withdraw_gem(
+        ctx.accounts.withdraw_gem_ctx(),
+        bump_vault_auth,
+        bump_gem_box,
+        bump_gdr,
+        bump_rarity,
+        amount,
+    )?;
+
+    gem_bank::cpi::set_vault_lock(
+        ctx.accounts
+            .set_lock_vault_ctx()
+            .with_signer(&[&ctx.accounts.farm.farm_seeds()]),
+        true,
+    )?;
+
+    //collect a fee for withdrawing
+    ctx.accounts.transfer_fee()?;
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use state::*;
 
 pub mod instructions;
 pub mod number128;
+pub mod number192;
 pub mod state;
 
 declare_id!("farmL4xeBFVXJqtfxCzU9b28QACM7E2W2ctT6epAjvE");
@@ -13,6 +14,28 @@ declare_id!("farmL4xeBFVXJqtfxCzU9b28QACM7E2W2ctT6epAjvE");
 pub mod gem_farm {
     use super::*;
 
+    // --------------------------------------- protocol
+
+    pub fn init_protocol_config(
+        ctx: Context<InitProtocolConfig>,
+        admin: Pubkey,
+        fee_wallet: Pubkey,
+        fees: ProtocolFees,
+    ) -> Result<()> {
+        msg!("init protocol config");
+        instructions::init_protocol_config::handler(ctx, admin, fee_wallet, fees)
+    }
+
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        admin: Option<Pubkey>,
+        fee_wallet: Option<Pubkey>,
+        fees: Option<ProtocolFees>,
+    ) -> Result<()> {
+        msg!("update protocol config");
+        instructions::update_protocol_config::handler(ctx, admin, fee_wallet, fees)
+    }
+
     // --------------------------------------- core
 
     pub fn init_farm(
@@ -42,8 +65,82 @@ pub mod gem_farm {
         config: Option<FarmConfig>,
         manager: Option<Pubkey>,
         max_counts: Option<MaxCounts>,
+        restake_keeps_tenure: Option<bool>,
+        loyalty_boost: Option<LoyaltyBoost>,
+        lock_options: Option<LockOptions>,
+    ) -> Result<()> {
+        instructions::update_farm::handler(
+            ctx,
+            config,
+            manager,
+            max_counts,
+            restake_keeps_tenure,
+            loyalty_boost,
+            lock_options,
+        )
+    }
+
+    pub fn set_config_timelock(
+        ctx: Context<SetConfigTimelock>,
+        config_timelock_sec: u64,
+    ) -> Result<()> {
+        msg!("set config timelock");
+        instructions::set_config_timelock::handler(ctx, config_timelock_sec)
+    }
+
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        config: Option<FarmConfig>,
+        max_counts: Option<MaxCounts>,
+        config_timelock_sec: Option<u64>,
+        effective_ts: u64,
+    ) -> Result<()> {
+        msg!("queue config change");
+        instructions::queue_config_change::handler(
+            ctx,
+            config,
+            max_counts,
+            config_timelock_sec,
+            effective_ts,
+        )
+    }
+
+    /// permissionless - once the queued change's effective_ts has passed
+    pub fn apply_pending_config(ctx: Context<ApplyPendingConfig>, _bump_pending: u8) -> Result<()> {
+        msg!("apply pending config");
+        instructions::apply_pending_config::handler(ctx)
+    }
+
+    pub fn cancel_pending_config(
+        ctx: Context<CancelPendingConfig>,
+        _bump_pending: u8,
+    ) -> Result<()> {
+        msg!("cancel pending config");
+        instructions::cancel_pending_config::handler(ctx)
+    }
+
+    pub fn init_manager_set(ctx: Context<InitManagerSet>, managers: Managers) -> Result<()> {
+        msg!("init manager set");
+        instructions::init_manager_set::handler(ctx, managers)
+    }
+
+    pub fn propose(
+        ctx: Context<Propose>,
+        _bump_manager_set: u8,
+        action: ProposalAction,
     ) -> Result<()> {
-        instructions::update_farm::handler(ctx, config, manager, max_counts)
+        msg!("propose");
+        instructions::propose::handler(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>, _bump_manager_set: u8) -> Result<()> {
+        msg!("approve proposal");
+        instructions::approve_proposal::handler(ctx)
+    }
+
+    pub fn update_manager_set(ctx: Context<UpdateManagerSet>, _bump_manager_set: u8) -> Result<()> {
+        msg!("update manager set");
+        instructions::update_manager_set::handler(ctx)
     }
 
     pub fn payout_from_treasury(
@@ -56,6 +153,63 @@ pub mod gem_farm {
         instructions::treasury_payout::handler(ctx, bump_treasury, lamports)
     }
 
+    pub fn payout_tokens_from_treasury(
+        ctx: Context<TreasuryTokenPayout>,
+        bump_treasury: u8,
+        amount: u64,
+    ) -> Result<()> {
+        msg!("token payout");
+        instructions::treasury_token_payout::handler(ctx, bump_treasury, amount)
+    }
+
+    pub fn set_treasury_split(
+        ctx: Context<SetTreasurySplit>,
+        recipients: Vec<TreasuryRecipient>,
+    ) -> Result<()> {
+        msg!("set treasury split");
+        instructions::set_treasury_split::handler(ctx, recipients)
+    }
+
+    /// permissionless - pays the treasury out according to the farm's treasury split
+    pub fn distribute_treasury<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DistributeTreasury<'info>>,
+        bump_treasury: u8,
+    ) -> Result<()> {
+        msg!("distribute treasury");
+        instructions::distribute_treasury::handler(ctx, bump_treasury)
+    }
+
+    pub fn set_unstake_penalty(
+        ctx: Context<SetUnstakePenalty>,
+        penalty: EarlyUnstakePenalty,
+    ) -> Result<()> {
+        msg!("set unstake penalty");
+        instructions::set_unstake_penalty::handler(ctx, penalty)
+    }
+
+    pub fn set_public_top_ups(
+        ctx: Context<SetPublicTopUps>,
+        allow_public_top_ups: bool,
+    ) -> Result<()> {
+        msg!("set public top-ups");
+        instructions::set_public_top_ups::handler(ctx, allow_public_top_ups)
+    }
+
+    pub fn set_reward_cap(ctx: Context<SetRewardCap>, cap: RewardCap) -> Result<()> {
+        msg!("set reward cap");
+        instructions::set_reward_cap::handler(ctx, cap)
+    }
+
+    pub fn set_token_fees(
+        ctx: Context<SetTokenFees>,
+        _bump_treasury: u8,
+        stake_fee: u64,
+        unstake_fee: u64,
+    ) -> Result<()> {
+        msg!("set token fees");
+        instructions::set_token_fees::handler(ctx, stake_fee, unstake_fee)
+    }
+
     pub fn add_to_bank_whitelist(
         ctx: Context<AddToBankWhitelist>,
         _bump_auth: u8,
@@ -74,6 +228,11 @@ pub mod gem_farm {
         instructions::remove_from_bank_whitelist::handler(ctx, bump_wl)
     }
 
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        msg!("migrate account");
+        instructions::migrate_account::handler(ctx)
+    }
+
     // --------------------------------------- farmer ops
 
     pub fn init_farmer(ctx: Context<InitFarmer>) -> Result<()> {
@@ -81,13 +240,30 @@ pub mod gem_farm {
         instructions::init_farmer::handler(ctx)
     }
 
-    pub fn stake(ctx: Context<Stake>, _bump_auth: u8, _bump_farmer: u8) -> Result<()> {
+    /// init farmer + vault, deposit a gem and stake it, all in one go
+    pub fn enter_farm<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, EnterFarm<'info>>,
+        bump_vault_auth: u8,
+        bump_rarity: u8,
+        amount: u64,
+        lock_option: Option<u8>,
+    ) -> Result<()> {
+        // msg!("enter farm"); //same compute budget concerns as flash deposit
+        instructions::enter_farm::handler(ctx, bump_vault_auth, bump_rarity, amount, lock_option)
+    }
+
+    pub fn stake<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Stake<'info>>,
+        _bump_auth: u8,
+        _bump_farmer: u8,
+        lock_option: Option<u8>,
+    ) -> Result<()> {
         msg!("stake");
-        instructions::stake::handler(ctx)
+        instructions::stake::handler(ctx, lock_option)
     }
 
-    pub fn unstake(
-        ctx: Context<Unstake>,
+    pub fn unstake<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Unstake<'info>>,
         _bump_auth: u8,
         _bump_treasury: u8,
         _bump_farmer: u8,
@@ -97,6 +273,49 @@ pub mod gem_farm {
         instructions::unstake::handler(ctx, skip_rewards)
     }
 
+    pub fn partial_unstake<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, PartialUnstake<'info>>,
+        _bump_treasury: u8,
+        _bump_farmer: u8,
+        _bump_gdr: u8,
+        _bump_rarity: u8,
+        amount: u64,
+    ) -> Result<()> {
+        msg!("partial unstake");
+        instructions::partial_unstake::handler(ctx, amount)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn withdraw_unstaked_gem(
+        ctx: Context<WithdrawUnstakedGem>,
+        _bump_farmer: u8,
+        bump_vault_auth: u8,
+        bump_gem_box: u8,
+        bump_gdr: u8,
+        bump_rarity: u8,
+        _bump_cooling_gem: u8,
+        amount: u64,
+    ) -> Result<()> {
+        msg!("withdraw unstaked gem");
+        instructions::withdraw_unstaked_gem::handler(
+            ctx,
+            bump_vault_auth,
+            bump_gem_box,
+            bump_gdr,
+            bump_rarity,
+            amount,
+        )
+    }
+
+    /// cancels a pending cooldown and puts the farmer back to staked
+    pub fn restake<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, Restake<'info>>,
+        _bump_farmer: u8,
+    ) -> Result<()> {
+        msg!("restake");
+        instructions::restake::handler(ctx)
+    }
+
     pub fn claim(
         ctx: Context<Claim>,
         _bump_auth: u8,
@@ -119,6 +338,53 @@ pub mod gem_farm {
         instructions::flash_deposit::handler(ctx, bump_vault_auth, bump_rarity, amount)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn flash_withdraw<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FlashWithdraw<'info>>,
+        _bump_treasury: u8,
+        _bump_farmer: u8,
+        bump_vault_auth: u8,
+        bump_gem_box: u8,
+        bump_gdr: u8,
+        bump_rarity: u8,
+        amount: u64,
+    ) -> Result<()> {
+        // msg!("flash withdraw"); //same compute budget concerns as flash deposit
+        instructions::flash_withdraw::handler(
+            ctx,
+            bump_vault_auth,
+            bump_gem_box,
+            bump_gdr,
+            bump_rarity,
+            amount,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn swap_gem<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SwapGem<'info>>,
+        _bump_farmer: u8,
+        bump_vault_auth: u8,
+        bump_old_gem_box: u8,
+        bump_old_gdr: u8,
+        bump_old_rarity: u8,
+        bump_new_rarity: u8,
+        old_amount: u64,
+        new_amount: u64,
+    ) -> Result<()> {
+        // msg!("swap gem"); //same compute budget concerns as flash deposit
+        instructions::swap_gem::handler(
+            ctx,
+            bump_vault_auth,
+            bump_old_gem_box,
+            bump_old_gdr,
+            bump_old_rarity,
+            bump_new_rarity,
+            old_amount,
+            new_amount,
+        )
+    }
+
     pub fn refresh_farmer(ctx: Context<RefreshFarmer>, _bump: u8) -> Result<()> {
         msg!("refresh farmer");
         instructions::refresh_farmer::handler(ctx)
@@ -138,9 +404,14 @@ pub mod gem_farm {
 
     // --------------------------------------- funder ops
 
-    pub fn authorize_funder(ctx: Context<AuthorizeFunder>) -> Result<()> {
+    pub fn authorize_funder(
+        ctx: Context<AuthorizeFunder>,
+        max_amount: u64,
+        expires_ts: u64,
+        allowed_reward: FundableReward,
+    ) -> Result<()> {
         msg!("authorize funder");
-        instructions::authorize_funder::handler(ctx)
+        instructions::authorize_funder::handler(ctx, max_amount, expires_ts, allowed_reward)
     }
 
     pub fn deauthorize_funder(ctx: Context<DeauthorizeFunder>, _bump: u8) -> Result<()> {
@@ -161,16 +432,124 @@ pub mod gem_farm {
         instructions::fund_reward::handler(ctx, variable_rate_config, fixed_rate_config)
     }
 
+    /// permissionless (if the farm allows it) - adds to a running variable reward, same end time
+    pub fn top_up_reward(ctx: Context<TopUpReward>, _bump_pot: u8, amount: u64) -> Result<()> {
+        msg!("top up reward");
+        instructions::top_up_reward::handler(ctx, amount)
+    }
+
+    /// permissionless - folds tokens sent straight to the pot into the reward
+    pub fn sync_reward_pot(ctx: Context<SyncRewardPot>, _bump_pot: u8) -> Result<()> {
+        msg!("sync reward pot");
+        instructions::sync_reward_pot::handler(ctx)
+    }
+
     pub fn cancel_reward(ctx: Context<CancelReward>, _bump_auth: u8, _bump_pot: u8) -> Result<()> {
         msg!("cancel reward");
         instructions::cancel_reward::handler(ctx)
     }
 
+    /// permissionless - pays a funder their share of the last cancelled funding round
+    pub fn claim_refund(
+        ctx: Context<ClaimRefund>,
+        _bump_auth: u8,
+        _bump_contribution: u8,
+        _bump_pot: u8,
+    ) -> Result<()> {
+        msg!("claim refund");
+        instructions::claim_refund::handler(ctx)
+    }
+
+    /// permissionless - moves early unstake penalties owed to the treasury out of the reward pot
+    pub fn collect_penalties(
+        ctx: Context<CollectPenalties>,
+        _bump_auth: u8,
+        _bump_treasury: u8,
+        _bump_pot: u8,
+    ) -> Result<()> {
+        msg!("collect penalties");
+        instructions::collect_penalties::handler(ctx)
+    }
+
     pub fn lock_reward(ctx: Context<LockReward>) -> Result<()> {
         msg!("lock reward");
         instructions::lock_reward::handler(ctx)
     }
 
+    /// retires an ended, unlocked & drained reward and replaces it with a new mint / type
+    pub fn replace_reward(
+        ctx: Context<ReplaceReward>,
+        _bump_auth: u8,
+        _bump_old_pot: u8,
+        new_reward_type: RewardType,
+    ) -> Result<()> {
+        msg!("replace reward");
+        instructions::replace_reward::handler(ctx, new_reward_type)
+    }
+
+    // --------------------------------------- reward slots
+
+    pub fn init_reward_slot(
+        ctx: Context<InitRewardSlot>,
+        _bump_auth: u8,
+        reward_type: RewardType,
+    ) -> Result<()> {
+        msg!("init reward slot");
+        instructions::init_reward_slot::handler(ctx, reward_type)
+    }
+
+    pub fn fund_reward_slot(
+        ctx: Context<FundRewardSlot>,
+        _bump_proof: u8,
+        _bump_slot: u8,
+        _bump_pot: u8,
+        variable_rate_config: Option<VariableRateConfig>,
+        fixed_rate_config: Option<FixedRateConfig>,
+    ) -> Result<()> {
+        msg!("fund reward slot");
+        instructions::fund_reward_slot::handler(ctx, variable_rate_config, fixed_rate_config)
+    }
+
+    pub fn cancel_reward_slot(
+        ctx: Context<CancelRewardSlot>,
+        _bump_auth: u8,
+        _bump_slot: u8,
+        _bump_pot: u8,
+    ) -> Result<()> {
+        msg!("cancel reward slot");
+        instructions::cancel_reward_slot::handler(ctx)
+    }
+
+    pub fn lock_reward_slot(ctx: Context<LockRewardSlot>) -> Result<()> {
+        msg!("lock reward slot");
+        instructions::lock_reward_slot::handler(ctx)
+    }
+
+    pub fn init_farmer_reward_slot(ctx: Context<InitFarmerRewardSlot>) -> Result<()> {
+        msg!("init farmer reward slot");
+        instructions::init_farmer_reward_slot::handler(ctx)
+    }
+
+    pub fn refresh_reward_slot(
+        ctx: Context<RefreshRewardSlot>,
+        _bump_farmer_slot: u8,
+    ) -> Result<()> {
+        msg!("refresh reward slot");
+        instructions::refresh_reward_slot::handler(ctx)
+    }
+
+    pub fn claim_reward_slot(
+        ctx: Context<ClaimRewardSlot>,
+        _bump_auth: u8,
+        _bump_farmer: u8,
+        _bump_slot: u8,
+        _bump_farmer_slot: u8,
+        _bump_pot: u8,
+    ) -> Result<()> {
+        msg!("claim reward slot");
+        instructions::claim_reward_slot::handler(ctx)
+    }
+
     // --------------------------------------- rarities
 
     pub fn add_rarities_to_bank<'a, 'b, 'c, 'info>(
@@ -199,4 +578,47 @@ pub mod gem_farm {
             rules_acc_present,
         )
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn flash_withdraw_pnft<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, FlashWithdrawPnft<'info>>,
+        _bump_treasury: u8,
+        _bump_farmer: u8,
+        bump_vault_auth: u8,
+        bump_gem_box: u8,
+        bump_gdr: u8,
+        bump_rarity: u8,
+        amount: u64,
+        rules_acc_present: bool,
+    ) -> Result<()> {
+        // msg!("flash withdraw"); //have to remove all msgs! or run out of compute budget for this ix
+        instructions::flash_withdraw_pnft::handler(
+            ctx,
+            bump_vault_auth,
+            bump_gem_box,
+            bump_gdr,
+            bump_rarity,
+            amount,
+            rules_acc_present,
+        )
+    }
+
+    pub fn enter_farm_pnft<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, EnterFarmPnft<'info>>,
+        bump_vault_auth: u8,
+        bump_rarity: u8,
+        amount: u64,
+        rules_acc_present: bool,
+        lock_option: Option<u8>,
+    ) -> Result<()> {
+        // msg!("enter farm"); //have to remove all msgs! or run out of compute budget for this ix
+        instructions::enter_farm_pnft::handler(
+            ctx,
+            bump_vault_auth,
+            bump_rarity,
+            amount,
+            rules_acc_present,
+            lock_option,
+        )
+    }
 }
@@ -1,5 +1,7 @@
 //! Yet another decimal lib
 //!
+//! Superseded by Number192 - only kept to read accounts that haven't been migrated yet
+//!
 //! This one in particular suited to peculiarities of Anchor -
 //! specifically that it doesn't support Newtypes
 //!
@@ -31,6 +33,11 @@ impl Number128 {
     pub const ONE: Self = Self { n: ONE };
     pub const ZERO: Self = Self { n: 0 };
 
+    /// the underlying value, scaled by ONE
+    pub(crate) fn raw(&self) -> u128 {
+        self.n
+    }
+
     pub fn as_u64(&self, exponent: impl Into<i32>) -> Result<u64> {
         let extra_precision = PRECISION + exponent.into();
         let mut prec_value = Self::ten_pow(extra_precision.abs() as u32);
@@ -321,12 +328,20 @@ mod tests2 {
                 .unwrap(),
             1u64
         );
+        // smallest value representable with 3 decimals
         assert_eq!(
-            Number128::from_decimal(1_u64, -10_i32)
+            Number128::from_decimal(1_u64, -3_i32)
                 .as_u64_ceil(0_i32)
                 .unwrap(),
             1u64
         );
+        // anything smaller underflows to 0 in from_decimal, so there's nothing left to round up
+        assert_eq!(
+            Number128::from_decimal(1_u64, -10_i32)
+                .as_u64_ceil(0_i32)
+                .unwrap(),
+            0u64
+        );
     }
 
     #[test]
@@ -0,0 +1,527 @@
+//! Higher precision sibling of Number128
+//!
+//! Same idea (plain struct so that Anchor can generate the IDL), but:
+//!  1) 12 decimals instead of 3, so that low-decimal mints spread over lots of rarity points
+//!     don't get rounded away
+//!  2) backed by a U192 (stored as 3 little-endian u64 words), with U256 intermediates
+//!  3) never panics - anything that doesn't fit returns ArithmeticError
+//!  4) rounding direction is part of the method name:
+//!     try_mul / try_div / as_u64 round down, try_mul_ceil / try_ceil_div / as_u64_ceil round up,
+//!     try_rounded_div rounds half up
+
+use std::fmt::{Display, Formatter};
+
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, TryAdd, TryDiv, TryMul, TryPow, TryRem, TrySub};
+
+use crate::number128::Number128;
+
+#[allow(clippy::all)]
+mod uint_types {
+    use uint::construct_uint;
+
+    construct_uint! {
+        pub struct U192(3);
+    }
+    construct_uint! {
+        pub struct U256(4);
+    }
+}
+
+use uint_types::{U192, U256};
+
+const ONE: u64 = 1_000_000_000_000;
+const PRECISION: i32 = 12;
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct Number192 {
+    n: [u64; 3],
+}
+
+impl Number192 {
+    pub const ONE: Self = Self { n: [ONE, 0, 0] };
+    pub const ZERO: Self = Self { n: [0, 0, 0] };
+
+    fn value(&self) -> U192 {
+        U192(self.n)
+    }
+
+    fn from_value(value: U192) -> Self {
+        Self { n: value.0 }
+    }
+
+    fn widen(value: U192) -> U256 {
+        let [a, b, c] = value.0;
+        U256([a, b, c, 0])
+    }
+
+    fn narrow(value: U256) -> Option<U192> {
+        let [a, b, c, d] = value.0;
+        match d {
+            0 => Some(U192([a, b, c])),
+            _ => None,
+        }
+    }
+
+    fn ten_pow(exponent: u32) -> Result<U192> {
+        U192::from(10u64)
+            .checked_pow(U192::from(exponent))
+            .ok_or_else(|| error!(ErrorCode::ArithmeticError))
+    }
+
+    /// rounds down
+    pub fn as_u64(&self, exponent: impl Into<i32>) -> Result<u64> {
+        self.as_u64_rounded(exponent.into(), Rounding::Down)
+    }
+
+    /// rounds up
+    pub fn as_u64_ceil(&self, exponent: impl Into<i32>) -> Result<u64> {
+        self.as_u64_rounded(exponent.into(), Rounding::Up)
+    }
+
+    fn as_u64_rounded(&self, exponent: i32, rounding: Rounding) -> Result<u64> {
+        let extra_precision = PRECISION
+            .checked_add(exponent)
+            .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
+        let prec_value = Self::ten_pow(extra_precision.unsigned_abs())?;
+
+        let target_value = if extra_precision < 0 {
+            Self::widen(self.value())
+                .checked_mul(Self::widen(prec_value))
+                .ok_or_else(|| error!(ErrorCode::ArithmeticError))?
+        } else {
+            rounding
+                .div(Self::widen(self.value()), Self::widen(prec_value))
+                .ok_or_else(|| error!(ErrorCode::ArithmeticError))?
+        };
+
+        if target_value.bits() > 64 {
+            msg!("cannot convert {} to u64 due to overflow", self);
+            return Err(error!(ErrorCode::ArithmeticError));
+        }
+
+        Ok(target_value.low_u64())
+    }
+
+    /// anything beyond 12 decimals is rounded down
+    pub fn from_decimal(value: impl Into<u128>, exponent: impl Into<i32>) -> Result<Self> {
+        let extra_precision = PRECISION
+            .checked_add(exponent.into())
+            .ok_or_else(|| error!(ErrorCode::ArithmeticError))?;
+        let prec_value = Self::ten_pow(extra_precision.unsigned_abs())?;
+        let value = U192::from(value.into());
+
+        let n = if extra_precision < 0 {
+            value / prec_value
+        } else {
+            value
+                .checked_mul(prec_value)
+                .ok_or_else(|| error!(ErrorCode::ArithmeticError))?
+        };
+
+        Ok(Self::from_value(n))
+    }
+
+    /// rounds up
+    pub fn try_mul_ceil(self, rhs: Self) -> Result<Self> {
+        self.mul_rounded(rhs, Rounding::Up)
+    }
+
+    fn mul_rounded(self, rhs: Self, rounding: Rounding) -> Result<Self> {
+        let result = Self::widen(self.value())
+            .checked_mul(Self::widen(rhs.value()))
+            .and_then(|product| rounding.div(product, U256::from(ONE)))
+            .and_then(Self::narrow)
+            .ok_or_else(|| {
+                msg!("tried multiplying {} and {}", self, rhs);
+                error!(ErrorCode::ArithmeticError)
+            })?;
+        Ok(Self::from_value(result))
+    }
+
+    fn div_rounded(self, rhs: Self, rounding: Rounding) -> Result<Self> {
+        // can't overflow, U192 * 10^12 < U256
+        let numerator = Self::widen(self.value()) * U256::from(ONE);
+
+        let result = rounding
+            .div(numerator, Self::widen(rhs.value()))
+            .and_then(Self::narrow)
+            .ok_or_else(|| {
+                msg!("tried dividing {} by {}", self, rhs);
+                error!(ErrorCode::ArithmeticError)
+            })?;
+        Ok(Self::from_value(result))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Rounding {
+    Down,
+    Up,
+    HalfUp,
+}
+
+impl Rounding {
+    /// None on division by zero
+    fn div(self, numerator: U256, denominator: U256) -> Option<U256> {
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = numerator.div_mod(denominator);
+        let round_up = match self {
+            Rounding::Down => false,
+            Rounding::Up => !remainder.is_zero(),
+            Rounding::HalfUp => remainder >= denominator - remainder,
+        };
+
+        match round_up {
+            // can't overflow, quotient <= U256::MAX / 2 whenever there's a remainder
+            true => Some(quotient + U256::one()),
+            false => Some(quotient),
+        }
+    }
+}
+
+impl TrySub for Number192 {
+    fn try_sub(self, rhs: Self) -> Result<Self> {
+        let result = self.value().checked_sub(rhs.value()).ok_or_else(|| {
+            msg!("tried subtracting {} from {}", rhs, self);
+            error!(ErrorCode::ArithmeticError)
+        })?;
+        Ok(Self::from_value(result))
+    }
+}
+
+impl TryAdd for Number192 {
+    fn try_add(self, rhs: Self) -> Result<Self> {
+        let result = self.value().checked_add(rhs.value()).ok_or_else(|| {
+            msg!("tried adding {} and {}", rhs, self);
+            error!(ErrorCode::ArithmeticError)
+        })?;
+        Ok(Self::from_value(result))
+    }
+}
+
+impl TryDiv for Number192 {
+    /// rounds down
+    fn try_div(self, rhs: Self) -> Result<Self> {
+        self.div_rounded(rhs, Rounding::Down)
+    }
+    fn try_ceil_div(self, rhs: Self) -> Result<Self> {
+        self.div_rounded(rhs, Rounding::Up)
+    }
+    fn try_rounded_div(self, rhs: Self) -> Result<Self> {
+        self.div_rounded(rhs, Rounding::HalfUp)
+    }
+}
+
+impl TryMul for Number192 {
+    /// rounds down
+    fn try_mul(self, rhs: Self) -> Result<Self> {
+        self.mul_rounded(rhs, Rounding::Down)
+    }
+}
+
+impl TryPow for Number192 {
+    /// rounds down at every step
+    fn try_pow(self, rhs: u32) -> Result<Self> {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut exponent = rhs;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.try_mul(base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.try_mul(base)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl TryRem for Number192 {
+    fn try_rem(self, rhs: Self) -> Result<Self> {
+        let result = self.value().checked_rem(rhs.value()).ok_or_else(|| {
+            msg!("tried getting the remainder of {} / {}", self, rhs);
+            error!(ErrorCode::ArithmeticError)
+        })?;
+        Ok(Self::from_value(result))
+    }
+}
+
+impl<T: Into<u128>> From<T> for Number192 {
+    fn from(n: T) -> Number192 {
+        // can't overflow, u128 * 10^12 < U192
+        Number192::from_value(U192::from(n.into()) * U192::from(ONE))
+    }
+}
+
+/// lossless, 3 decimals always fit into 12
+impl From<Number128> for Number192 {
+    fn from(legacy: Number128) -> Number192 {
+        let scale = U192::from(ONE) / U192::from(Number128::ONE.raw());
+        // can't overflow, u128 * 10^9 < U192
+        Number192::from_value(U192::from(legacy.raw()) * scale)
+    }
+}
+
+impl PartialOrd for Number192 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Number192 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl Display for Number192 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (int, rem) = self.value().div_mod(U192::from(ONE));
+        let decimals = format!("{:012}", rem.low_u64());
+        let stripped_decimals = decimals.trim_end_matches('0');
+        let pretty_decimals = if stripped_decimals.is_empty() {
+            "0"
+        } else {
+            stripped_decimals
+        };
+        write!(f, "{}.{}", int, pretty_decimals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_equals_zero() {
+        assert_eq!(
+            Number192::ZERO,
+            Number192::from_decimal(0_u64, 0_i32).unwrap()
+        );
+        assert_eq!(Number192::ZERO, Number192::from(0u64));
+    }
+
+    #[test]
+    fn one_equals_one() {
+        assert_eq!(
+            Number192::ONE,
+            Number192::from_decimal(1_u64, 0_i32).unwrap()
+        );
+        assert_eq!(Number192::ONE, Number192::from(1u64));
+    }
+
+    #[test]
+    fn one_plus_one_equals_two() {
+        assert_eq!(
+            Number192::from(2u64),
+            Number192::ONE.try_add(Number192::ONE).unwrap()
+        )
+    }
+
+    #[test]
+    fn one_minus_one_equals_zero() {
+        assert_eq!(
+            Number192::ONE.try_sub(Number192::ONE).unwrap(),
+            Number192::ZERO
+        );
+        assert!(Number192::ZERO.try_sub(Number192::ONE).is_err());
+    }
+
+    #[test]
+    fn ten_div_100_equals_point_1() {
+        assert_eq!(
+            Number192::from_decimal(1_u64, -1_i32).unwrap(),
+            Number192::from(10u64)
+                .try_div(Number192::from(100u64))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn div_by_zero_errors() {
+        assert!(Number192::ONE.try_div(Number192::ZERO).is_err());
+        assert!(Number192::ONE.try_ceil_div(Number192::ZERO).is_err());
+        assert!(Number192::ONE.try_rounded_div(Number192::ZERO).is_err());
+        assert!(Number192::ONE.try_rem(Number192::ZERO).is_err());
+    }
+
+    #[test]
+    fn div_rounding() {
+        // 2 / 3 = 0.666666666666|6...
+        let x = Number192::from(2u64);
+        let y = Number192::from(3u64);
+        assert_eq!(
+            x.try_div(y).unwrap(),
+            Number192::from_decimal(666_666_666_666_u64, -12_i32).unwrap()
+        );
+        assert_eq!(
+            x.try_ceil_div(y).unwrap(),
+            Number192::from_decimal(666_666_666_667_u64, -12_i32).unwrap()
+        );
+        assert_eq!(
+            x.try_rounded_div(y).unwrap(),
+            Number192::from_decimal(666_666_666_667_u64, -12_i32).unwrap()
+        );
+
+        // 1 / 3 = 0.333333333333|3...
+        let x = Number192::from(1u64);
+        assert_eq!(
+            x.try_rounded_div(y).unwrap(),
+            Number192::from_decimal(333_333_333_333_u64, -12_i32).unwrap()
+        );
+
+        // no remainder - all three agree
+        let x = Number192::from(10u64);
+        let y = Number192::from(4u64);
+        let expected = Number192::from_decimal(25u64, -1_i32).unwrap();
+        assert_eq!(x.try_div(y).unwrap(), expected);
+        assert_eq!(x.try_ceil_div(y).unwrap(), expected);
+        assert_eq!(x.try_rounded_div(y).unwrap(), expected);
+    }
+
+    #[test]
+    fn mul_rounding() {
+        // 0.000001 * 0.000001 = 1e-12 exactly
+        let x = Number192::from_decimal(1u64, -6_i32).unwrap();
+        assert_eq!(
+            x.try_mul(x).unwrap(),
+            Number192::from_decimal(1u64, -12_i32).unwrap()
+        );
+
+        // 0.0000001 * 0.0000001 = 1e-14, below precision
+        let x = Number192::from_decimal(1u64, -7_i32).unwrap();
+        assert_eq!(x.try_mul(x).unwrap(), Number192::ZERO);
+        assert_eq!(
+            x.try_mul_ceil(x).unwrap(),
+            Number192::from_decimal(1u64, -12_i32).unwrap()
+        );
+    }
+
+    #[test]
+    fn ceil_gt_one() {
+        assert_eq!(
+            Number192::from_decimal(11_u64, -1_i32)
+                .unwrap()
+                .as_u64_ceil(0_i32)
+                .unwrap(),
+            2u64
+        );
+        assert_eq!(
+            Number192::from_decimal(19_u64, -1_i32)
+                .unwrap()
+                .as_u64_ceil(0_i32)
+                .unwrap(),
+            2u64
+        );
+    }
+
+    #[test]
+    fn ceil_lt_one() {
+        assert_eq!(
+            Number192::from_decimal(1_u64, -1_i32)
+                .unwrap()
+                .as_u64_ceil(0_i32)
+                .unwrap(),
+            1u64
+        );
+        assert_eq!(
+            Number192::from_decimal(1_u64, -10_i32)
+                .unwrap()
+                .as_u64_ceil(0_i32)
+                .unwrap(),
+            1u64
+        );
+        assert_eq!(
+            Number192::from_decimal(1_u64, -10_i32)
+                .unwrap()
+                .as_u64(0_i32)
+                .unwrap(),
+            0u64
+        );
+    }
+
+    #[test]
+    fn ceil_of_int() {
+        assert_eq!(
+            Number192::from(1_000_000u64).as_u64_ceil(0_i32).unwrap(),
+            1_000_000u64
+        );
+        assert_eq!(
+            Number192::from(1_000_000u64).as_u64(0_i32).unwrap(),
+            1_000_000u64
+        );
+    }
+
+    #[test]
+    fn as_u64_overflow_errors() {
+        let x = Number192::from(u64::MAX);
+        assert_eq!(x.as_u64(0_i32).unwrap(), u64::MAX);
+
+        let x = x.try_add(Number192::ONE).unwrap();
+        assert!(x.as_u64(0_i32).is_err());
+        assert!(x.as_u64_ceil(0_i32).is_err());
+    }
+
+    #[test]
+    fn mul_overflow_errors() {
+        let x = Number192::from(u128::MAX);
+        assert!(x.try_mul(x).is_err());
+        assert!(x.try_pow(3).is_err());
+    }
+
+    #[test]
+    fn test_pow() {
+        let x = Number192::from(10u64);
+        assert_eq!(x.try_pow(0).unwrap(), Number192::ONE);
+        assert_eq!(x.try_pow(3).unwrap(), Number192::from(1000u64));
+
+        let x = Number192::from_decimal(5u64, -1_i32).unwrap();
+        assert_eq!(
+            x.try_pow(2).unwrap(),
+            Number192::from_decimal(25u64, -2_i32).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_number128() {
+        assert_eq!(Number192::from(Number128::ONE), Number192::ONE);
+        assert_eq!(
+            Number192::from(Number128::from_decimal(1_u64, -3_i32)),
+            Number192::from_decimal(1_u64, -3_i32).unwrap()
+        );
+        assert_eq!(
+            Number192::from(Number128::from(1234u64)),
+            Number192::from(1234u64)
+        );
+    }
+
+    #[test]
+    fn low_decimal_reward_keeps_precision() {
+        // 1 token/s spread over 10M rarity points - Number128 would round this to 0
+        let per_point = Number192::from(1u64)
+            .try_div(Number192::from(10_000_000u64))
+            .unwrap();
+        assert_ne!(per_point, Number192::ZERO);
+
+        let accrued = per_point.try_mul(Number192::from(10_000_000u64)).unwrap();
+        assert_eq!(accrued.as_u64(0_i32).unwrap(), 1);
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!("1000.0", Number192::from(1000_u64).to_string());
+        assert_eq!("1.0", Number192::from(1_u64).to_string());
+        assert_eq!(
+            "0.000000000001",
+            Number192::from_decimal(1_u64, -12_i32).unwrap().to_string()
+        );
+    }
+}
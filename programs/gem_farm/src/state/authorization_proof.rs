@@ -1,4 +1,18 @@
 use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+/// which of the farm's rewards a funder may fund
+#[proc_macros::assert_size(1)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum FundableReward {
+    /// reward a, reward b and any reward slot
+    Any,
+
+    RewardA,
+
+    RewardB,
+}
 
 /// if this PDA exists, this means the funder recorded below has been authorized by the
 /// farm recorded below to fund rewards
@@ -10,6 +24,110 @@ pub struct AuthorizationProof {
 
     pub farm: Pubkey,
 
+    /// max cumulative amount the funder can ever fund (across all mints), 0 = no cap
+    pub max_amount: u64,
+
+    /// funding isn't possible from this ts onwards, 0 = never expires
+    pub expires_ts: u64,
+
+    /// all funding so far, across all rewards. Never goes down (ie is cumulative)
+    pub total_funded: u64,
+
+    pub allowed_reward: FundableReward,
+
     /// reserved for future updates, has to be /8
-    _reserved: [u8; 32],
+    _reserved: [u8; 7],
+}
+
+impl AuthorizationProof {
+    pub fn set_limits(&mut self, max_amount: u64, expires_ts: u64, allowed_reward: FundableReward) {
+        self.max_amount = max_amount;
+        self.expires_ts = expires_ts;
+        self.allowed_reward = allowed_reward;
+    }
+
+    /// reward: RewardA / RewardB when funding the built-in rewards, Any when funding a reward slot
+    pub fn record_funding(
+        &mut self,
+        now_ts: u64,
+        reward: FundableReward,
+        amount: u64,
+    ) -> Result<()> {
+        if self.expires_ts > 0 && now_ts >= self.expires_ts {
+            return Err(error!(ErrorCode::FunderExpired));
+        }
+
+        if self.allowed_reward != FundableReward::Any && self.allowed_reward != reward {
+            return Err(error!(ErrorCode::FunderNotAllowedReward));
+        }
+
+        let total_funded = self.total_funded.try_add(amount)?;
+        if self.max_amount > 0 && total_funded > self.max_amount {
+            return Err(error!(ErrorCode::FundingCapExceeded));
+        }
+
+        self.total_funded = total_funded;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl AuthorizationProof {
+        pub fn limited(max_amount: u64, expires_ts: u64, allowed_reward: FundableReward) -> Self {
+            Self {
+                authorized_funder: Pubkey::default(),
+                farm: Pubkey::default(),
+                max_amount,
+                expires_ts,
+                total_funded: 0,
+                allowed_reward,
+                _reserved: [0; 7],
+            }
+        }
+    }
+
+    #[test]
+    fn test_funding_cap() {
+        let mut proof = AuthorizationProof::limited(1000, 0, FundableReward::Any);
+
+        proof
+            .record_funding(100, FundableReward::RewardA, 600)
+            .unwrap();
+        proof.record_funding(100, FundableReward::Any, 400).unwrap();
+        assert_eq!(proof.total_funded, 1000);
+
+        assert!(proof
+            .record_funding(100, FundableReward::RewardB, 1)
+            .is_err());
+        assert_eq!(proof.total_funded, 1000);
+    }
+
+    #[test]
+    fn test_funding_expiry() {
+        let mut proof = AuthorizationProof::limited(0, 200, FundableReward::Any);
+
+        proof
+            .record_funding(199, FundableReward::RewardA, u64::MAX)
+            .unwrap();
+        assert!(proof
+            .record_funding(200, FundableReward::RewardA, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_funding_allowed_reward() {
+        let mut proof = AuthorizationProof::limited(0, 0, FundableReward::RewardB);
+
+        proof
+            .record_funding(100, FundableReward::RewardB, 10)
+            .unwrap();
+        assert!(proof
+            .record_funding(100, FundableReward::RewardA, 10)
+            .is_err());
+        // reward slots
+        assert!(proof.record_funding(100, FundableReward::Any, 10).is_err());
+    }
 }
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+use crate::state::Farmer;
+
+/// a gem moved into cooldown by partial_unstake, one per (farmer, gem mint)
+/// withdraw_unstaked_gem only lets out gems that have one of these, once their own cooldown is over
+#[proc_macros::assert_size(128)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct CoolingGem {
+    pub farmer: Pubkey,
+
+    pub gem_mint: Pubkey,
+
+    /// Farmer.begin_staking_ts at the time of the partial unstake
+    /// if it no longer matches, the farmer has fully unstaked since and the record is void
+    pub begin_staking_ts: u64,
+
+    pub amount: u64,
+
+    pub rarity_points: u64,
+
+    pub cooldown_ends_ts: u64,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+}
+
+impl CoolingGem {
+    fn is_current(&self, farmer: &Farmer) -> bool {
+        self.amount > 0 && self.begin_staking_ts == farmer.begin_staking_ts
+    }
+
+    /// how many of this mint are already cooling down - these are still in the vault,
+    /// but can't be partially unstaked again
+    pub fn cooling_amount(&self, farmer: &Farmer) -> u64 {
+        if self.is_current(farmer) {
+            self.amount
+        } else {
+            0
+        }
+    }
+
+    /// unstaking more of the same (fungible) gem restarts the cooldown for all of it
+    pub fn record(
+        &mut self,
+        farmer: &Farmer,
+        amount: u64,
+        rarity_points: u64,
+        cooldown_ends_ts: u64,
+    ) -> Result<()> {
+        if !self.is_current(farmer) {
+            self.begin_staking_ts = farmer.begin_staking_ts;
+            self.amount = 0;
+            self.rarity_points = 0;
+        }
+
+        self.amount.try_add_assign(amount)?;
+        self.rarity_points.try_add_assign(rarity_points)?;
+        self.cooldown_ends_ts = cooldown_ends_ts;
+
+        Ok(())
+    }
+
+    /// everything recorded comes out at once - the record is closed right after
+    pub fn withdraw(&mut self, now_ts: u64, farmer: &Farmer, amount: u64) -> Result<()> {
+        if !self.is_current(farmer) {
+            return Err(error!(ErrorCode::NotEnoughGemsCoolingDown));
+        }
+
+        if amount != self.amount {
+            return Err(error!(ErrorCode::AmountMismatch));
+        }
+
+        if now_ts < self.cooldown_ends_ts {
+            return Err(error!(ErrorCode::CooldownNotPassed));
+        }
+
+        self.amount = 0;
+        self.rarity_points = 0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cooling_gem() {
+        let mut farmer = Farmer::unstaked();
+        farmer.begin_staking(0, 100, 3, 30).unwrap();
+
+        let mut gem = CoolingGem::deserialize(&mut &[0; 256][..]).unwrap();
+        gem.record(&farmer, 1, 10, 200).unwrap();
+        gem.record(&farmer, 1, 10, 250).unwrap();
+        assert_eq!(gem.amount, 2);
+        assert_eq!(gem.rarity_points, 20);
+
+        assert!(gem.withdraw(249, &farmer, 2).is_err());
+        assert!(gem.withdraw(250, &farmer, 1).is_err());
+        gem.withdraw(250, &farmer, 2).unwrap();
+        assert!(gem.withdraw(250, &farmer, 0).is_err());
+    }
+
+    #[test]
+    fn test_cooling_gem_void_after_full_unstake() {
+        let mut farmer = Farmer::unstaked();
+        farmer.begin_staking(0, 100, 3, 30).unwrap();
+
+        let mut gem = CoolingGem::deserialize(&mut &[0; 256][..]).unwrap();
+        gem.record(&farmer, 1, 10, 200).unwrap();
+
+        // the farmer fully unstakes (the gem comes out with the rest) and later stakes again
+        farmer.end_staking_begin_cooldown(300, 0).unwrap();
+        farmer.end_cooldown(300).unwrap();
+        farmer.begin_staking(0, 400, 3, 30).unwrap();
+
+        assert!(gem.withdraw(500, &farmer, 1).is_err());
+
+        // a new partial unstake starts from scratch
+        gem.record(&farmer, 1, 10, 500).unwrap();
+        assert_eq!(gem.amount, 1);
+        assert_eq!(gem.begin_staking_ts, 400);
+    }
+}
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 use gem_common::{errors::ErrorCode, *};
 
-use crate::state::*;
+use crate::{number192::Number192, state::*};
 
-pub const LATEST_FARM_VERSION: u16 = 0;
+pub const LATEST_FARM_VERSION: u16 = 1;
 
 #[proc_macros::assert_size(24)]
 #[repr(C)]
@@ -18,6 +18,21 @@ pub struct FarmConfig {
     pub unstaking_fee_lamp: u64,
 }
 
+/// optional fees in the farm's own token, paid into the farm treasury's token account for fee_mint
+/// (on top of the lamport ones - see FarmConfig.unstaking_fee_lamp)
+#[proc_macros::assert_size(48)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct TokenFees {
+    pub fee_mint: Pubkey,
+
+    /// charged whenever a farmer starts staking (stake, restake)
+    pub stake_fee: u64,
+
+    /// charged wherever the lamport unstaking fee is
+    pub unstake_fee: u64,
+}
+
 /// refers to staked counts
 #[proc_macros::assert_size(12)]
 #[repr(C)]
@@ -30,7 +45,89 @@ pub struct MaxCounts {
     pub max_rarity_points: u32,
 }
 
-#[proc_macros::assert_size(1000)] // + 5 to make it /8
+/// where rewards forfeited by an early unstake go
+#[proc_macros::assert_size(4)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum PenaltyDestination {
+    /// variable: split across the remaining stakers, else (or if no one's left) back to pending funds
+    RewardPool,
+
+    /// left in the reward pot until collect_penalties moves it to the treasury's token account
+    Treasury,
+}
+
+/// farmers who unstake before min_tenure_sec forfeit penalty_bps of their outstanding reward_a / reward_b
+/// (and can't claim until they reach it, else the penalty could be dodged by claiming first)
+/// partial unstakes forfeit pro rata to the rarity points taken out
+#[proc_macros::assert_size(24)] // +4 to make it /8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct EarlyUnstakePenalty {
+    /// continuous staking time (see Farmer.begin_staking_ts) after which no penalty applies
+    pub min_tenure_sec: u64,
+
+    /// 0 = no penalty
+    pub penalty_bps: u64,
+
+    pub destination: PenaltyDestination,
+}
+
+impl Default for EarlyUnstakePenalty {
+    fn default() -> Self {
+        Self {
+            min_tenure_sec: 0,
+            penalty_bps: 0,
+            destination: PenaltyDestination::RewardPool,
+        }
+    }
+}
+
+impl EarlyUnstakePenalty {
+    pub fn verify(&self) -> Result<()> {
+        if self.penalty_bps > PENALTY_BPS {
+            return Err(error!(ErrorCode::InvalidUnstakePenalty));
+        }
+        Ok(())
+    }
+
+    pub fn applies_to(&self, now_ts: u64, farmer: &Farmer) -> bool {
+        self.penalty_bps > 0
+            && farmer.state == FarmerState::Staked
+            && now_ts.saturating_sub(farmer.begin_staking_ts) < self.min_tenure_sec
+    }
+
+    /// the penalty for unstaking only part of the farmer's rarity points
+    /// rounds down, in the farmer's favor
+    pub fn pro_rata(&self, rarity_points_unstaked: u64, rarity_points_staked: u64) -> Result<Self> {
+        let penalty_bps = if rarity_points_staked == 0 {
+            0
+        } else {
+            (self.penalty_bps as u128)
+                .try_mul(rarity_points_unstaked as u128)?
+                .try_div(rarity_points_staked as u128)?
+                .try_cast()?
+        };
+
+        Ok(Self {
+            penalty_bps,
+            ..*self
+        })
+    }
+
+    /// rounds down, in the farmer's favor
+    pub fn penalty_on(&self, outstanding_reward: u64) -> Result<u64> {
+        (outstanding_reward as u128)
+            .try_mul(self.penalty_bps as u128)?
+            .try_div(PENALTY_BPS as u128)?
+            .try_cast()
+    }
+}
+
+/// 100%, penalties are expressed in basis points
+pub const PENALTY_BPS: u64 = 10_000;
+
+#[proc_macros::assert_size(1704)] // + 5 to make it /8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
@@ -81,10 +178,81 @@ pub struct Farm {
     // ----------------- extra
     pub max_counts: MaxCounts,
 
+    /// extra reward streams living in their own PDAs (see FarmRewardSlot)
+    pub reward_slot_count: u32,
+
+    /// if set, farmers who restake out of cooldown keep their original begin_staking_ts
+    pub restake_keeps_tenure: bool,
+
+    /// if set, anyone can add tokens to a running variable reward (see top_up_reward)
+    pub allow_public_top_ups: bool,
+
+    /// if set, sensitive ixs need an approved Proposal from the farm's ManagerSet
+    pub requires_approvals: bool,
+
     /// reserved for future updates, has to be /8
     _reserved: [u8; 32],
-    _reserved2: [u8; 16],
-    _reserved3: [u8; 4],
+    _reserved2: [u8; 12],
+    _reserved3: [u8; 1],
+
+    /// sum of all staked farmers' boosted points (see Farmer), only kept while the boost is on
+    /// variable rewards are split across this instead of rarity_points_staked
+    pub boosted_points_staked: u64,
+
+    pub loyalty_boost: LoyaltyBoost,
+
+    /// locks farmers can pick from when they stake, see LockOptions
+    pub lock_options: LockOptions,
+
+    pub early_unstake_penalty: EarlyUnstakePenalty,
+
+    pub token_fees: TokenFees,
+
+    /// min delay between queueing a config / max counts change and it taking effect
+    /// 0 = update_farm changes them instantly, as before (see PendingFarmConfig)
+    /// while > 0, token fees, the early unstake penalty, lock options, the loyalty boost
+    /// and restake_keeps_tenure can't be changed at all
+    pub config_timelock_sec: u64,
+
+    /// reserved for future updates, has to be /8
+    _reserved4: [u8; 24],
+}
+
+impl From<FarmV0> for Farm {
+    fn from(v0: FarmV0) -> Self {
+        Self {
+            version: LATEST_FARM_VERSION,
+            farm_manager: v0.farm_manager,
+            farm_treasury: v0.farm_treasury,
+            farm_authority: v0.farm_authority,
+            farm_authority_seed: v0.farm_authority_seed,
+            farm_authority_bump_seed: v0.farm_authority_bump_seed,
+            bank: v0.bank,
+            config: v0.config,
+            farmer_count: v0.farmer_count,
+            staked_farmer_count: v0.staked_farmer_count,
+            gems_staked: v0.gems_staked,
+            rarity_points_staked: v0.rarity_points_staked,
+            authorized_funder_count: v0.authorized_funder_count,
+            reward_a: v0.reward_a.into(),
+            reward_b: v0.reward_b.into(),
+            max_counts: v0.max_counts,
+            reward_slot_count: 0,
+            restake_keeps_tenure: false,
+            _reserved: [0; 32],
+            _reserved2: [0; 12],
+            allow_public_top_ups: false,
+            requires_approvals: false,
+            _reserved3: [0; 1],
+            boosted_points_staked: 0,
+            loyalty_boost: LoyaltyBoost::default(),
+            lock_options: LockOptions::default(),
+            early_unstake_penalty: EarlyUnstakePenalty::default(),
+            token_fees: TokenFees::default(),
+            config_timelock_sec: 0,
+            _reserved4: [0; 24],
+        }
+    }
 }
 
 impl Farm {
@@ -164,16 +332,190 @@ impl Farm {
         reward.cancel_reward_by_type(now_ts)
     }
 
+    pub fn replace_reward_by_mint(
+        &mut self,
+        now_ts: u64,
+        reward_mint: Pubkey,
+        pot_balance: u64,
+        new_reward_mint: Pubkey,
+        new_reward_pot: Pubkey,
+        new_reward_type: RewardType,
+    ) -> Result<u64> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.replace_reward(
+            now_ts,
+            pot_balance,
+            new_reward_mint,
+            new_reward_pot,
+            new_reward_type,
+        )
+    }
+
+    pub fn top_up_reward_by_mint(
+        &mut self,
+        now_ts: u64,
+        reward_mint: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        if !self.allow_public_top_ups {
+            return Err(error!(ErrorCode::PublicTopUpsDisabled));
+        }
+
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.top_up_reward(now_ts, amount)
+    }
+
+    pub fn sync_pot_by_mint(
+        &mut self,
+        now_ts: u64,
+        reward_mint: Pubkey,
+        pot_balance: u64,
+    ) -> Result<u64> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.sync_pot(now_ts, pot_balance)
+    }
+
+    pub fn set_reward_cap_by_mint(
+        &mut self,
+        now_ts: u64,
+        reward_mint: Pubkey,
+        cap: RewardCap,
+    ) -> Result<()> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.set_farmer_cap(now_ts, cap)
+    }
+
+    pub fn collect_penalties_by_mint(&mut self, reward_mint: Pubkey) -> Result<u64> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.collect_penalties()
+    }
+
+    pub fn record_contribution_by_mint(
+        &mut self,
+        reward_mint: Pubkey,
+        contribution: &mut FunderContribution,
+        amount: u64,
+    ) -> Result<()> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.refunds.record_contribution(contribution, amount)
+    }
+
+    /// returns what goes to the canceller's receiver, the rest is left for funders to claim
+    pub fn close_funding_round_by_mint(
+        &mut self,
+        reward_mint: Pubkey,
+        cancel_amount: u64,
+    ) -> Result<u64> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.refunds.close_round(cancel_amount)
+    }
+
+    pub fn claim_refund_by_mint(
+        &mut self,
+        reward_mint: Pubkey,
+        contribution: &mut FunderContribution,
+    ) -> Result<u64> {
+        let reward = self.match_reward_by_mint(reward_mint)?;
+        reward.refunds.claim_refund(contribution)
+    }
+
+    /// voids any farmer reward state left over from a reward that has since been replaced
+    fn sync_reward_generations(&self, farmer: &mut Account<Farmer>) {
+        farmer.reward_a.sync_generation(self.reward_a.generation);
+        farmer.reward_b.sync_generation(self.reward_b.generation);
+    }
+
+    /// boosted points (see Farmer) are only kept while either boost is configured
+    pub fn tracks_boosted_points(&self) -> bool {
+        self.loyalty_boost.is_enabled() || self.lock_options.is_enabled()
+    }
+
+    /// variable rewards are split by boosted points while they're tracked,
+    /// fixed ones by lock-weighted rarity points (they have their own tenure tiers)
+    fn boost_applies_to(&self, reward_type: RewardType) -> bool {
+        reward_type == RewardType::Variable && self.tracks_boosted_points()
+    }
+
+    fn points_staked_for(&self, reward_type: RewardType) -> u64 {
+        if self.boost_applies_to(reward_type) {
+            self.boosted_points_staked
+        } else {
+            self.rarity_points_staked
+        }
+    }
+
+    fn farmer_points_staked_for(&self, reward_type: RewardType, farmer: &Farmer) -> Result<u64> {
+        if self.boost_applies_to(reward_type) {
+            Ok(farmer.boosted_points_staked)
+        } else {
+            farmer.lock_weighted(farmer.rarity_points_staked)
+        }
+    }
+
+    /// re-snapshots the farmer's boosted points (and the farm total) after any change to their
+    /// rarity points, state or tenure - thresholds crossed since the last refresh only count from now on
+    pub fn update_boosted_points(&mut self, now_ts: u64, farmer: &mut Farmer) -> Result<()> {
+        if !self.tracks_boosted_points() {
+            return Ok(());
+        }
+
+        let boosted_points = match farmer.state {
+            FarmerState::Staked => self.loyalty_boost.boosted_points(
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                now_ts.saturating_sub(farmer.begin_staking_ts),
+            )?,
+            _ => 0,
+        };
+
+        self.boosted_points_staked = self
+            .boosted_points_staked
+            .try_sub(farmer.boosted_points_staked)?
+            .try_add(boosted_points)?;
+        farmer.boosted_points_staked = boosted_points;
+
+        Ok(())
+    }
+
+    /// boosts can be re-tiered at any time (farmers pick new tiers up on refresh, and keep the lock they picked),
+    /// but starting / stopping to track boosted points needs every farmer's snapshot to be 0, ie no one staked
+    fn assert_can_switch_boosts(&self, was_tracking: bool) -> Result<()> {
+        if self.tracks_boosted_points() != was_tracking && self.staked_farmer_count > 0 {
+            return Err(error!(ErrorCode::LoyaltyBoostLocked));
+        }
+        Ok(())
+    }
+
+    pub fn set_loyalty_boost(&mut self, loyalty_boost: LoyaltyBoost) -> Result<()> {
+        loyalty_boost.verify()?;
+
+        let was_tracking = self.tracks_boosted_points();
+        self.loyalty_boost = loyalty_boost;
+        self.assert_can_switch_boosts(was_tracking)
+    }
+
+    pub fn set_lock_options(&mut self, lock_options: LockOptions) -> Result<()> {
+        lock_options.verify()?;
+
+        let was_tracking = self.tracks_boosted_points();
+        self.lock_options = lock_options;
+        self.assert_can_switch_boosts(was_tracking)
+    }
+
     pub fn update_rewards(
         &mut self,
         now_ts: u64,
         mut farmer: Option<&mut Account<Farmer>>,
         reenroll: bool, //relevant for fixed only
     ) -> Result<()> {
+        if let Some(ref mut farmer) = farmer {
+            self.sync_reward_generations(farmer);
+        }
+
         // reward a
+        let farm_points_staked = self.points_staked_for(self.reward_a.reward_type);
         let (farmer_points_staked, farmer_reward_a) = match farmer {
             Some(ref mut farmer) => (
-                Some(farmer.rarity_points_staked),
+                Some(self.farmer_points_staked_for(self.reward_a.reward_type, farmer)?),
                 Some(&mut farmer.reward_a),
             ),
             None => (None, None),
@@ -181,37 +523,87 @@ impl Farm {
 
         self.reward_a.update_accrued_reward_by_type(
             now_ts,
-            self.rarity_points_staked,
+            farm_points_staked,
             farmer_points_staked,
             farmer_reward_a,
             reenroll,
         )?;
 
         // reward b
-        let farmer_reward_b = match farmer {
-            Some(ref mut farmer) => Some(&mut farmer.reward_b),
-            None => None,
+        let farm_points_staked = self.points_staked_for(self.reward_b.reward_type);
+        let (farmer_points_staked, farmer_reward_b) = match farmer {
+            Some(ref mut farmer) => (
+                Some(self.farmer_points_staked_for(self.reward_b.reward_type, farmer)?),
+                Some(&mut farmer.reward_b),
+            ),
+            None => (None, None),
         };
 
         self.reward_b.update_accrued_reward_by_type(
             now_ts,
-            self.rarity_points_staked,
+            farm_points_staked,
             farmer_points_staked,
             farmer_reward_b,
             reenroll,
+        )?;
+
+        // rewards are settled up to now at the old boost, any newly crossed threshold applies from here
+        if let Some(farmer) = farmer {
+            self.update_boosted_points(now_ts, farmer)?;
+        }
+
+        Ok(())
+    }
+
+    /// a farmer that was just init'ed stakes the vault they just deposited into, all in one go
+    pub fn enter_farm(
+        &mut self,
+        now_ts: u64,
+        gems_in_vault: u64,
+        rarity_points_in_vault: u64,
+        farmer: &mut Account<Farmer>,
+        lock_option: Option<u8>,
+    ) -> Result<()> {
+        if gems_in_vault == 0 {
+            return Err(error!(ErrorCode::VaultIsEmpty));
+        }
+
+        self.farmer_count.try_add_assign(1)?;
+
+        // update accrued rewards BEFORE we increment the stake
+        self.update_rewards(now_ts, Some(farmer), true)?;
+
+        self.begin_staking(
+            now_ts,
+            gems_in_vault,
+            rarity_points_in_vault,
+            farmer,
+            lock_option,
         )
     }
 
+    /// lock_option picks one of the farm's lock_options, only possible when a new staking session begins
     pub fn begin_staking(
         &mut self,
         now_ts: u64,
         gems_in_vault: u64,
         rarity_points_in_vault: u64,
         farmer: &mut Account<Farmer>,
+        lock_option: Option<u8>,
     ) -> Result<()> {
+        if farmer.state != FarmerState::Staked {
+            let lock = lock_option
+                .map(|index| self.lock_options.get(index))
+                .transpose()?;
+            farmer.set_lock(lock);
+        } else if lock_option.is_some() {
+            return Err(error!(ErrorCode::CantChangeLock));
+        }
+
         // update farmer
+        let staking_period_sec = farmer.staking_period_sec(self.config.min_staking_period_sec);
         farmer.begin_staking(
-            self.config.min_staking_period_sec,
+            staking_period_sec,
             now_ts,
             gems_in_vault,
             rarity_points_in_vault,
@@ -225,13 +617,15 @@ impl Farm {
 
         self.assert_valid_max_counts()?;
 
+        self.update_boosted_points(now_ts, farmer)?;
+
         // fixed-rate only - we need to do some extra book-keeping
         if self.reward_a.reward_type == RewardType::Fixed {
             self.reward_a.fixed_rate.enroll_farmer(
                 now_ts,
                 &mut self.reward_a.times,
                 &mut self.reward_a.funds,
-                farmer.rarity_points_staked,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
                 &mut farmer.reward_a,
                 None,
             )?;
@@ -242,7 +636,7 @@ impl Farm {
                 now_ts,
                 &mut self.reward_b.times,
                 &mut self.reward_b.funds,
-                farmer.rarity_points_staked,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
                 &mut farmer.reward_b,
                 None,
             )?;
@@ -252,21 +646,29 @@ impl Farm {
     }
 
     pub fn end_staking(&mut self, now_ts: u64, farmer: &mut Account<Farmer>) -> Result<()> {
+        // rewards might have been skipped (see unstake), so we can't rely on update_rewards for this
+        self.sync_reward_generations(farmer);
+
         match farmer.state {
             FarmerState::Unstaked => Ok(msg!("already unstaked!")),
             FarmerState::Staked => {
+                // (!) MUST COME BEFORE FARMER IS UPDATED - WE NEED THE CURRENT TENURE
+                let penalized = self.early_unstake_penalty.applies_to(now_ts, farmer);
+
                 // fixed-rate only - we need to do some extra book-keeping
                 // (!) MUST COME BEFORE FARMER IS UPDATED - WE NEED CURRENT RARITY POINTS AMOUNT
                 if self.reward_a.reward_type == RewardType::Fixed {
-                    self.reward_a
-                        .fixed_rate
-                        .graduate_farmer(farmer.rarity_points_staked, &mut farmer.reward_a)?;
+                    self.reward_a.fixed_rate.graduate_farmer(
+                        farmer.lock_weighted(farmer.rarity_points_staked)?,
+                        &mut farmer.reward_a,
+                    )?;
                 }
 
                 if self.reward_b.reward_type == RewardType::Fixed {
-                    self.reward_b
-                        .fixed_rate
-                        .graduate_farmer(farmer.rarity_points_staked, &mut farmer.reward_b)?;
+                    self.reward_b.fixed_rate.graduate_farmer(
+                        farmer.lock_weighted(farmer.rarity_points_staked)?,
+                        &mut farmer.reward_b,
+                    )?;
                 }
 
                 // update farmer
@@ -279,12 +681,180 @@ impl Farm {
                     .try_sub_assign(rarity_points_unstaked)?;
                 self.gems_staked.try_sub_assign(gems_unstaked)?;
 
+                self.update_boosted_points(now_ts, farmer)?;
+
+                // after the farm's points are updated, so the pool only goes to those still staked
+                if penalized {
+                    let penalty = self.early_unstake_penalty;
+                    self.forfeit_early_unstake_rewards(&penalty, farmer)?;
+                }
+
                 Ok(())
             }
             FarmerState::PendingCooldown => farmer.end_cooldown(now_ts),
         }
     }
 
+    /// the farmer might still have some points staked (partial unstake), they don't share in their own penalty
+    fn forfeit_early_unstake_rewards(
+        &mut self,
+        penalty: &EarlyUnstakePenalty,
+        farmer: &mut Farmer,
+    ) -> Result<()> {
+        let points_a = self
+            .points_staked_for(self.reward_a.reward_type)
+            .try_sub(self.farmer_points_staked_for(self.reward_a.reward_type, farmer)?)?;
+        let forfeited_a = self
+            .reward_a
+            .forfeit_reward(penalty, points_a, &mut farmer.reward_a)?;
+
+        let points_b = self
+            .points_staked_for(self.reward_b.reward_type)
+            .try_sub(self.farmer_points_staked_for(self.reward_b.reward_type, farmer)?)?;
+        let forfeited_b = self
+            .reward_b
+            .forfeit_reward(penalty, points_b, &mut farmer.reward_b)?;
+
+        msg!(
+            "early unstake, forfeited {} reward a and {} reward b",
+            forfeited_a,
+            forfeited_b
+        );
+        Ok(())
+    }
+
+    pub fn set_early_unstake_penalty(&mut self, penalty: EarlyUnstakePenalty) -> Result<()> {
+        penalty.verify()?;
+        self.early_unstake_penalty = penalty;
+        Ok(())
+    }
+
+    /// claiming early would let farmers dodge the penalty
+    pub fn assert_can_claim(&self, now_ts: u64, farmer: &Farmer) -> Result<()> {
+        if self.early_unstake_penalty.applies_to(now_ts, farmer) {
+            return Err(error!(ErrorCode::RewardsStillVesting));
+        }
+        Ok(())
+    }
+
+    pub fn assert_config_not_timelocked(&self) -> Result<()> {
+        if self.config_timelock_sec > 0 {
+            return Err(error!(ErrorCode::ConfigTimelocked));
+        }
+        Ok(())
+    }
+
+    /// raising the timelock is instant, lowering it has to be queued like any other change
+    pub fn raise_config_timelock(&mut self, config_timelock_sec: u64) -> Result<()> {
+        if config_timelock_sec < self.config_timelock_sec {
+            return Err(error!(ErrorCode::ConfigTimelocked));
+        }
+        self.config_timelock_sec = config_timelock_sec;
+        Ok(())
+    }
+
+    /// overwrites anything already queued, with a fresh effective_ts
+    pub fn queue_config_change(
+        &self,
+        now_ts: u64,
+        effective_ts: u64,
+        config: Option<FarmConfig>,
+        max_counts: Option<MaxCounts>,
+        config_timelock_sec: Option<u64>,
+        pending: &mut PendingFarmConfig,
+    ) -> Result<()> {
+        if effective_ts < now_ts.try_add(self.config_timelock_sec)? {
+            return Err(error!(ErrorCode::InvalidEffectiveTs));
+        }
+
+        pending.effective_ts = effective_ts;
+        pending.config = config;
+        pending.max_counts = max_counts;
+        pending.config_timelock_sec = config_timelock_sec;
+
+        Ok(())
+    }
+
+    pub fn apply_config_change(&mut self, now_ts: u64, pending: &PendingFarmConfig) -> Result<()> {
+        if now_ts < pending.effective_ts {
+            return Err(error!(ErrorCode::PendingConfigNotReady));
+        }
+
+        if let Some(config) = pending.config {
+            self.config = config;
+        }
+
+        if let Some(max_counts) = pending.max_counts {
+            self.max_counts = max_counts;
+        }
+
+        if let Some(config_timelock_sec) = pending.config_timelock_sec {
+            self.config_timelock_sec = config_timelock_sec;
+        }
+
+        Ok(())
+    }
+
+    pub fn restake(
+        &mut self,
+        now_ts: u64,
+        gems_in_vault: u64,
+        rarity_points_in_vault: u64,
+        farmer: &mut Account<Farmer>,
+    ) -> Result<()> {
+        // the menu might have been taken down since the farmer picked their lock
+        if !self.lock_options.is_enabled() {
+            farmer.set_lock(None);
+        }
+
+        // update farmer (relocks for the lock they picked, if any)
+        let staking_period_sec = farmer.staking_period_sec(self.config.min_staking_period_sec);
+        farmer.restake(
+            staking_period_sec,
+            now_ts,
+            gems_in_vault,
+            rarity_points_in_vault,
+            self.restake_keeps_tenure,
+        )?;
+
+        // update farm
+        self.staked_farmer_count.try_add_assign(1)?;
+        self.gems_staked.try_add_assign(farmer.gems_staked)?;
+        self.rarity_points_staked
+            .try_add_assign(farmer.rarity_points_staked)?;
+
+        self.assert_valid_max_counts()?;
+
+        self.update_boosted_points(now_ts, farmer)?;
+
+        // fixed-rate only - we need to do some extra book-keeping
+        let begin_staking_ts = farmer.begin_staking_ts;
+
+        if self.reward_a.reward_type == RewardType::Fixed {
+            self.reward_a.fixed_rate.enroll_farmer(
+                now_ts,
+                &mut self.reward_a.times,
+                &mut self.reward_a.funds,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                &mut farmer.reward_a,
+                Some(begin_staking_ts),
+            )?;
+        }
+
+        if self.reward_b.reward_type == RewardType::Fixed {
+            self.reward_b.fixed_rate.enroll_farmer(
+                now_ts,
+                &mut self.reward_b.times,
+                &mut self.reward_b.funds,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                &mut farmer.reward_b,
+                Some(begin_staking_ts),
+            )?;
+        }
+
+        Ok(())
+    }
+
     pub fn stake_extra_gems(
         &mut self,
         now_ts: u64,
@@ -294,9 +864,10 @@ impl Farm {
         extra_rarity_points: u64,
         farmer: &mut Account<Farmer>,
     ) -> Result<()> {
-        // update farmer
+        // update farmer (relocks for the lock they picked, if any)
+        let staking_period_sec = farmer.staking_period_sec(self.config.min_staking_period_sec);
         let (_previous_gems, previous_rarity_points) = farmer.begin_staking(
-            self.config.min_staking_period_sec,
+            staking_period_sec,
             now_ts,
             gems_in_vault,
             rarity_points_in_vault,
@@ -309,20 +880,22 @@ impl Farm {
 
         self.assert_valid_max_counts()?;
 
+        self.update_boosted_points(now_ts, farmer)?;
+
         // fixed-rate only - we need to do some extra book-keeping
         if self.reward_a.reward_type == RewardType::Fixed {
             // graduate with PREVIOUS rarity points count
-            let original_begin_staking_ts = self
-                .reward_a
-                .fixed_rate
-                .graduate_farmer(previous_rarity_points, &mut farmer.reward_a)?;
+            let original_begin_staking_ts = self.reward_a.fixed_rate.graduate_farmer(
+                farmer.lock_weighted(previous_rarity_points)?,
+                &mut farmer.reward_a,
+            )?;
 
             // re-enroll with NEW rarity points count
             self.reward_a.fixed_rate.enroll_farmer(
                 now_ts,
                 &mut self.reward_a.times,
                 &mut self.reward_a.funds,
-                farmer.rarity_points_staked,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
                 &mut farmer.reward_a,
                 Some(original_begin_staking_ts),
             )?;
@@ -330,17 +903,17 @@ impl Farm {
 
         if self.reward_b.reward_type == RewardType::Fixed {
             // graduate with PREVIOUS rarity points count
-            let original_begin_staking_ts = self
-                .reward_b
-                .fixed_rate
-                .graduate_farmer(previous_rarity_points, &mut farmer.reward_b)?;
+            let original_begin_staking_ts = self.reward_b.fixed_rate.graduate_farmer(
+                farmer.lock_weighted(previous_rarity_points)?,
+                &mut farmer.reward_b,
+            )?;
 
             // re-enroll with NEW rarity points count
             self.reward_b.fixed_rate.enroll_farmer(
                 now_ts,
                 &mut self.reward_b.times,
                 &mut self.reward_b.funds,
-                farmer.rarity_points_staked,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
                 &mut farmer.reward_b,
                 Some(original_begin_staking_ts),
             )?;
@@ -348,12 +921,242 @@ impl Farm {
 
         Ok(())
     }
-}
 
-// --------------------------------------- farm reward
-
-#[proc_macros::assert_size(4)]
-#[repr(C)]
+    /// called after one gem has been swapped for another inside a locked vault
+    /// tenure (begin_staking_ts) is only kept if the farmer's rarity didn't go down
+    /// if it did, the points swapped out count as unstaked early (see EarlyUnstakePenalty)
+    pub fn swap_gems(
+        &mut self,
+        now_ts: u64,
+        gems_in_vault: u64,
+        rarity_points_in_vault: u64,
+        farmer: &mut Account<Farmer>,
+    ) -> Result<()> {
+        // (!) MUST COME BEFORE FARMER IS UPDATED - WE NEED THE CURRENT TENURE
+        let penalized = self.early_unstake_penalty.applies_to(now_ts, farmer);
+
+        // update farmer
+        let (previous_gems, previous_rarity_points) =
+            farmer.swap_gems(now_ts, gems_in_vault, rarity_points_in_vault)?;
+
+        // swapping down is a partial unstake that skips cooldown, same as flash_unstake_gems
+        let keep_tenure = farmer.rarity_points_staked >= previous_rarity_points;
+        if !keep_tenure {
+            if self.config.cooldown_period_sec > 0 {
+                return Err(error!(ErrorCode::CooldownPeriodNotZero));
+            }
+
+            // a new tenure would void the records of any gems still cooling down (see CoolingGem)
+            if farmer.gems_cooling_down > 0 {
+                return Err(error!(ErrorCode::GemsCoolingDown));
+            }
+
+            farmer.begin_staking_ts = now_ts;
+        }
+
+        // update farm
+        self.gems_staked.try_sub_assign(previous_gems)?;
+        self.gems_staked.try_add_assign(gems_in_vault)?;
+        self.rarity_points_staked
+            .try_sub_assign(previous_rarity_points)?;
+        self.rarity_points_staked
+            .try_add_assign(rarity_points_in_vault)?;
+
+        self.assert_valid_max_counts()?;
+
+        self.update_boosted_points(now_ts, farmer)?;
+
+        // fixed-rate only - we need to do some extra book-keeping
+        if self.reward_a.reward_type == RewardType::Fixed {
+            // graduate with PREVIOUS rarity points count
+            let original_begin_staking_ts = self.reward_a.fixed_rate.graduate_farmer(
+                farmer.lock_weighted(previous_rarity_points)?,
+                &mut farmer.reward_a,
+            )?;
+
+            // re-enroll with NEW rarity points count
+            self.reward_a.fixed_rate.enroll_farmer(
+                now_ts,
+                &mut self.reward_a.times,
+                &mut self.reward_a.funds,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                &mut farmer.reward_a,
+                keep_tenure.then_some(original_begin_staking_ts),
+            )?;
+        }
+
+        if self.reward_b.reward_type == RewardType::Fixed {
+            // graduate with PREVIOUS rarity points count
+            let original_begin_staking_ts = self.reward_b.fixed_rate.graduate_farmer(
+                farmer.lock_weighted(previous_rarity_points)?,
+                &mut farmer.reward_b,
+            )?;
+
+            // re-enroll with NEW rarity points count
+            self.reward_b.fixed_rate.enroll_farmer(
+                now_ts,
+                &mut self.reward_b.times,
+                &mut self.reward_b.funds,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                &mut farmer.reward_b,
+                keep_tenure.then_some(original_begin_staking_ts),
+            )?;
+        }
+
+        // same as unstake_gems, but only for the points swapped out
+        if !keep_tenure && penalized {
+            let penalty = self.early_unstake_penalty.pro_rata(
+                previous_rarity_points.try_sub(farmer.rarity_points_staked)?,
+                previous_rarity_points,
+            )?;
+            self.forfeit_early_unstake_rewards(&penalty, farmer)?;
+        }
+
+        Ok(())
+    }
+
+    /// unstakes the gems and releases them in one go, only possible on farms with no cooldown
+    /// cooling_gem is the mint's CoolingGem, if it exists
+    /// returns true if the farmer ended up fully unstaked (vault no longer needs to be locked)
+    pub fn flash_unstake_gems(
+        &mut self,
+        now_ts: u64,
+        gems_to_unstake: u64,
+        rarity_points_to_unstake: u64,
+        farmer: &mut Account<Farmer>,
+        cooling_gem: Option<&CoolingGem>,
+    ) -> Result<bool> {
+        if self.config.cooldown_period_sec > 0 {
+            return Err(error!(ErrorCode::CooldownPeriodNotZero));
+        }
+
+        if farmer.state != FarmerState::Staked {
+            return Err(error!(ErrorCode::FarmerNotStaked));
+        }
+
+        // those are already out of the stake, we can't tell them apart from the ones being withdrawn
+        if cooling_gem.map_or(0, |gem| gem.cooling_amount(farmer)) > 0 {
+            return Err(error!(ErrorCode::GemsCoolingDown));
+        }
+
+        // last gem(s) out - go through the full unstake flow (staked -> cooldown -> unstaked)
+        if gems_to_unstake >= farmer.gems_staked {
+            self.end_staking(now_ts, farmer)?;
+            self.end_staking(now_ts, farmer)?;
+            return Ok(true);
+        }
+
+        self.unstake_gems(now_ts, gems_to_unstake, rarity_points_to_unstake, farmer)?;
+        farmer.withdraw_cooled_down_gems(gems_to_unstake, rarity_points_to_unstake)?;
+
+        Ok(false)
+    }
+
+    /// partially unstakes gems of a single mint, recording them on that mint's CoolingGem
+    /// gems_deposited is the vault's deposit receipt count for the mint, incl. any already cooling down
+    pub fn partial_unstake_gem(
+        &mut self,
+        now_ts: u64,
+        gems_deposited: u64,
+        gems_to_unstake: u64,
+        rarity_points_to_unstake: u64,
+        farmer: &mut Account<Farmer>,
+        cooling_gem: &mut CoolingGem,
+    ) -> Result<()> {
+        let still_staked = gems_deposited.try_sub(cooling_gem.cooling_amount(farmer))?;
+        if gems_to_unstake > still_staked {
+            return Err(error!(ErrorCode::AmountMismatch));
+        }
+
+        self.unstake_gems(now_ts, gems_to_unstake, rarity_points_to_unstake, farmer)?;
+
+        cooling_gem.record(
+            farmer,
+            gems_to_unstake,
+            rarity_points_to_unstake,
+            now_ts.try_add(self.config.cooldown_period_sec)?,
+        )
+    }
+
+    /// the reverse of stake_extra_gems - some gems go into cooldown, the rest keep earning
+    pub fn unstake_gems(
+        &mut self,
+        now_ts: u64,
+        gems_to_unstake: u64,
+        rarity_points_to_unstake: u64,
+        farmer: &mut Account<Farmer>,
+    ) -> Result<()> {
+        // (!) MUST COME BEFORE FARMER IS UPDATED - WE NEED THE CURRENT TENURE
+        let penalized = self.early_unstake_penalty.applies_to(now_ts, farmer);
+
+        // update farmer
+        let previous_rarity_points = farmer.unstake_gems_begin_cooldown(
+            now_ts,
+            self.config.cooldown_period_sec,
+            gems_to_unstake,
+            rarity_points_to_unstake,
+        )?;
+
+        // update farm
+        self.gems_staked.try_sub_assign(gems_to_unstake)?;
+        self.rarity_points_staked
+            .try_sub_assign(rarity_points_to_unstake)?;
+
+        self.update_boosted_points(now_ts, farmer)?;
+
+        // fixed-rate only - we need to do some extra book-keeping
+        if self.reward_a.reward_type == RewardType::Fixed {
+            // graduate with PREVIOUS rarity points count
+            let original_begin_staking_ts = self.reward_a.fixed_rate.graduate_farmer(
+                farmer.lock_weighted(previous_rarity_points)?,
+                &mut farmer.reward_a,
+            )?;
+
+            // re-enroll with NEW rarity points count
+            self.reward_a.fixed_rate.enroll_farmer(
+                now_ts,
+                &mut self.reward_a.times,
+                &mut self.reward_a.funds,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                &mut farmer.reward_a,
+                Some(original_begin_staking_ts),
+            )?;
+        }
+
+        if self.reward_b.reward_type == RewardType::Fixed {
+            // graduate with PREVIOUS rarity points count
+            let original_begin_staking_ts = self.reward_b.fixed_rate.graduate_farmer(
+                farmer.lock_weighted(previous_rarity_points)?,
+                &mut farmer.reward_b,
+            )?;
+
+            // re-enroll with NEW rarity points count
+            self.reward_b.fixed_rate.enroll_farmer(
+                now_ts,
+                &mut self.reward_b.times,
+                &mut self.reward_b.funds,
+                farmer.lock_weighted(farmer.rarity_points_staked)?,
+                &mut farmer.reward_b,
+                Some(original_begin_staking_ts),
+            )?;
+        }
+
+        // same as end_staking, but only for the share of points taken out
+        if penalized {
+            let penalty = self
+                .early_unstake_penalty
+                .pro_rata(rarity_points_to_unstake, previous_rarity_points)?;
+            self.forfeit_early_unstake_rewards(&penalty, farmer)?;
+        }
+
+        Ok(())
+    }
+}
+
+// --------------------------------------- farm reward
+
+#[proc_macros::assert_size(4)]
+#[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub enum RewardType {
     Variable,
@@ -380,7 +1183,98 @@ impl FundsTracker {
     }
 }
 
-#[proc_macros::assert_size(24)]
+/// per-funder refunds (see FunderContribution)
+/// funding is tracked in rounds - each cancellation closes the current one, and what it refunds is
+/// split across that round's funders, pro rata to what they contributed (claimed via claim_refund)
+#[proc_macros::assert_size(48)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct RefundTracker {
+    /// bumped by every cancellation that has contributions to refund
+    pub funding_round: u64,
+
+    /// contributed by all funders during the current round
+    pub round_contributions: u64,
+
+    /// the next 4 fields describe the last closed round
+    pub refund_amount: u64,
+
+    pub refund_contributions: u64,
+
+    /// contributions / tokens not yet claimed back by their funders
+    pub unclaimed_contributions: u64,
+
+    pub unclaimed_refund: u64,
+}
+
+impl RefundTracker {
+    pub fn record_contribution(
+        &mut self,
+        contribution: &mut FunderContribution,
+        amount: u64,
+    ) -> Result<()> {
+        // otherwise funders who haven't claimed yet would get mixed up with the new round
+        if self.unclaimed_contributions > 0 {
+            return Err(error!(ErrorCode::RefundsPending));
+        }
+
+        if contribution.funding_round != self.funding_round {
+            contribution.funding_round = self.funding_round;
+            contribution.contributed = 0;
+        }
+
+        contribution.contributed.try_add_assign(amount)?;
+        self.round_contributions.try_add_assign(amount)
+    }
+
+    /// returns what's left for the receiver - only funding from before contributions were tracked
+    pub fn close_round(&mut self, cancel_amount: u64) -> Result<u64> {
+        if self.round_contributions == 0 {
+            return Ok(cancel_amount);
+        }
+
+        if self.unclaimed_contributions > 0 {
+            return Err(error!(ErrorCode::RefundsPending));
+        }
+
+        self.refund_amount = cancel_amount;
+        self.refund_contributions = self.round_contributions;
+        self.unclaimed_contributions = self.round_contributions;
+        self.unclaimed_refund = cancel_amount;
+
+        self.round_contributions = 0;
+        self.funding_round.try_add_assign(1)?;
+
+        Ok(0)
+    }
+
+    /// rounds down, except for the last funder to claim who gets whatever is left over
+    pub fn claim_refund(&mut self, contribution: &mut FunderContribution) -> Result<u64> {
+        if contribution.contributed == 0
+            || contribution.funding_round.try_add(1)? != self.funding_round
+        {
+            return Err(error!(ErrorCode::NoRefundDue));
+        }
+
+        let refund = if contribution.contributed == self.unclaimed_contributions {
+            self.unclaimed_refund
+        } else {
+            (self.refund_amount as u128)
+                .try_mul(contribution.contributed as u128)?
+                .try_div(self.refund_contributions as u128)?
+                .try_cast()?
+        };
+
+        self.unclaimed_contributions
+            .try_sub_assign(contribution.contributed)?;
+        self.unclaimed_refund.try_sub_assign(refund)?;
+        contribution.contributed = 0;
+
+        Ok(refund)
+    }
+}
+
+#[proc_macros::assert_size(32)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct TimeTracker {
@@ -388,6 +1282,9 @@ pub struct TimeTracker {
     /// updated with each new funding round
     pub duration_sec: u64,
 
+    /// nothing accrues before this - can be in the future if funding was scheduled ahead of time
+    pub reward_begin_ts: u64,
+
     pub reward_end_ts: u64,
 
     /// this will be set = to reward_end_ts if farm manager decides to lock up their reward
@@ -395,17 +1292,45 @@ pub struct TimeTracker {
     pub lock_end_ts: u64,
 }
 
+impl From<TimeTrackerV0> for TimeTracker {
+    fn from(v0: TimeTrackerV0) -> Self {
+        Self {
+            duration_sec: v0.duration_sec,
+            reward_begin_ts: v0.reward_end_ts.saturating_sub(v0.duration_sec),
+            reward_end_ts: v0.reward_end_ts,
+            lock_end_ts: v0.lock_end_ts,
+        }
+    }
+}
+
 impl TimeTracker {
-    pub fn reward_begin_ts(&self) -> Result<u64> {
-        self.reward_end_ts.try_sub(self.duration_sec)
+    /// starts a new funding round, at start_ts if passed, else right away
+    pub fn begin_reward(
+        &mut self,
+        now_ts: u64,
+        start_ts: Option<u64>,
+        duration_sec: u64,
+    ) -> Result<()> {
+        let begin_ts = start_ts.unwrap_or(now_ts);
+        if begin_ts < now_ts {
+            return Err(error!(ErrorCode::RewardStartInPast));
+        }
+
+        self.duration_sec = duration_sec;
+        self.reward_begin_ts = begin_ts;
+        self.reward_end_ts = begin_ts.try_add(duration_sec)?;
+
+        Ok(())
     }
 
+    /// time left from whichever comes last - now or the beginning of the reward
     pub fn remaining_duration(&self, now_ts: u64) -> Result<u64> {
         if now_ts >= self.reward_end_ts {
             return Ok(0);
         }
 
-        self.reward_end_ts.try_sub(now_ts)
+        self.reward_end_ts
+            .try_sub(std::cmp::max(now_ts, self.reward_begin_ts))
     }
 
     pub fn passed_duration(&self, now_ts: u64) -> Result<u64> {
@@ -416,31 +1341,33 @@ impl TimeTracker {
         self.duration_sec
             .try_sub_assign(self.remaining_duration(now_ts)?)?;
         self.reward_end_ts = std::cmp::min(now_ts, self.reward_end_ts);
+        // a scheduled reward cancelled before it began never begins
+        self.reward_begin_ts = std::cmp::min(self.reward_begin_ts, self.reward_end_ts);
 
         Ok(())
     }
 
-    /// returns whichever comes first - now or the end of the reward
+    /// the point in time up to which the reward can be counted as applied
+    /// whichever comes first - now or the end of the reward, but never before the beginning
     pub fn reward_upper_bound(&self, now_ts: u64) -> u64 {
-        std::cmp::min(self.reward_end_ts, now_ts)
+        std::cmp::max(
+            self.reward_begin_ts,
+            std::cmp::min(self.reward_end_ts, now_ts),
+        )
     }
 
     /// returns whichever comes last - beginning of the reward, or beginning of farmer's staking
     pub fn reward_lower_bound(&self, farmer_begin_staking_ts: u64) -> Result<u64> {
-        Ok(std::cmp::max(
-            self.reward_begin_ts()?,
-            farmer_begin_staking_ts,
-        ))
+        Ok(std::cmp::max(self.reward_begin_ts, farmer_begin_staking_ts))
     }
 }
 
-#[proc_macros::assert_size(352)] // +4  to make it /8
+#[proc_macros::assert_size(576)] // +4  to make it /8
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct FarmReward {
-    /// in v0 the next 3 fields (mint, pot type) are set ONLY once, at farm init
-    ///   and can't ever be changed for security reasons
-    ///   potentially in v1++ might find a way around it, but for now just use a new farm
+    /// the next 3 fields (mint, pot type) are set at farm init
+    ///   and can only be changed by replace_reward, once the reward is ended, unlocked and drained
     pub reward_mint: Pubkey,
 
     /// where the reward is stored
@@ -457,26 +1384,216 @@ pub struct FarmReward {
 
     pub times: TimeTracker,
 
+    /// bumped every time the reward is replaced, farmers on an older generation get voided
+    pub generation: u64,
+
+    /// early unstake penalties (see EarlyUnstakePenalty) sitting in the pot, owed to the treasury
+    /// both are cumulative, the difference is what collect_penalties can still move
+    pub total_forfeited_to_treasury: u64,
+
+    pub total_paid_to_treasury: u64,
+
+    pub refunds: RefundTracker,
+
+    /// claimed by farmers so far, only tracked since this was added (see sync_pot)
+    /// never goes down (ie is cumulative)
+    pub total_paid_out: u64,
+
+    /// optional caps on what a single farmer can accrue from this reward
+    pub farmer_cap: RewardCap,
+
+    /// upper bound on what variable accrual has rounded away - it's ceiled at the farm level and
+    /// floored at the farmer level, < 1 each per update. Never goes down (ie is cumulative)
+    /// lets replace_reward tell rounding dust apart from rewards farmers haven't claimed yet
+    pub max_rounding_dust: u64,
+
     /// reserved for future updates, has to be /8
-    _reserved: [u8; 32],
+    _reserved: [u8; 16],
+}
+
+impl From<FarmRewardV0> for FarmReward {
+    fn from(v0: FarmRewardV0) -> Self {
+        Self {
+            reward_mint: v0.reward_mint,
+            reward_pot: v0.reward_pot,
+            reward_type: v0.reward_type,
+            fixed_rate: v0.fixed_rate.into(),
+            variable_rate: v0.variable_rate.into(),
+            funds: v0.funds,
+            times: v0.times.into(),
+            generation: 0,
+            total_forfeited_to_treasury: 0,
+            total_paid_to_treasury: 0,
+            refunds: RefundTracker::default(),
+            total_paid_out: 0,
+            farmer_cap: RewardCap::default(),
+            max_rounding_dust: 0,
+            _reserved: [0; 16],
+        }
+    }
 }
 
 impl FarmReward {
+    /// swaps the reward for a new mint / type, starting from a clean slate
+    /// only possible once nothing can accrue, be reserved or be refunded anymore
+    /// farmers' claims are voided by the replacement, so they all have to have been paid out first
+    /// returns how much of the old pot is untracked surplus, ie free to sweep
+    /// (!) claims from before total_paid_out was tracked count as unclaimed, blocking replacement
+    pub fn replace_reward(
+        &mut self,
+        now_ts: u64,
+        pot_balance: u64,
+        new_reward_mint: Pubkey,
+        new_reward_pot: Pubkey,
+        new_reward_type: RewardType,
+    ) -> Result<u64> {
+        let pending_amount = self
+            .funds
+            .total_funded
+            .saturating_sub(self.funds.total_refunded)
+            .saturating_sub(self.funds.total_accrued_to_stakers);
+
+        if self.is_locked(now_ts)
+            || now_ts < self.times.reward_end_ts
+            || pending_amount > 0
+            || self.fixed_rate.reserved_amount > 0
+            || self.penalties_owed_to_treasury()? > 0
+            || self.refunds.unclaimed_contributions > 0
+            || self.unclaimed_by_farmers() > self.max_rounding_dust
+        {
+            return Err(error!(ErrorCode::RewardNotDrained));
+        }
+
+        // what's left tracked is rounding dust at most, which stays in the old pot
+        let surplus = pot_balance.saturating_sub(self.expected_pot_balance()?);
+
+        self.reward_mint = new_reward_mint;
+        self.reward_pot = new_reward_pot;
+        self.reward_type = new_reward_type;
+
+        // denominated in the old mint
+        self.farmer_cap = RewardCap::default();
+
+        self.fixed_rate.schedule = FixedRateSchedule::default(); //denom to 1
+        self.variable_rate.reward_rate = Number192::ZERO;
+        self.variable_rate.reward_last_updated_ts = 0;
+        self.variable_rate.accrued_reward_per_rarity_point = Number192::ZERO;
+
+        self.funds = FundsTracker {
+            total_funded: 0,
+            total_refunded: 0,
+            total_accrued_to_stakers: 0,
+        };
+        self.times = TimeTracker {
+            duration_sec: 0,
+            reward_begin_ts: 0,
+            reward_end_ts: 0,
+            lock_end_ts: 0,
+        };
+        self.total_forfeited_to_treasury = 0;
+        self.total_paid_to_treasury = 0;
+        self.refunds = RefundTracker::default();
+        self.total_paid_out = 0;
+        self.max_rounding_dust = 0;
+
+        self.generation.try_add_assign(1)?;
+
+        Ok(surplus)
+    }
+
+    /// accrued to farmers but neither claimed nor forfeited to the treasury yet
+    /// an upper bound - variable accrual is overestimated at the farm level (see max_rounding_dust)
+    pub fn unclaimed_by_farmers(&self) -> u64 {
+        self.funds
+            .total_accrued_to_stakers
+            .saturating_sub(self.total_paid_out)
+            .saturating_sub(self.total_forfeited_to_treasury)
+    }
+
+    pub fn penalties_owed_to_treasury(&self) -> Result<u64> {
+        self.total_forfeited_to_treasury
+            .try_sub(self.total_paid_to_treasury)
+    }
+
+    /// records everything owed to the treasury as paid, returns the amount to transfer
+    pub fn collect_penalties(&mut self) -> Result<u64> {
+        let owed = self.penalties_owed_to_treasury()?;
+        self.total_paid_to_treasury.try_add_assign(owed)?;
+        Ok(owed)
+    }
+
+    /// takes the penalty out of the farmer's outstanding reward and sends it to its destination
+    /// farm_points_staked has to already exclude the farmer's (remaining) points
+    pub fn forfeit_reward(
+        &mut self,
+        penalty: &EarlyUnstakePenalty,
+        farm_points_staked: u64,
+        farmer_reward: &mut FarmerReward,
+    ) -> Result<u64> {
+        let forfeited = penalty.penalty_on(farmer_reward.outstanding_reward()?)?;
+        if forfeited == 0 {
+            return Ok(0);
+        }
+
+        farmer_reward.forfeited_reward.try_add_assign(forfeited)?;
+
+        match penalty.destination {
+            PenaltyDestination::Treasury => {
+                self.total_forfeited_to_treasury.try_add_assign(forfeited)?;
+            }
+            // counted as accrued to stakers already, so it just gets re-split among those left
+            PenaltyDestination::RewardPool
+                if self.reward_type == RewardType::Variable && farm_points_staked > 0 =>
+            {
+                self.variable_rate
+                    .accrued_reward_per_rarity_point
+                    .try_add_assign(
+                        Number192::from(forfeited).try_div(Number192::from(farm_points_staked))?,
+                    )?;
+
+                // a farmer still partially staked doesn't get a cut of their own penalty
+                farmer_reward
+                    .variable_rate
+                    .last_recorded_accrued_reward_per_rarity_point =
+                    self.variable_rate.accrued_reward_per_rarity_point;
+            }
+            // back to pending - available for enrolling farmers (fixed), the next funding round or refunds
+            PenaltyDestination::RewardPool => {
+                self.funds
+                    .total_accrued_to_stakers
+                    .try_sub_assign(forfeited)?;
+            }
+        }
+
+        Ok(forfeited)
+    }
+
     /// (!) THIS OPERATION IS IRREVERSIBLE
     /// locking ensures the committed reward cannot be withdrawn/changed by a malicious farm operator
     /// once locked, any funding / cancellation ixs become non executable until reward_ned_ts is reached
-    fn lock_reward(&mut self) -> Result<()> {
+    pub fn lock_reward(&mut self) -> Result<()> {
         self.times.lock_end_ts = self.times.reward_end_ts;
 
         // msg!("locked reward up to {}", self.times.reward_end_ts);
         Ok(())
     }
 
-    fn is_locked(&self, now_ts: u64) -> bool {
+    /// a locked reward is a commitment to stakers, so its caps can't change either
+    pub fn set_farmer_cap(&mut self, now_ts: u64, cap: RewardCap) -> Result<()> {
+        if self.is_locked(now_ts) {
+            return Err(error!(ErrorCode::RewardLocked));
+        }
+
+        cap.verify()?;
+        self.farmer_cap = cap;
+        Ok(())
+    }
+
+    pub fn is_locked(&self, now_ts: u64) -> bool {
         now_ts < self.times.lock_end_ts
     }
 
-    fn fund_reward_by_type(
+    pub fn fund_reward_by_type(
         &mut self,
         now_ts: u64,
         variable_rate_config: Option<VariableRateConfig>,
@@ -502,7 +1619,58 @@ impl FarmReward {
         }
     }
 
-    fn cancel_reward_by_type(&mut self, now_ts: u64) -> Result<u64> {
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid_out.try_add_assign(amount)
+    }
+
+    /// what the pot should be holding - owed to farmers, pending, unclaimed refunds and penalties
+    pub fn expected_pot_balance(&self) -> Result<u64> {
+        self.funds
+            .total_funded
+            .try_sub(self.funds.total_refunded)?
+            .try_add(self.refunds.unclaimed_refund)?
+            .try_sub(self.total_paid_out)?
+            .try_sub(self.total_paid_to_treasury)
+    }
+
+    /// folds tokens sent straight to the pot into the reward, returns how many
+    /// (!) claims from before total_paid_out was tracked will show up as a deficit
+    /// they go into the running emission if possible, else they're left pending for the next funding
+    pub fn sync_pot(&mut self, now_ts: u64, pot_balance: u64) -> Result<u64> {
+        let expected = self.expected_pot_balance()?;
+        if pot_balance < expected {
+            msg!("pot holds {}, expected at least {}", pot_balance, expected);
+            return Err(error!(ErrorCode::RewardPotDeficit));
+        }
+
+        let surplus = pot_balance.try_sub(expected)?;
+        if surplus == 0 {
+            return Ok(0);
+        }
+
+        if self.reward_type == RewardType::Variable
+            && self.variable_rate.can_top_up(now_ts, &self.times)?
+        {
+            self.variable_rate
+                .top_up(now_ts, &mut self.times, &mut self.funds, surplus)?;
+        } else {
+            self.funds.total_funded.try_add_assign(surplus)?;
+        }
+
+        Ok(surplus)
+    }
+
+    /// unlike funding, allowed on locked rewards - it can only ever add to what stakers get
+    pub fn top_up_reward(&mut self, now_ts: u64, amount: u64) -> Result<()> {
+        if self.reward_type != RewardType::Variable {
+            return Err(error!(ErrorCode::InvalidFundingMode));
+        }
+
+        self.variable_rate
+            .top_up(now_ts, &mut self.times, &mut self.funds, amount)
+    }
+
+    pub fn cancel_reward_by_type(&mut self, now_ts: u64) -> Result<u64> {
         if self.is_locked(now_ts) {
             return Err(error!(ErrorCode::RewardLocked));
         }
@@ -519,7 +1687,7 @@ impl FarmReward {
         }
     }
 
-    fn update_accrued_reward_by_type(
+    pub fn update_accrued_reward_by_type(
         &mut self,
         now_ts: u64,
         farm_rarity_points_staked: u64,
@@ -528,14 +1696,21 @@ impl FarmReward {
         reenroll: bool,
     ) -> Result<()> {
         match self.reward_type {
-            RewardType::Variable => self.variable_rate.update_accrued_reward(
-                now_ts,
-                &self.times,
-                &mut self.funds,
-                farm_rarity_points_staked,
-                farmer_rarity_points_staked,
-                farmer_reward,
-            ),
+            RewardType::Variable => {
+                // farm-level ceil, plus farmer-level floor & cap overflow redistribution
+                let rounding = if farmer_reward.is_some() { 3 } else { 1 };
+                self.max_rounding_dust.try_add_assign(rounding)?;
+
+                self.variable_rate.update_accrued_reward(
+                    now_ts,
+                    &self.times,
+                    &mut self.funds,
+                    &self.farmer_cap,
+                    farm_rarity_points_staked,
+                    farmer_rarity_points_staked,
+                    farmer_reward,
+                )
+            }
             RewardType::Fixed => {
                 // for fixed rewards we only update if Farmer has been passed
                 if farmer_reward.is_none() {
@@ -546,6 +1721,7 @@ impl FarmReward {
                     now_ts,
                     &mut self.times,
                     &mut self.funds,
+                    &self.farmer_cap,
                     farmer_rarity_points_staked.unwrap(),
                     farmer_reward.unwrap(),
                     reenroll,
@@ -559,10 +1735,21 @@ impl FarmReward {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_farm_from_v0() {
+        let data = [0; FARM_V0_LEN - 8];
+        let farm = Farm::from(FarmV0::deserialize(&mut &data[..]).unwrap());
+
+        assert_eq!(farm.version, LATEST_FARM_VERSION);
+        assert_eq!(farm.reward_a.times.reward_begin_ts, 0);
+        assert_eq!(farm.config_timelock_sec, 0);
+    }
+
     #[test]
     fn test_time_tracker() {
         let times = TimeTracker {
             duration_sec: 100,
+            reward_begin_ts: 100,
             reward_end_ts: 200,
             lock_end_ts: 0,
         };
@@ -572,7 +1759,6 @@ mod tests {
         assert_eq!(30, times.passed_duration(130).unwrap());
         assert_eq!(199, times.reward_upper_bound(199));
         assert_eq!(200, times.reward_upper_bound(201));
-        assert_eq!(100, times.reward_begin_ts().unwrap());
         assert_eq!(110, times.reward_lower_bound(110).unwrap());
     }
 
@@ -580,6 +1766,7 @@ mod tests {
     fn test_time_tracker_end_reward() {
         let mut times = TimeTracker {
             duration_sec: 80,
+            reward_begin_ts: 120,
             reward_end_ts: 200,
             lock_end_ts: 0,
         };
@@ -594,6 +1781,35 @@ mod tests {
         assert_eq!(times.reward_end_ts, 140);
     }
 
+    #[test]
+    fn test_time_tracker_scheduled() {
+        let mut times = TimeTracker {
+            duration_sec: 0,
+            reward_begin_ts: 0,
+            reward_end_ts: 0,
+            lock_end_ts: 0,
+        };
+
+        assert!(times.begin_reward(100, Some(99), 100).is_err());
+
+        times.begin_reward(100, Some(150), 100).unwrap();
+        assert_eq!(times.reward_begin_ts, 150);
+        assert_eq!(times.reward_end_ts, 250);
+
+        // nothing passes / accrues before the beginning
+        assert_eq!(100, times.remaining_duration(120).unwrap());
+        assert_eq!(0, times.passed_duration(120).unwrap());
+        assert_eq!(150, times.reward_upper_bound(120));
+        assert_eq!(70, times.remaining_duration(180).unwrap());
+        assert_eq!(180, times.reward_upper_bound(180));
+
+        // cancelled before it began
+        times.end_reward(120).unwrap();
+        assert_eq!(times.duration_sec, 0);
+        assert_eq!(times.reward_begin_ts, 120);
+        assert_eq!(times.reward_end_ts, 120);
+    }
+
     #[test]
     fn test_funds_tracker() {
         let funds = FundsTracker {
@@ -604,4 +1820,518 @@ mod tests {
 
         assert_eq!(20, funds.pending_amount().unwrap());
     }
+
+    /// runs f against an Account<Farmer> backed by a throwaway AccountInfo
+    fn with_farmer_account<R>(farmer: &Farmer, f: impl FnOnce(&mut Account<Farmer>) -> R) -> R {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = vec![];
+        farmer.try_serialize(&mut data).unwrap();
+        let info = AccountInfo::new(
+            &key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &crate::ID,
+            false,
+            0,
+        );
+        let mut account = Account::<Farmer>::try_from(&info).unwrap();
+        f(&mut account)
+    }
+
+    fn farm_with_staked_farmer(min_staking_period_sec: u64) -> (Farm, Farmer) {
+        let mut farm = Farm::deserialize(&mut &[0; 2048][..]).unwrap();
+        let mut farmer = Farmer::unstaked();
+        farmer
+            .begin_staking(min_staking_period_sec, 0, 3, 30)
+            .unwrap();
+        farm.staked_farmer_count = 1;
+        farm.gems_staked = 3;
+        farm.rarity_points_staked = 30;
+        (farm, farmer)
+    }
+
+    fn farm_with_fixed_reward(total_funded: u64) -> Farm {
+        let mut farm = Farm::deserialize(&mut &[0; 2048][..]).unwrap();
+        farm.reward_a.reward_type = RewardType::Fixed;
+        farm.reward_a.fixed_rate.schedule = FixedRateSchedule::new_base(3, 1);
+        farm.reward_a.times = TimeTracker {
+            duration_sec: 100,
+            reward_begin_ts: 0,
+            reward_end_ts: 100,
+            lock_end_ts: 0,
+        };
+        farm.reward_a.funds.total_funded = total_funded;
+        farm
+    }
+
+    fn new_farmer() -> Farmer {
+        let mut farmer = Farmer::deserialize(&mut &[0; 2048][..]).unwrap();
+        farmer.init(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        farmer
+    }
+
+    #[test]
+    fn test_enter_farm() {
+        let mut farm = farm_with_fixed_reward(1000);
+
+        with_farmer_account(&new_farmer(), |farmer| {
+            assert!(farm.enter_farm(10, 0, 0, farmer, None).is_err());
+            assert_eq!(farm.farmer_count, 0);
+
+            farm.enter_farm(10, 1, 3, farmer, None).unwrap();
+            assert!(farmer.state == FarmerState::Staked);
+            assert_eq!(farmer.gems_staked, 1);
+            assert_eq!(farmer.rarity_points_staked, 3);
+            assert_eq!(farmer.begin_staking_ts, 10);
+            assert_eq!(farm.farmer_count, 1);
+            assert_eq!(farm.staked_farmer_count, 1);
+            assert_eq!(farm.gems_staked, 1);
+            assert_eq!(farm.rarity_points_staked, 3);
+
+            // enrolled for the remaining 90s of the fixed-rate reward, at 3 per point per sec
+            let fixed_rate = &farmer.reward_a.fixed_rate;
+            assert_eq!(fixed_rate.begin_staking_ts, 10);
+            assert_eq!(fixed_rate.promised_duration, 90);
+            assert_eq!(farm.reward_a.fixed_rate.reserved_amount, 3 * 3 * 90);
+        });
+    }
+
+    #[test]
+    fn test_enter_farm_with_lock() {
+        let mut farm = farm_with_fixed_reward(10_000);
+        farm.set_lock_options(
+            LockOptions::new(&[LockOption {
+                lock_sec: 50,
+                multiplier_bps: 20000,
+            }])
+            .unwrap(),
+        )
+        .unwrap();
+
+        with_farmer_account(&new_farmer(), |farmer| {
+            assert!(farm.enter_farm(10, 1, 3, farmer, Some(1)).is_err());
+        });
+
+        with_farmer_account(&new_farmer(), |farmer| {
+            farm.enter_farm(10, 1, 3, farmer, Some(0)).unwrap();
+            assert_eq!(farmer.lock_period_sec, 50);
+            assert_eq!(farmer.lock_multiplier_bps, 20000);
+            assert_eq!(farmer.min_staking_ends_ts, 60);
+
+            // the fixed-rate reserve is for the lock-weighted points
+            assert_eq!(farm.reward_a.fixed_rate.reserved_amount, 2 * 3 * 3 * 90);
+        });
+    }
+
+    #[test]
+    fn test_enter_farm_underfunded() {
+        let mut farm = farm_with_fixed_reward(100);
+
+        with_farmer_account(&new_farmer(), |farmer| {
+            assert!(farm.enter_farm(10, 1, 3, farmer, None).is_err());
+        });
+    }
+
+    #[test]
+    fn test_flash_unstake_gems() {
+        let (mut farm, farmer) = farm_with_staked_farmer(0);
+
+        with_farmer_account(&farmer, |farmer| {
+            // some gems out, the rest keep earning
+            assert!(!farm.flash_unstake_gems(10, 1, 10, farmer, None).unwrap());
+            assert_eq!(farmer.gems_staked, 2);
+            assert_eq!(farmer.rarity_points_staked, 20);
+            assert_eq!(farmer.gems_cooling_down, 0);
+            assert_eq!(farmer.rarity_points_cooling_down, 0);
+            assert_eq!(farm.gems_staked, 2);
+            assert_eq!(farm.rarity_points_staked, 20);
+            assert_eq!(farm.staked_farmer_count, 1);
+
+            // last gems out - straight through to unstaked
+            assert!(farm.flash_unstake_gems(10, 2, 20, farmer, None).unwrap());
+            assert!(farmer.state == FarmerState::Unstaked);
+            assert_eq!(farmer.gems_staked, 0);
+            assert_eq!(farm.gems_staked, 0);
+            assert_eq!(farm.rarity_points_staked, 0);
+            assert_eq!(farm.staked_farmer_count, 0);
+
+            // nothing left to withdraw
+            assert!(farm.flash_unstake_gems(10, 1, 10, farmer, None).is_err());
+        });
+    }
+
+    #[test]
+    fn test_flash_unstake_gems_respects_cooldown_and_lock() {
+        let (mut farm, farmer) = farm_with_staked_farmer(100);
+
+        with_farmer_account(&farmer, |farmer| {
+            assert!(farm.flash_unstake_gems(50, 1, 10, farmer, None).is_err());
+            assert!(farm.flash_unstake_gems(50, 3, 30, farmer, None).is_err());
+
+            farm.config.cooldown_period_sec = 10;
+            assert!(farm.flash_unstake_gems(100, 1, 10, farmer, None).is_err());
+
+            farm.config.cooldown_period_sec = 0;
+            assert!(!farm.flash_unstake_gems(100, 1, 10, farmer, None).unwrap());
+        });
+    }
+
+    #[test]
+    fn test_partial_unstake_gem_twice() {
+        let (mut farm, farmer) = farm_with_staked_farmer(0);
+        farm.config.cooldown_period_sec = 50;
+        let mut cooling_gem = CoolingGem::deserialize(&mut &[0; 256][..]).unwrap();
+
+        // 2 of the farmer's 3 gems are of this mint
+        with_farmer_account(&farmer, |farmer| {
+            assert!(farm
+                .partial_unstake_gem(10, 2, 3, 30, farmer, &mut cooling_gem)
+                .is_err());
+
+            farm.partial_unstake_gem(10, 2, 1, 10, farmer, &mut cooling_gem)
+                .unwrap();
+            assert_eq!(cooling_gem.amount, 1);
+            assert_eq!(farmer.gems_cooling_down, 1);
+
+            // the receipt still counts the gem that's cooling down, it can't be unstaked again
+            assert!(farm
+                .partial_unstake_gem(20, 2, 2, 20, farmer, &mut cooling_gem)
+                .is_err());
+
+            farm.partial_unstake_gem(20, 2, 1, 10, farmer, &mut cooling_gem)
+                .unwrap();
+            assert_eq!(cooling_gem.amount, 2);
+            assert_eq!(cooling_gem.cooldown_ends_ts, 70);
+            assert_eq!(farmer.gems_staked, 1);
+            assert_eq!(farmer.gems_cooling_down, 2);
+            assert_eq!(farm.gems_staked, 1);
+
+            assert!(farm
+                .partial_unstake_gem(30, 2, 1, 10, farmer, &mut cooling_gem)
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn test_flash_unstake_gems_with_gems_cooling_down() {
+        let (mut farm, farmer) = farm_with_staked_farmer(0);
+        let mut cooling_gem = CoolingGem::deserialize(&mut &[0; 256][..]).unwrap();
+
+        with_farmer_account(&farmer, |farmer| {
+            farm.partial_unstake_gem(10, 2, 1, 10, farmer, &mut cooling_gem)
+                .unwrap();
+
+            assert_eq!(
+                farm.flash_unstake_gems(10, 1, 10, farmer, Some(&cooling_gem))
+                    .unwrap_err(),
+                error!(ErrorCode::GemsCoolingDown)
+            );
+
+            // once they're withdrawn, the rest of the mint can be flash withdrawn
+            cooling_gem.withdraw(10, farmer, 1).unwrap();
+            farmer.withdraw_cooled_down_gems(1, 10).unwrap();
+            assert!(!farm
+                .flash_unstake_gems(10, 1, 10, farmer, Some(&cooling_gem))
+                .unwrap());
+            assert_eq!(farmer.gems_staked, 1);
+        });
+    }
+
+    #[test]
+    fn test_swap_gems() {
+        let (mut farm, farmer) = farm_with_staked_farmer(0);
+        farm.early_unstake_penalty = penalty(6000, PenaltyDestination::Treasury);
+
+        with_farmer_account(&farmer, |farmer| {
+            farmer.begin_staking_ts = 10;
+            farmer.reward_a.accrued_reward = 100;
+
+            // swapping up keeps the tenure, nothing is forfeited
+            farm.swap_gems(20, 3, 40, farmer).unwrap();
+            assert_eq!(farmer.begin_staking_ts, 10);
+            assert_eq!(farm.rarity_points_staked, 40);
+            assert_eq!(farmer.reward_a.forfeited_reward, 0);
+
+            // swapping down early forfeits the share of the points swapped out, and restarts the tenure
+            farm.swap_gems(30, 3, 10, farmer).unwrap();
+            assert_eq!(farmer.begin_staking_ts, 30);
+            assert_eq!(farm.rarity_points_staked, 10);
+            assert_eq!(farmer.reward_a.forfeited_reward, 45); //6000 bps * 30/40 of 100
+            assert_eq!(farm.reward_a.total_forfeited_to_treasury, 45);
+
+            // (each failed swap still leaves the farmer updated, the tx would revert that)
+            farm.config.cooldown_period_sec = 10;
+            assert_eq!(
+                farm.swap_gems(100, 3, 8, farmer).unwrap_err(),
+                error!(ErrorCode::CooldownPeriodNotZero)
+            );
+
+            // with gems cooling down, their records would go stale
+            farm.config.cooldown_period_sec = 0;
+            farmer.gems_cooling_down = 1;
+            assert_eq!(
+                farm.swap_gems(100, 3, 5, farmer).unwrap_err(),
+                error!(ErrorCode::GemsCoolingDown)
+            );
+        });
+    }
+
+    fn zeroed_reward(reward_type: RewardType) -> FarmReward {
+        let mut reward = FarmReward::deserialize(&mut &[0; 1024][..]).unwrap();
+        reward.reward_type = reward_type;
+        reward.funds.total_funded = 1000;
+        reward.funds.total_accrued_to_stakers = 400;
+        reward
+    }
+
+    fn farmer_reward(accrued_reward: u64, paid_out_reward: u64) -> FarmerReward {
+        let mut farmer_reward = FarmerReward::new();
+        farmer_reward.accrued_reward = accrued_reward;
+        farmer_reward.paid_out_reward = paid_out_reward;
+        farmer_reward
+    }
+
+    fn penalty(penalty_bps: u64, destination: PenaltyDestination) -> EarlyUnstakePenalty {
+        EarlyUnstakePenalty {
+            min_tenure_sec: 50,
+            penalty_bps,
+            destination,
+        }
+    }
+
+    #[test]
+    fn test_early_unstake_penalty() {
+        let mut farmer = Farmer::unstaked();
+        farmer.state = FarmerState::Staked;
+        farmer.begin_staking_ts = 100;
+
+        let p = penalty(2500, PenaltyDestination::RewardPool);
+        assert!(p.applies_to(149, &farmer));
+        assert!(!p.applies_to(150, &farmer));
+        assert!(!penalty(0, PenaltyDestination::RewardPool).applies_to(149, &farmer));
+        farmer.state = FarmerState::PendingCooldown;
+        assert!(!p.applies_to(149, &farmer));
+
+        assert_eq!(p.penalty_on(999).unwrap(), 249); //249.75 rounded down
+        assert!(p.verify().is_ok());
+        assert!(penalty(10_001, PenaltyDestination::RewardPool)
+            .verify()
+            .is_err());
+    }
+
+    #[test]
+    fn test_early_unstake_penalty_pro_rata() {
+        let p = penalty(2500, PenaltyDestination::Treasury);
+
+        // unstaking 3 of 10 rarity points only forfeits 30% of the penalty
+        let partial = p.pro_rata(3, 10).unwrap();
+        assert_eq!(partial.penalty_bps, 750);
+        assert_eq!(partial.min_tenure_sec, p.min_tenure_sec);
+        assert_eq!(partial.destination, p.destination);
+        assert_eq!(partial.penalty_on(1000).unwrap(), 75);
+
+        assert_eq!(p.pro_rata(10, 10).unwrap(), p);
+        assert_eq!(p.pro_rata(1, 3).unwrap().penalty_bps, 833); //833.33 rounded down
+        assert_eq!(p.pro_rata(0, 0).unwrap().penalty_bps, 0);
+    }
+
+    #[test]
+    fn test_forfeit_reward_to_remaining_stakers() {
+        let mut reward = zeroed_reward(RewardType::Variable);
+        let mut farmer_reward = farmer_reward(400, 100);
+        let p = penalty(5000, PenaltyDestination::RewardPool);
+
+        assert_eq!(
+            reward.forfeit_reward(&p, 10, &mut farmer_reward).unwrap(),
+            150
+        );
+        assert_eq!(farmer_reward.outstanding_reward().unwrap(), 150);
+        assert_eq!(
+            reward.variable_rate.accrued_reward_per_rarity_point,
+            Number192::from(15u64)
+        );
+        assert_eq!(reward.funds.total_accrued_to_stakers, 400);
+
+        // if still partially staked, the farmer doesn't share in their own penalty
+        assert_eq!(
+            farmer_reward
+                .variable_rate
+                .last_recorded_accrued_reward_per_rarity_point,
+            Number192::from(15u64)
+        );
+    }
+
+    #[test]
+    fn test_forfeit_reward_to_pending() {
+        // fixed, or variable with no one left to split it
+        for reward_type in [RewardType::Fixed, RewardType::Variable] {
+            let mut reward = zeroed_reward(reward_type);
+            let mut farmer_reward = farmer_reward(400, 100);
+            let p = penalty(5000, PenaltyDestination::RewardPool);
+
+            assert_eq!(
+                reward.forfeit_reward(&p, 0, &mut farmer_reward).unwrap(),
+                150
+            );
+            assert_eq!(reward.funds.total_accrued_to_stakers, 250);
+            assert_eq!(reward.funds.pending_amount().unwrap(), 750);
+        }
+    }
+
+    #[test]
+    fn test_forfeit_reward_to_treasury() {
+        let mut reward = zeroed_reward(RewardType::Variable);
+        let mut farmer_reward = farmer_reward(400, 100);
+        let p = penalty(5000, PenaltyDestination::Treasury);
+
+        assert_eq!(
+            reward.forfeit_reward(&p, 10, &mut farmer_reward).unwrap(),
+            150
+        );
+        assert_eq!(
+            reward.variable_rate.accrued_reward_per_rarity_point,
+            Number192::ZERO
+        );
+        assert_eq!(reward.funds.total_accrued_to_stakers, 400);
+
+        assert_eq!(reward.collect_penalties().unwrap(), 150);
+        assert_eq!(reward.collect_penalties().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_sync_pot() {
+        let mut reward = zeroed_reward(RewardType::Fixed);
+        reward.record_payout(300).unwrap();
+        reward.total_forfeited_to_treasury = 50;
+        reward.collect_penalties().unwrap();
+
+        assert_eq!(reward.expected_pot_balance().unwrap(), 650);
+        assert_eq!(reward.sync_pot(100, 650).unwrap(), 0);
+
+        // surplus is left pending, for the next funding
+        assert_eq!(reward.sync_pot(100, 850).unwrap(), 200);
+        assert_eq!(reward.funds.total_funded, 1200);
+        assert_eq!(reward.funds.pending_amount().unwrap(), 800);
+
+        assert!(reward.sync_pot(100, 849).is_err());
+    }
+
+    fn drained_reward(reward_type: RewardType) -> FarmReward {
+        let mut reward = zeroed_reward(reward_type);
+        reward.funds.total_funded = 400;
+        reward.record_payout(300).unwrap();
+        reward.total_forfeited_to_treasury = 100;
+        reward.collect_penalties().unwrap();
+        reward
+    }
+
+    #[test]
+    fn test_replace_drained_reward() {
+        let mut reward = drained_reward(RewardType::Fixed);
+
+        // only the surplus on top of what's tracked gets swept
+        let surplus = reward
+            .replace_reward(
+                100,
+                50,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                RewardType::Variable,
+            )
+            .unwrap();
+        assert_eq!(surplus, 50);
+        assert_eq!(reward.generation, 1);
+        assert_eq!(reward.reward_type, RewardType::Variable);
+        assert_eq!(reward.funds.total_funded, 0);
+    }
+
+    #[test]
+    fn test_replace_undrained_reward() {
+        // 50 accrued to farmers but not claimed yet
+        let mut reward = drained_reward(RewardType::Fixed);
+        reward.total_paid_out = 250;
+        assert!(reward
+            .replace_reward(
+                100,
+                100,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                RewardType::Fixed
+            )
+            .is_err());
+
+        // variable accrual can be off by rounding dust, but no more
+        let mut reward = drained_reward(RewardType::Variable);
+        reward.total_paid_out = 298;
+        reward.max_rounding_dust = 1;
+        assert!(reward
+            .replace_reward(
+                100,
+                100,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                RewardType::Fixed
+            )
+            .is_err());
+
+        reward.max_rounding_dust = 2;
+        let surplus = reward
+            .replace_reward(
+                100,
+                100,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                RewardType::Fixed,
+            )
+            .unwrap();
+        assert_eq!(surplus, 98); //the dust stays in the pot
+
+        // still running
+        let mut reward = drained_reward(RewardType::Fixed);
+        reward.times.reward_end_ts = 200;
+        assert!(reward
+            .replace_reward(
+                100,
+                0,
+                Pubkey::new_unique(),
+                Pubkey::new_unique(),
+                RewardType::Fixed
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_config_timelock() {
+        let mut farm = Farm::deserialize(&mut &[0; 2048][..]).unwrap();
+        let mut pending = PendingFarmConfig::deserialize(&mut &[0; 256][..]).unwrap();
+        let config = FarmConfig {
+            min_staking_period_sec: 100,
+            cooldown_period_sec: 50,
+            unstaking_fee_lamp: 0,
+        };
+
+        farm.raise_config_timelock(1000).unwrap();
+        assert!(farm.assert_config_not_timelocked().is_err());
+        assert!(farm.raise_config_timelock(999).is_err());
+
+        // has to be at least the timelock away
+        assert!(farm
+            .queue_config_change(100, 1099, Some(config), None, Some(0), &mut pending)
+            .is_err());
+        farm.queue_config_change(100, 1100, Some(config), None, Some(0), &mut pending)
+            .unwrap();
+
+        assert!(farm.apply_config_change(1099, &pending).is_err());
+        farm.apply_config_change(1100, &pending).unwrap();
+        assert_eq!(farm.config.min_staking_period_sec, 100);
+        assert_eq!(farm.max_counts.max_farmers, 0); //left unchanged
+        assert!(farm.assert_config_not_timelocked().is_ok());
+    }
 }
@@ -1,7 +1,15 @@
 use anchor_lang::prelude::*;
 use gem_common::{errors::ErrorCode, *};
 
-use crate::{number128::Number128, state::FixedRateSchedule};
+use crate::{
+    number192::Number192,
+    state::{
+        apply_lock_multiplier, FarmerFixedRateRewardV0, FarmerRewardV0, FarmerV0,
+        FarmerVariableRateRewardV0, FixedRateSchedule, LockOption, RewardCap,
+    },
+};
+
+pub const LATEST_FARMER_VERSION: u16 = 1;
 
 #[proc_macros::assert_size(4)]
 #[repr(C)]
@@ -12,11 +20,13 @@ pub enum FarmerState {
     PendingCooldown,
 }
 
-#[proc_macros::assert_size(600)] // +4 to make it /8
+#[proc_macros::assert_size(1016)] // +4 to make it /8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
 pub struct Farmer {
+    pub version: u16,
+
     pub farm: Pubkey,
 
     /// the identity of the farmer = their public key
@@ -44,11 +54,76 @@ pub struct Farmer {
 
     pub reward_b: FarmerReward,
 
+    // ----------------- partial unstaking
+    /// gems that have been unstaked while the rest of the vault keeps earning
+    /// they sit in the (still locked) vault until their own cooldown is over (see CoolingGem)
+    pub gems_cooling_down: u64,
+
+    pub rarity_points_cooling_down: u64,
+
+    /// when the most recent partial cooldown ends
+    pub partial_cooldown_ends_ts: u64,
+
+    /// when the farmer last went from unstaked to staked, kept across restakes if the farm allows it
+    pub begin_staking_ts: u64,
+
+    /// rarity points x loyalty boost, as of the farmer's last refresh (0 unless staked & the boost is on)
+    /// what the farmer's share of variable rewards is based on, see Farm.loyalty_boost
+    pub boosted_points_staked: u64,
+
+    /// the lock the farmer picked when they started staking (0 = none, the farm's min_staking_period_sec applies)
+    pub lock_period_sec: u64,
+
+    /// comes with the lock (0 = none, ie 1x), applies to all reward types until the farmer fully unstakes
+    pub lock_multiplier_bps: u64,
+
     /// reserved for future updates, has to be /8
-    _reserved: [u8; 32],
+    _reserved: [u8; 64],
+    _reserved2: [u8; 32],
+    _reserved3: [u8; 8],
+}
+
+impl From<FarmerV0> for Farmer {
+    fn from(v0: FarmerV0) -> Self {
+        Self {
+            version: LATEST_FARMER_VERSION,
+            farm: v0.farm,
+            identity: v0.identity,
+            vault: v0.vault,
+            state: v0.state,
+            gems_staked: v0.gems_staked,
+            rarity_points_staked: v0.rarity_points_staked,
+            min_staking_ends_ts: v0.min_staking_ends_ts,
+            cooldown_ends_ts: v0.cooldown_ends_ts,
+            reward_a: v0.reward_a.into(),
+            reward_b: v0.reward_b.into(),
+            gems_cooling_down: 0,
+            rarity_points_cooling_down: 0,
+            partial_cooldown_ends_ts: 0,
+            // wasn't tracked, see Farmer::restake
+            begin_staking_ts: 0,
+            boosted_points_staked: 0,
+            lock_period_sec: 0,
+            lock_multiplier_bps: 0,
+            _reserved: [0; 64],
+            _reserved2: [0; 32],
+            _reserved3: [0; 8],
+        }
+    }
 }
 
 impl Farmer {
+    /// a brand new farmer, see init_farmer / enter_farm
+    pub fn init(&mut self, farm: Pubkey, identity: Pubkey, vault: Pubkey) {
+        self.version = LATEST_FARMER_VERSION;
+        self.farm = farm;
+        self.identity = identity;
+        self.vault = vault;
+        self.reward_a.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
+        self.reward_b.fixed_rate.promised_schedule = FixedRateSchedule::default();
+        //denom to 1
+    }
+
     pub fn begin_staking(
         &mut self,
         min_staking_period_sec: u64,
@@ -56,18 +131,138 @@ impl Farmer {
         gems_in_vault: u64,
         rarity_points_in_vault: u64,
     ) -> Result<(u64, u64)> {
+        if self.state != FarmerState::Staked {
+            self.begin_staking_ts = now_ts;
+        }
+
         self.state = FarmerState::Staked;
 
         let previous_gems_staked = self.gems_staked;
         let previous_rarity_points_staked = self.rarity_points_staked;
-        self.gems_staked = gems_in_vault;
-        self.rarity_points_staked = rarity_points_in_vault;
+        self.gems_staked = gems_in_vault.try_sub(self.gems_cooling_down)?;
+        self.rarity_points_staked =
+            rarity_points_in_vault.try_sub(self.rarity_points_cooling_down)?;
         self.min_staking_ends_ts = now_ts.try_add(min_staking_period_sec)?;
         self.cooldown_ends_ts = 0; //zero it out in case it was set before
 
         Ok((previous_gems_staked, previous_rarity_points_staked))
     }
 
+    /// cancels a pending cooldown - the gems never left the vault, so we stake whatever is in there
+    pub fn restake(
+        &mut self,
+        min_staking_period_sec: u64,
+        now_ts: u64,
+        gems_in_vault: u64,
+        rarity_points_in_vault: u64,
+        keep_tenure: bool,
+    ) -> Result<()> {
+        if self.state != FarmerState::PendingCooldown {
+            return Err(error!(ErrorCode::FarmerNotCoolingDown));
+        }
+
+        self.state = FarmerState::Staked;
+
+        self.gems_staked = gems_in_vault.try_sub(self.gems_cooling_down)?;
+        self.rarity_points_staked =
+            rarity_points_in_vault.try_sub(self.rarity_points_cooling_down)?;
+        self.min_staking_ends_ts = now_ts.try_add(min_staking_period_sec)?;
+        self.cooldown_ends_ts = 0;
+
+        // farmers who began staking before begin_staking_ts was tracked have it at 0 -
+        // there's no tenure to keep, or they'd be credited from the epoch
+        if !keep_tenure || self.begin_staking_ts == 0 {
+            self.begin_staking_ts = now_ts;
+        }
+
+        Ok(())
+    }
+
+    /// unlike begin_staking, doesn't touch min_staking_ends_ts - the farmer never stopped staking
+    /// if rarity points drop, the difference is effectively unstaked, so the farmer's lock has to be over
+    pub fn swap_gems(
+        &mut self,
+        now_ts: u64,
+        gems_in_vault: u64,
+        rarity_points_in_vault: u64,
+    ) -> Result<(u64, u64)> {
+        if self.state != FarmerState::Staked {
+            return Err(error!(ErrorCode::FarmerNotStaked));
+        }
+
+        let rarity_points_staked =
+            rarity_points_in_vault.try_sub(self.rarity_points_cooling_down)?;
+        if rarity_points_staked < self.rarity_points_staked && !self.can_end_staking(now_ts) {
+            return Err(error!(ErrorCode::MinStakingNotPassed));
+        }
+
+        let previous_gems_staked = self.gems_staked;
+        let previous_rarity_points_staked = self.rarity_points_staked;
+        self.gems_staked = gems_in_vault.try_sub(self.gems_cooling_down)?;
+        self.rarity_points_staked = rarity_points_staked;
+
+        Ok((previous_gems_staked, previous_rarity_points_staked))
+    }
+
+    /// moves some (but never all) of the staked gems into cooldown
+    /// (!) starting a new partial cooldown resets the timer for gems already cooling down
+    pub fn unstake_gems_begin_cooldown(
+        &mut self,
+        now_ts: u64,
+        cooldown_period_sec: u64,
+        gems_to_unstake: u64,
+        rarity_points_to_unstake: u64,
+    ) -> Result<u64> {
+        if self.state != FarmerState::Staked {
+            return Err(error!(ErrorCode::FarmerNotStaked));
+        }
+
+        if !self.can_end_staking(now_ts) {
+            return Err(error!(ErrorCode::MinStakingNotPassed));
+        }
+
+        // unstaking everything has to go through the normal unstake flow
+        if gems_to_unstake >= self.gems_staked {
+            return Err(error!(ErrorCode::CantPartiallyUnstakeAllGems));
+        }
+
+        let previous_rarity_points_staked = self.rarity_points_staked;
+        self.gems_staked.try_sub_assign(gems_to_unstake)?;
+        self.rarity_points_staked
+            .try_sub_assign(rarity_points_to_unstake)?;
+
+        self.gems_cooling_down.try_add_assign(gems_to_unstake)?;
+        self.rarity_points_cooling_down
+            .try_add_assign(rarity_points_to_unstake)?;
+        self.partial_cooldown_ends_ts = now_ts.try_add(cooldown_period_sec)?;
+
+        Ok(previous_rarity_points_staked)
+    }
+
+    /// per-gem cooldown is checked by the caller (see CoolingGem), this only keeps the totals
+    pub fn withdraw_cooled_down_gems(
+        &mut self,
+        gems_to_withdraw: u64,
+        rarity_points_to_withdraw: u64,
+    ) -> Result<()> {
+        // once fully unstaked, gems come out through the normal vault withdrawal
+        if self.state != FarmerState::Staked {
+            return Err(error!(ErrorCode::FarmerNotStaked));
+        }
+
+        if gems_to_withdraw > self.gems_cooling_down
+            || rarity_points_to_withdraw > self.rarity_points_cooling_down
+        {
+            return Err(error!(ErrorCode::NotEnoughGemsCoolingDown));
+        }
+
+        self.gems_cooling_down.try_sub_assign(gems_to_withdraw)?;
+        self.rarity_points_cooling_down
+            .try_sub_assign(rarity_points_to_withdraw)?;
+
+        Ok(())
+    }
+
     pub fn end_staking_begin_cooldown(
         &mut self,
         now_ts: u64,
@@ -105,6 +300,10 @@ impl Farmer {
         self.rarity_points_staked = 0;
         self.min_staking_ends_ts = 0;
         self.cooldown_ends_ts = 0;
+        self.gems_cooling_down = 0;
+        self.rarity_points_cooling_down = 0;
+        self.partial_cooldown_ends_ts = 0;
+        self.set_lock(None);
 
         // msg!(
         //     "gems now unstaked and available for withdrawal for {}",
@@ -113,6 +312,27 @@ impl Farmer {
         Ok(())
     }
 
+    pub fn set_lock(&mut self, lock: Option<LockOption>) {
+        let lock = lock.unwrap_or_default();
+        self.lock_period_sec = lock.lock_sec;
+        self.lock_multiplier_bps = lock.multiplier_bps;
+    }
+
+    /// the picked lock replaces the farm-wide minimum
+    pub fn staking_period_sec(&self, min_staking_period_sec: u64) -> u64 {
+        if self.lock_period_sec > 0 {
+            self.lock_period_sec
+        } else {
+            min_staking_period_sec
+        }
+    }
+
+    /// what the farmer's rarity points count for, given the lock they picked
+    pub fn lock_weighted(&self, rarity_points: u64) -> Result<u64> {
+        apply_lock_multiplier(rarity_points, self.lock_multiplier_bps)
+    }
+
+    /// enforces the farmer's lock, see staking_period_sec
     fn can_end_staking(&self, now_ts: u64) -> bool {
         now_ts >= self.min_staking_ends_ts
     }
@@ -124,7 +344,7 @@ impl Farmer {
 
 // --------------------------------------- farmer reward
 
-#[proc_macros::assert_size(216)]
+#[proc_macros::assert_size(360)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct FarmerReward {
@@ -139,13 +359,65 @@ pub struct FarmerReward {
 
     pub fixed_rate: FarmerFixedRateReward,
 
+    /// mirrors FarmReward.generation, if they differ the farm reward has been replaced
+    pub generation: u64,
+
+    /// total lost to early unstake penalties, never claimable. Never goes down (ie is cumulative)
+    pub forfeited_reward: u64,
+
+    /// the current period under FarmReward.farmer_cap, and what's been accrued during it
+    pub cap_period_start_ts: u64,
+
+    pub cap_period_accrued: u64,
+
+    /// when accrual was last settled against the cap, so it can be spread over the periods since
+    pub cap_settled_ts: u64,
+
     /// reserved for future updates, has to be /8
     _reserved: [u8; 32],
 }
 
+impl From<FarmerRewardV0> for FarmerReward {
+    fn from(v0: FarmerRewardV0) -> Self {
+        Self {
+            paid_out_reward: v0.paid_out_reward,
+            accrued_reward: v0.accrued_reward,
+            variable_rate: v0.variable_rate.into(),
+            fixed_rate: v0.fixed_rate.into(),
+            generation: 0,
+            forfeited_reward: 0,
+            cap_period_start_ts: 0,
+            cap_period_accrued: 0,
+            cap_settled_ts: 0,
+            _reserved: [0; 32],
+        }
+    }
+}
+
 impl FarmerReward {
+    /// the farm replaced this reward since we last saw it - anything tied to the old stream is void
+    pub fn sync_generation(&mut self, reward_generation: u64) {
+        if self.generation == reward_generation {
+            return;
+        }
+
+        self.paid_out_reward = 0;
+        self.accrued_reward = 0;
+        self.forfeited_reward = 0;
+        self.cap_period_start_ts = 0;
+        self.cap_period_accrued = 0;
+        self.cap_settled_ts = 0;
+        self.variable_rate
+            .last_recorded_accrued_reward_per_rarity_point = Number192::ZERO;
+        self.fixed_rate = FarmerFixedRateReward::default();
+        self.fixed_rate.promised_schedule = FixedRateSchedule::default(); //denom to 1
+        self.generation = reward_generation;
+    }
+
     pub fn outstanding_reward(&self) -> Result<u64> {
-        self.accrued_reward.try_sub(self.paid_out_reward)
+        self.accrued_reward
+            .try_sub(self.paid_out_reward)?
+            .try_sub(self.forfeited_reward)
     }
 
     pub fn claim_reward(&mut self, pot_balance: u64) -> Result<u64> {
@@ -157,10 +429,114 @@ impl FarmerReward {
         Ok(to_claim)
     }
 
+    /// returns how much of newly_accrued_reward fits under the cap, the rest is the caller's to place
+    pub fn apply_cap(
+        &mut self,
+        cap: &RewardCap,
+        now_ts: u64,
+        newly_accrued_reward: u64,
+    ) -> Result<u64> {
+        // kept current even while uncapped, so a cap enabled later only sees accrual from then on
+        let settled_ts = std::cmp::max(self.cap_settled_ts, self.cap_period_start_ts);
+        self.cap_settled_ts = now_ts;
+
+        if !cap.is_enabled() {
+            return Ok(newly_accrued_reward);
+        }
+
+        let mut allowed = newly_accrued_reward;
+
+        if cap.max_lifetime > 0 {
+            allowed = std::cmp::min(
+                allowed,
+                cap.max_lifetime.saturating_sub(self.accrued_reward),
+            );
+        }
+
+        if cap.period_sec > 0 {
+            allowed = self.apply_period_cap(cap, settled_ts, now_ts, allowed)?;
+        }
+
+        Ok(allowed)
+    }
+
+    /// accrual settled late is spread evenly over the time since settled_ts, so every period
+    /// it covers gets its own allowance (and no more)
+    fn apply_period_cap(
+        &mut self,
+        cap: &RewardCap,
+        settled_ts: u64,
+        now_ts: u64,
+        newly_accrued_reward: u64,
+    ) -> Result<u64> {
+        // first time under this cap
+        if self.cap_period_start_ts == 0 {
+            self.cap_period_start_ts = now_ts;
+            self.cap_period_accrued = 0;
+        }
+
+        let period_end_ts = self.cap_period_start_ts.try_add(cap.period_sec)?;
+
+        // still in the same period
+        if now_ts < period_end_ts {
+            let allowed = std::cmp::min(
+                newly_accrued_reward,
+                cap.max_per_period.saturating_sub(self.cap_period_accrued),
+            );
+            self.cap_period_accrued.try_add_assign(allowed)?;
+            return Ok(allowed);
+        }
+
+        // periods stay aligned to when the first one started
+        let elapsed_periods = now_ts
+            .try_sub(self.cap_period_start_ts)?
+            .try_div(cap.period_sec)?;
+        let new_period_start_ts = self
+            .cap_period_start_ts
+            .try_add(elapsed_periods.try_mul(cap.period_sec)?)?;
+
+        // split the accrual by time: the rest of the old period, whole periods, the new period so far
+        let elapsed_sec = now_ts.saturating_sub(settled_ts);
+        let accrued_in = |sec: u64| -> Result<u64> {
+            if elapsed_sec == 0 {
+                return Ok(0);
+            }
+            (newly_accrued_reward as u128)
+                .try_mul(std::cmp::min(sec, elapsed_sec) as u128)?
+                .try_div(elapsed_sec as u128)?
+                .try_cast()
+        };
+        let accrued_in_old = accrued_in(period_end_ts.saturating_sub(settled_ts))?;
+        let accrued_in_new = if elapsed_sec == 0 {
+            newly_accrued_reward
+        } else {
+            accrued_in(now_ts.try_sub(new_period_start_ts)?)?
+        };
+        let accrued_in_whole = newly_accrued_reward
+            .try_sub(accrued_in_old)?
+            .try_sub(accrued_in_new)?;
+
+        let allowed_old = std::cmp::min(
+            accrued_in_old,
+            cap.max_per_period.saturating_sub(self.cap_period_accrued),
+        );
+        let allowed_whole = std::cmp::min(
+            accrued_in_whole,
+            cap.max_per_period
+                .saturating_mul(elapsed_periods.try_sub(1)?),
+        );
+        let allowed_new = std::cmp::min(accrued_in_new, cap.max_per_period);
+
+        self.cap_period_start_ts = new_period_start_ts;
+        self.cap_period_accrued = allowed_new;
+
+        allowed_old.try_add(allowed_whole)?.try_add(allowed_new)
+    }
+
     pub fn update_variable_reward(
         &mut self,
         newly_accrued_reward: u64,
-        accrued_reward_per_rarity_point: Number128,
+        accrued_reward_per_rarity_point: Number192,
     ) -> Result<()> {
         self.accrued_reward.try_add_assign(newly_accrued_reward)?;
 
@@ -181,21 +557,32 @@ impl FarmerReward {
 
 // --------------------------------------- variable rate reward
 
-#[proc_macros::assert_size(32)]
+#[proc_macros::assert_size(40)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct FarmerVariableRateReward {
     /// used to keep track of how much of the variable reward has been updated for this farmer
     /// (read more in variable rate config)
-    pub last_recorded_accrued_reward_per_rarity_point: Number128,
+    pub last_recorded_accrued_reward_per_rarity_point: Number192,
 
     /// reserved for future updates, has to be /8
     _reserved: [u8; 16],
 }
 
+impl From<FarmerVariableRateRewardV0> for FarmerVariableRateReward {
+    fn from(v0: FarmerVariableRateRewardV0) -> Self {
+        Self {
+            last_recorded_accrued_reward_per_rarity_point: v0
+                .last_recorded_accrued_reward_per_rarity_point
+                .into(),
+            _reserved: v0._reserved,
+        }
+    }
+}
+
 // --------------------------------------- fixed rate reward
 
-#[proc_macros::assert_size(136)]
+#[proc_macros::assert_size(232)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
 pub struct FarmerFixedRateReward {
@@ -223,6 +610,19 @@ pub struct FarmerFixedRateReward {
     _reserved: [u8; 16],
 }
 
+impl From<FarmerFixedRateRewardV0> for FarmerFixedRateReward {
+    fn from(v0: FarmerFixedRateRewardV0) -> Self {
+        Self {
+            begin_staking_ts: v0.begin_staking_ts,
+            begin_schedule_ts: v0.begin_schedule_ts,
+            last_updated_ts: v0.last_updated_ts,
+            promised_schedule: v0.promised_schedule.into(),
+            promised_duration: v0.promised_duration,
+            _reserved: v0._reserved,
+        }
+    }
+}
+
 impl FarmerFixedRateReward {
     /// accrued to rolled stakers, whose begin_staking_ts < begin_schedule_ts
     pub fn loyal_staker_bonus_time(&self) -> Result<u64> {
@@ -242,8 +642,12 @@ impl FarmerFixedRateReward {
         Ok(now_ts >= self.end_schedule_ts()?)
     }
 
+    /// never before last_updated_ts, so nothing accrues before a scheduled reward begins
     pub fn reward_upper_bound(&self, now_ts: u64) -> Result<u64> {
-        Ok(std::cmp::min(now_ts, self.end_schedule_ts()?))
+        Ok(std::cmp::max(
+            self.last_updated_ts,
+            std::cmp::min(now_ts, self.end_schedule_ts()?),
+        ))
     }
 
     pub fn time_from_staking_to_update(&self) -> Result<u64> {
@@ -276,7 +680,7 @@ impl FarmerFixedRateReward {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::TierConfig;
+    use crate::state::{CapOverflow, TierConfig, FARMER_V0_LEN};
 
     impl FarmerFixedRateReward {
         pub fn new() -> Self {
@@ -284,22 +688,25 @@ mod tests {
                 begin_staking_ts: 100,
                 begin_schedule_ts: 150,
                 last_updated_ts: 155,
-                promised_schedule: FixedRateSchedule {
-                    base_rate: 3,
-                    tier1: Some(TierConfig {
-                        reward_rate: 5,
-                        required_tenure: 55,
-                    }),
-                    tier2: Some(TierConfig {
-                        reward_rate: 7,
-                        required_tenure: 65,
-                    }),
-                    tier3: Some(TierConfig {
-                        reward_rate: 11,
-                        required_tenure: 75,
-                    }),
-                    denominator: 1,
-                },
+                promised_schedule: FixedRateSchedule::new(
+                    3,
+                    &[
+                        TierConfig {
+                            reward_rate: 5,
+                            required_tenure: 55,
+                        },
+                        TierConfig {
+                            reward_rate: 7,
+                            required_tenure: 65,
+                        },
+                        TierConfig {
+                            reward_rate: 11,
+                            required_tenure: 75,
+                        },
+                    ],
+                    1,
+                )
+                .unwrap(),
                 promised_duration: 60,
                 _reserved: [0; 16],
             }
@@ -312,15 +719,161 @@ mod tests {
                 paid_out_reward: 0,
                 accrued_reward: 123,
                 variable_rate: FarmerVariableRateReward {
-                    last_recorded_accrued_reward_per_rarity_point: Number128::from(10u64),
+                    last_recorded_accrued_reward_per_rarity_point: Number192::from(10u64),
                     _reserved: [0; 16],
                 },
                 fixed_rate: FarmerFixedRateReward::new(),
+                generation: 0,
+                forfeited_reward: 0,
+                cap_period_start_ts: 0,
+                cap_period_accrued: 0,
+                cap_settled_ts: 0,
                 _reserved: [0; 32],
             }
         }
     }
 
+    impl Farmer {
+        pub fn unstaked() -> Self {
+            Self {
+                version: LATEST_FARMER_VERSION,
+                farm: Pubkey::default(),
+                identity: Pubkey::default(),
+                vault: Pubkey::default(),
+                state: FarmerState::Unstaked,
+                gems_staked: 0,
+                rarity_points_staked: 0,
+                min_staking_ends_ts: 0,
+                cooldown_ends_ts: 0,
+                reward_a: FarmerReward::new(),
+                reward_b: FarmerReward::new(),
+                gems_cooling_down: 0,
+                rarity_points_cooling_down: 0,
+                partial_cooldown_ends_ts: 0,
+                begin_staking_ts: 0,
+                boosted_points_staked: 0,
+                lock_period_sec: 0,
+                lock_multiplier_bps: 0,
+                _reserved: [0; 64],
+                _reserved2: [0; 32],
+                _reserved3: [0; 8],
+            }
+        }
+    }
+
+    #[test]
+    fn test_farmer_from_v0() {
+        let data = [0; FARMER_V0_LEN - 8];
+        let farmer = Farmer::from(FarmerV0::deserialize(&mut &data[..]).unwrap());
+
+        assert_eq!(farmer.version, LATEST_FARMER_VERSION);
+        assert_eq!(farmer.state, FarmerState::Unstaked);
+        assert_eq!(farmer.begin_staking_ts, 0);
+    }
+
+    #[test]
+    fn test_farmer_picked_lock() {
+        let mut farmer = Farmer::unstaked();
+
+        // no lock - farm-wide min applies, at 1x
+        assert_eq!(farmer.staking_period_sec(100), 100);
+        assert_eq!(farmer.lock_weighted(10).unwrap(), 10);
+
+        farmer.set_lock(Some(LockOption {
+            lock_sec: 1000,
+            multiplier_bps: 15_000,
+        }));
+        let period = farmer.staking_period_sec(100);
+        assert_eq!(period, 1000);
+        assert_eq!(farmer.lock_weighted(10).unwrap(), 15);
+
+        farmer.begin_staking(period, 0, 1, 10).unwrap();
+        assert!(!farmer.can_end_staking(999));
+        assert!(farmer.can_end_staking(1000));
+
+        // lock is gone once fully unstaked
+        farmer.end_staking_begin_cooldown(1000, 0).unwrap();
+        farmer.end_cooldown(1000).unwrap();
+        assert_eq!(farmer.staking_period_sec(100), 100);
+        assert_eq!(farmer.lock_weighted(10).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_farmer_restake_tenure() {
+        let mut farmer = Farmer::unstaked();
+        farmer.begin_staking(0, 100, 1, 10).unwrap();
+        farmer.end_staking_begin_cooldown(200, 50).unwrap();
+
+        // tenure carries over
+        farmer.restake(0, 220, 1, 10, true).unwrap();
+        assert_eq!(farmer.begin_staking_ts, 100);
+        assert_eq!(farmer.rarity_points_staked, 10);
+
+        // ...unless the farm doesn't allow it
+        farmer.end_staking_begin_cooldown(300, 50).unwrap();
+        farmer.restake(0, 320, 1, 10, false).unwrap();
+        assert_eq!(farmer.begin_staking_ts, 320);
+
+        // migrated farmers have no tenure to carry
+        farmer.end_staking_begin_cooldown(400, 50).unwrap();
+        farmer.begin_staking_ts = 0;
+        farmer.restake(0, 420, 1, 10, true).unwrap();
+        assert_eq!(farmer.begin_staking_ts, 420);
+    }
+
+    #[test]
+    fn test_farmer_partial_unstake() {
+        let mut farmer = Farmer::unstaked();
+        farmer.begin_staking(100, 0, 3, 30).unwrap();
+
+        // lock still on
+        assert!(farmer.unstake_gems_begin_cooldown(50, 10, 1, 10).is_err());
+        // everything has to go through the normal flow
+        assert!(farmer.unstake_gems_begin_cooldown(100, 10, 3, 30).is_err());
+
+        assert_eq!(
+            farmer.unstake_gems_begin_cooldown(100, 10, 1, 10).unwrap(),
+            30
+        );
+        assert_eq!(farmer.gems_staked, 2);
+        assert_eq!(farmer.rarity_points_staked, 20);
+        assert_eq!(farmer.gems_cooling_down, 1);
+        assert_eq!(farmer.rarity_points_cooling_down, 10);
+        assert_eq!(farmer.partial_cooldown_ends_ts, 110);
+
+        // gems cooling down don't count towards the stake
+        farmer.begin_staking(0, 200, 4, 40).unwrap();
+        assert_eq!(farmer.gems_staked, 3);
+        assert_eq!(farmer.rarity_points_staked, 30);
+
+        assert!(farmer.withdraw_cooled_down_gems(2, 10).is_err());
+        assert!(farmer.withdraw_cooled_down_gems(1, 11).is_err());
+        farmer.withdraw_cooled_down_gems(1, 10).unwrap();
+        assert_eq!(farmer.gems_cooling_down, 0);
+        assert_eq!(farmer.rarity_points_cooling_down, 0);
+        assert_eq!(farmer.gems_staked, 3);
+    }
+
+    #[test]
+    fn test_farmer_swap_gems() {
+        let mut farmer = Farmer::unstaked();
+        assert!(farmer.swap_gems(0, 1, 10).is_err());
+
+        farmer.begin_staking(100, 0, 1, 10).unwrap();
+
+        // same or higher rarity - fine while locked
+        assert_eq!(farmer.swap_gems(50, 1, 10).unwrap(), (1, 10));
+        assert_eq!(farmer.swap_gems(50, 1, 12).unwrap(), (1, 10));
+        assert_eq!(farmer.rarity_points_staked, 12);
+
+        // swapping down takes points out of the stake, so has to wait out the lock
+        assert!(farmer.swap_gems(50, 5, 2).is_err());
+        assert_eq!(farmer.swap_gems(100, 5, 2).unwrap(), (1, 12));
+        assert_eq!(farmer.gems_staked, 5);
+        assert_eq!(farmer.rarity_points_staked, 2);
+        assert_eq!(farmer.min_staking_ends_ts, 100);
+    }
+
     #[test]
     fn test_farmer_fixed_rate_reward() {
         let r = FarmerFixedRateReward::new();
@@ -350,11 +903,11 @@ mod tests {
         let mut r = FarmerReward::new();
         assert_eq!(123, r.outstanding_reward().unwrap());
 
-        r.update_variable_reward(10, Number128::from(50u64))
+        r.update_variable_reward(10, Number192::from(50u64))
             .unwrap();
         assert_eq!(133, r.outstanding_reward().unwrap());
         assert_eq!(
-            Number128::from(50u64),
+            Number192::from(50u64),
             r.variable_rate
                 .last_recorded_accrued_reward_per_rarity_point
         );
@@ -370,6 +923,43 @@ mod tests {
         assert_eq!(210, r.fixed_rate.last_updated_ts);
     }
 
+    #[test]
+    fn test_farmer_reward_cap() {
+        let mut r = FarmerReward::new();
+        let cap = RewardCap::new(100, 50, 200, CapOverflow::Redistribute).unwrap();
+
+        assert_eq!(30, r.apply_cap(&cap, 1000, 30).unwrap());
+        assert_eq!(20, r.apply_cap(&cap, 1050, 30).unwrap()); //period max
+        assert_eq!(0, r.apply_cap(&cap, 1099, 30).unwrap());
+
+        // earned over 1099..1100, so it still counts towards the (full) first period
+        assert_eq!(0, r.apply_cap(&cap, 1100, 30).unwrap());
+        assert_eq!(r.cap_period_start_ts, 1100);
+
+        // new period, but close to the lifetime max
+        r.accrued_reward = 190;
+        assert_eq!(10, r.apply_cap(&cap, 1150, 30).unwrap());
+
+        // settled late - every period in between gets its own allowance
+        // 1150..1200 (40 left in this period), 1200..1400 (2 whole periods), 1400..1450 (new one)
+        let cap = RewardCap::new(100, 50, 0, CapOverflow::Redistribute).unwrap();
+        assert_eq!(40 + 100 + 50, r.apply_cap(&cap, 1450, 300).unwrap());
+        assert_eq!(r.cap_period_start_ts, 1400);
+        assert_eq!(r.cap_period_accrued, 50);
+
+        // ...but no more than was actually earned in each
+        // 1450..1500 (period full), 1500..1600 (100 earned, 50 allowed), 1600..1610 (10 earned)
+        assert_eq!(50 + 10, r.apply_cap(&cap, 1610, 160).unwrap());
+        assert_eq!(r.cap_period_start_ts, 1600);
+        assert_eq!(r.cap_period_accrued, 10);
+
+        // no cap
+        assert_eq!(30, r.apply_cap(&RewardCap::default(), 1100, 30).unwrap());
+
+        // a period without a max
+        assert!(RewardCap::new(100, 0, 0, CapOverflow::Refund).is_err());
+    }
+
     #[test]
     fn test_farmer_reward_claim() {
         let mut r = FarmerReward::new();
@@ -378,4 +968,25 @@ mod tests {
         r.claim_reward(100).unwrap();
         assert_eq!(23, r.outstanding_reward().unwrap());
     }
+
+    #[test]
+    fn test_farmer_reward_sync_generation() {
+        let mut r = FarmerReward::new();
+
+        // same generation - nothing happens
+        r.sync_generation(0);
+        assert_eq!(123, r.outstanding_reward().unwrap());
+
+        // reward replaced - old state is void
+        r.sync_generation(1);
+        assert_eq!(1, r.generation);
+        assert_eq!(0, r.outstanding_reward().unwrap());
+        assert_eq!(
+            Number192::ZERO,
+            r.variable_rate
+                .last_recorded_accrued_reward_per_rarity_point
+        );
+        assert!(!r.fixed_rate.is_staked());
+        assert_eq!(1, r.fixed_rate.promised_schedule.denominator);
+    }
 }
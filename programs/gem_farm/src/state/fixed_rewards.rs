@@ -3,19 +3,12 @@ use gem_common::{errors::ErrorCode, *};
 
 use crate::state::*;
 
-#[proc_macros::assert_size(4)]
-#[repr(C)]
-#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
-pub enum FixedRateRewardTier {
-    Base,
-    Tier1,
-    Tier2,
-    Tier3,
-}
+/// upper bound on the number of tiers a single schedule can have
+pub const MAX_FIXED_RATE_TIERS: usize = 10;
 
 #[proc_macros::assert_size(16)]
 #[repr(C)]
-#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
 pub struct TierConfig {
     /// tokens/denominator/rarity point / sec
     pub reward_rate: u64,
@@ -24,22 +17,21 @@ pub struct TierConfig {
     pub required_tenure: u64,
 }
 
-#[proc_macros::assert_size(88)]
+#[proc_macros::assert_size(184)] // +7 to make it /8
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct FixedRateSchedule {
     /// tokens/denominator / sec
     pub base_rate: u64,
 
-    pub tier1: Option<TierConfig>, //16 + 8 overhead
-
-    pub tier2: Option<TierConfig>,
-
-    pub tier3: Option<TierConfig>,
+    /// only the first tier_count entries are used, ordered by required_tenure (ascending)
+    pub tiers: [TierConfig; MAX_FIXED_RATE_TIERS],
 
     /// needed to slow down the payout schedule (else min would be 1 token/rarity point/s or 86k/rarity point/day
     /// only used in fixed rate - in variable overall duration serves as sufficient speed regulator  
     pub denominator: u64,
+
+    pub tier_count: u8,
 }
 
 /// custom impl coz need the discriminator to be 1 by default, else get div /0 errors
@@ -47,15 +39,14 @@ impl Default for FixedRateSchedule {
     fn default() -> Self {
         Self {
             base_rate: 0,
-            tier1: None,
-            tier2: None,
-            tier3: None,
+            tiers: [TierConfig::default(); MAX_FIXED_RATE_TIERS],
             denominator: 1,
+            tier_count: 0,
         }
     }
 }
 
-#[proc_macros::assert_size(104)]
+#[proc_macros::assert_size(216)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct FixedRateConfig {
@@ -69,6 +60,10 @@ pub struct FixedRateConfig {
     /// set this carefully!
     /// every farmer enrolled will be "reserved" an amount to cover the schedule for this duration
     pub duration_sec: u64,
+
+    /// when the above duration starts, if not now
+    /// farmers enrolling before then have their schedule (and reserve) start at this time
+    pub start_ts: Option<u64>,
 }
 
 /// a tenure which we can definitely apply the reward rate to
@@ -111,59 +106,67 @@ impl HeldTenure {
     }
 }
 
-impl FixedRateSchedule {
-    /// rates themselves can be anything, no invariant
-    pub fn verify_schedule_invariants(&self) {
-        if let Some(t3) = self.tier3 {
-            // later tiers require earlier tiers to be present (no gaps)
-            assert!(self.tier2.is_some() && self.tier1.is_some());
-
-            // later tenures must be further into the future than earlier tenures
-            let t2_tenure = self.tier2.unwrap().required_tenure;
-            assert!(t3.required_tenure >= t2_tenure);
-
-            let t1_tenure = self.tier1.unwrap().required_tenure;
-            assert!(t2_tenure >= t1_tenure);
+impl From<FixedRateScheduleV0> for FixedRateSchedule {
+    fn from(v0: FixedRateScheduleV0) -> Self {
+        let mut schedule = Self {
+            base_rate: v0.base_rate,
+            denominator: v0.denominator,
+            ..Self::default()
         };
 
-        if let Some(t2) = self.tier2 {
-            // later tiers require earlier tiers to be present (no gaps)
-            assert!(self.tier1.is_some());
+        // v0 didn't allow gaps, so flattening keeps the tiers in order (and its 3 always fit)
+        for tier in [v0.tier1, v0.tier2, v0.tier3].into_iter().flatten() {
+            schedule.tiers[schedule.tier_count as usize] = tier;
+            schedule.tier_count += 1;
+        }
+        schedule
+    }
+}
+
+impl FixedRateSchedule {
+    pub fn new(base_rate: u64, tiers: &[TierConfig], denominator: u64) -> Result<Self> {
+        if tiers.len() > MAX_FIXED_RATE_TIERS {
+            return Err(error!(ErrorCode::TooManyFixedRateTiers));
+        }
 
-            // later tenures must be further into the future than earlier tenures
-            let t1_tenure = self.tier1.unwrap().required_tenure;
-            assert!(t2.required_tenure >= t1_tenure);
+        let mut schedule = Self {
+            base_rate,
+            denominator,
+            tier_count: tiers.len() as u8,
+            ..Self::default()
         };
+        schedule.tiers[..tiers.len()].copy_from_slice(tiers);
+        schedule.verify_schedule_invariants()?;
+        Ok(schedule)
+    }
 
-        // denominator can't be 0
-        assert_ne!(self.denominator, 0);
+    /// the tiers actually in use
+    pub fn tiers(&self) -> &[TierConfig] {
+        let tier_count = std::cmp::min(self.tier_count as usize, MAX_FIXED_RATE_TIERS);
+        &self.tiers[..tier_count]
     }
 
-    pub fn extract_tenure_and_rate(&self, tier: &str) -> Option<(u64, u64)> {
-        match tier {
-            "t1" => {
-                if let Some(t) = self.tier1 {
-                    Some((t.required_tenure, t.reward_rate))
-                } else {
-                    None
-                }
-            }
-            "t2" => {
-                if let Some(t) = self.tier2 {
-                    Some((t.required_tenure, t.reward_rate))
-                } else {
-                    None
-                }
-            }
-            "t3" => {
-                if let Some(t) = self.tier3 {
-                    Some((t.required_tenure, t.reward_rate))
-                } else {
-                    None
-                }
-            }
-            _ => panic!("undefined tier"),
+    /// rates themselves can be anything, no invariant
+    pub fn verify_schedule_invariants(&self) -> Result<()> {
+        if self.tier_count as usize > MAX_FIXED_RATE_TIERS {
+            return Err(error!(ErrorCode::TooManyFixedRateTiers));
+        }
+
+        // later tenures must be further into the future than earlier tenures
+        if self
+            .tiers()
+            .windows(2)
+            .any(|pair| pair[1].required_tenure < pair[0].required_tenure)
+        {
+            return Err(error!(ErrorCode::InvalidFixedRateSchedule));
+        }
+
+        // denominator can't be 0
+        if self.denominator == 0 {
+            return Err(error!(ErrorCode::InvalidFixedRateSchedule));
         }
+
+        Ok(())
     }
 
     pub fn get_base_reward(&self, start: u64, end: u64) -> Result<u64> {
@@ -171,59 +174,35 @@ impl FixedRateSchedule {
         self.base_rate.try_mul(duration)
     }
 
-    /// extracts held tenure from a combination of
-    ///   1) actual start & end times, and
-    ///   2) appropriate lower/upper bounds
-    /// lower bound: required_tenure extracted from appropriate TierConfig
-    /// upper bound: for first iteration (last-most TierConfig) simply U64::MAX,
-    ///   later recursively updated with previous TierConfig's required_tenure
-    fn extract_held_tenure(
-        &self,
-        tier: &str,
-        start_from: u64,
-        end_at: u64,
-        max_end: &mut u64,
-    ) -> Option<HeldTenure> {
-        match self.extract_tenure_and_rate(tier) {
-            // "required tenure" serves as lower bound
-            // previous iteration's
-            Some((begin, rate)) => {
-                let ht = HeldTenure::new(rate, start_from, end_at, begin, *max_end);
-                *max_end = begin;
-                ht
-            }
-            _ => None,
-        }
-    }
-
     /// calculates reward per rarity point, by
-    ///   1) recursively extracting tenures,
+    ///   1) walking the tiers from last to first, extracting definitively held tenures
+    ///      lower bound: the tier's required_tenure
+    ///      upper bound: the next tier's required_tenure (U64::MAX for the last tier)
     ///   2) calling get_reward() on each which isn't None
-    ///   3) calculating base rate
-    ///   4) folding base rate and non-None tenure rewards
+    ///   3) adding the base rate for any time before the first tier kicks in
     fn reward_per_rarity_point(&self, start_from: u64, end_at: u64) -> Result<u64> {
         let mut cap = u64::MAX;
+        let mut reward: u64 = 0;
+
+        for tier in self.tiers().iter().rev() {
+            if let Some(ht) = HeldTenure::new(
+                tier.reward_rate,
+                start_from,
+                end_at,
+                tier.required_tenure,
+                cap,
+            ) {
+                reward.try_add_assign(ht.get_reward()?)?;
+            }
+            cap = tier.required_tenure;
+        }
 
-        // collect definitively held tenures for 3 periods - still missing base
-        let t3 = self.extract_held_tenure("t3", start_from, end_at, &mut cap);
-        let t2 = self.extract_held_tenure("t2", start_from, end_at, &mut cap);
-        let t1 = self.extract_held_tenure("t1", start_from, end_at, &mut cap);
-
-        // flatten the options and call get_reward() on those that are Some()
-        let mut iter = vec![t1, t2, t3]
-            .into_iter()
-            .flatten()
-            .map(|t| t.get_reward());
-
-        // Base case is either base_reward or first applicable tenure, depending
-        // on the start position relative to the first applicable tenure
-        let init = match start_from < cap {
-            // If iter is empty, cap is MAX.  So this can only panic if start_from is also MAX.
-            false => iter.next().unwrap(),
-            true => self.get_base_reward(start_from, std::cmp::min(cap, end_at)),
-        };
+        // base applies until the first tier kicks in (or for the whole duration if there are no tiers)
+        if start_from < cap {
+            reward.try_add_assign(self.get_base_reward(start_from, std::cmp::min(cap, end_at))?)?;
+        }
 
-        iter.fold(init, |last, this| last?.try_add(this?))
+        Ok(reward)
     }
 
     pub fn reward_amount(&self, start_from: u64, end_at: u64, rarity_points: u64) -> Result<u64> {
@@ -241,7 +220,7 @@ impl FixedRateSchedule {
     }
 }
 
-#[proc_macros::assert_size(128)]
+#[proc_macros::assert_size(224)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct FixedRateReward {
@@ -255,6 +234,16 @@ pub struct FixedRateReward {
     _reserved: [u8; 32],
 }
 
+impl From<FixedRateRewardV0> for FixedRateReward {
+    fn from(v0: FixedRateRewardV0) -> Self {
+        Self {
+            schedule: v0.schedule.into(),
+            reserved_amount: v0.reserved_amount,
+            _reserved: v0._reserved,
+        }
+    }
+}
+
 impl FixedRateReward {
     pub fn fund_reward(
         &mut self,
@@ -267,12 +256,12 @@ impl FixedRateReward {
             schedule,
             amount,
             duration_sec,
+            start_ts,
         } = new_config;
 
-        schedule.verify_schedule_invariants();
+        schedule.verify_schedule_invariants()?;
 
-        times.duration_sec = duration_sec;
-        times.reward_end_ts = now_ts.try_add(duration_sec)?;
+        times.begin_reward(now_ts, start_ts, duration_sec)?;
 
         funds.total_funded.try_add_assign(amount)?;
 
@@ -297,11 +286,13 @@ impl FixedRateReward {
         Ok(refund_amount)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_accrued_reward(
         &mut self,
         now_ts: u64,
         times: &mut TimeTracker,
         funds: &mut FundsTracker,
+        cap: &RewardCap,
         farmer_rarity_points_staked: u64,
         farmer_reward: &mut FarmerReward,
         reenroll: bool,
@@ -310,14 +301,16 @@ impl FixedRateReward {
             .fixed_rate
             .newly_accrued_reward(now_ts, farmer_rarity_points_staked)?;
 
+        // anything over the farmer's cap is released from reserved straight back to pending,
+        // where it's available to enroll farmers (there's no pool to redistribute across)
+        let capped = farmer_reward.apply_cap(cap, now_ts, newly_accrued_reward)?;
+
         // update farm (move amount from reserved to accrued)
-        funds
-            .total_accrued_to_stakers
-            .try_add_assign(newly_accrued_reward)?;
+        funds.total_accrued_to_stakers.try_add_assign(capped)?;
         self.reserved_amount.try_sub_assign(newly_accrued_reward)?;
 
         // update farmer
-        farmer_reward.update_fixed_reward(now_ts, newly_accrued_reward)?;
+        farmer_reward.update_fixed_reward(now_ts, capped)?;
 
         if farmer_reward.fixed_rate.is_staked()
             && farmer_reward.fixed_rate.is_time_to_graduate(now_ts)?
@@ -359,9 +352,12 @@ impl FixedRateReward {
         // do NOT return OK(()) - this prevents us from passing down original_staking_start when next reward not ready
         let remaining_duration = times.remaining_duration(now_ts)?;
 
+        // if the reward hasn't begun yet, the schedule only starts once it does
+        let begin_schedule_ts = std::cmp::max(now_ts, times.reward_begin_ts);
+
         // calc any bonus due to previous staking
         farmer_reward.fixed_rate.begin_staking_ts = original_staking_start.unwrap_or(now_ts);
-        farmer_reward.fixed_rate.begin_schedule_ts = now_ts;
+        farmer_reward.fixed_rate.begin_schedule_ts = begin_schedule_ts;
         let bonus_time = farmer_reward.fixed_rate.loyal_staker_bonus_time()?;
 
         // calc how much we'd have to reserve for them
@@ -375,7 +371,7 @@ impl FixedRateReward {
         }
 
         // update farmer
-        farmer_reward.fixed_rate.last_updated_ts = now_ts;
+        farmer_reward.fixed_rate.last_updated_ts = begin_schedule_ts;
         farmer_reward.fixed_rate.promised_schedule = self.schedule;
         farmer_reward.fixed_rate.promised_duration = remaining_duration;
 
@@ -419,42 +415,37 @@ mod tests {
 
     impl FixedRateSchedule {
         pub fn new_base(base_rate: u64, denominator: u64) -> Self {
-            Self {
-                base_rate,
-                tier1: None,
-                tier2: None,
-                tier3: None,
-                denominator,
-            }
+            Self::new(base_rate, &[], denominator).unwrap()
         }
         pub fn new_t1(reward_rate: u64, required_tenure: u64) -> Self {
             //30 + ...
-            Self {
-                base_rate: 3,
-                tier1: Some(TierConfig {
+            Self::new(
+                3,
+                &[TierConfig {
                     reward_rate,
                     required_tenure,
-                }),
-                tier2: None,
-                tier3: None,
-                denominator: 1,
-            }
+                }],
+                1,
+            )
+            .unwrap()
         }
         pub fn new_t2(reward_rate: u64, required_tenure: u64) -> Self {
             //30 + 50 + ...
-            Self {
-                base_rate: 3,
-                tier1: Some(TierConfig {
-                    reward_rate: 5,
-                    required_tenure: 10,
-                }),
-                tier2: Some(TierConfig {
-                    reward_rate,
-                    required_tenure,
-                }),
-                tier3: None,
-                denominator: 1,
-            }
+            Self::new(
+                3,
+                &[
+                    TierConfig {
+                        reward_rate: 5,
+                        required_tenure: 10,
+                    },
+                    TierConfig {
+                        reward_rate,
+                        required_tenure,
+                    },
+                ],
+                1,
+            )
+            .unwrap()
         }
         pub fn new_t3(
             reward_rate2: u64,
@@ -463,138 +454,170 @@ mod tests {
             required_tenure3: u64,
         ) -> Self {
             //30 + 50 + ... + ...
-            Self {
-                base_rate: 3,
-                tier1: Some(TierConfig {
-                    reward_rate: 5,
-                    required_tenure: 10,
-                }),
-                tier2: Some(TierConfig {
-                    reward_rate: reward_rate2,
-                    required_tenure: required_tenure2,
-                }),
-                tier3: Some(TierConfig {
-                    reward_rate: reward_rate3,
-                    required_tenure: required_tenure3,
-                }),
-                denominator: 1,
-            }
-        }
-        pub fn bad_t2() -> Self {
-            Self {
-                base_rate: 3,
-                tier1: None,
-                tier2: Some(TierConfig {
-                    reward_rate: 7,
-                    required_tenure: 20,
-                }),
-                tier3: None,
-                denominator: 1,
-            }
-        }
-        pub fn bad_t3_gap_t1() -> Self {
-            Self {
-                base_rate: 3,
-                tier1: None,
-                tier2: Some(TierConfig {
-                    reward_rate: 7,
-                    required_tenure: 20,
-                }),
-                tier3: Some(TierConfig {
-                    reward_rate: 11,
-                    required_tenure: 30,
-                }),
-                denominator: 1,
-            }
+            Self::new(
+                3,
+                &[
+                    TierConfig {
+                        reward_rate: 5,
+                        required_tenure: 10,
+                    },
+                    TierConfig {
+                        reward_rate: reward_rate2,
+                        required_tenure: required_tenure2,
+                    },
+                    TierConfig {
+                        reward_rate: reward_rate3,
+                        required_tenure: required_tenure3,
+                    },
+                ],
+                1,
+            )
+            .unwrap()
         }
-        pub fn bad_t3_gap_t2() -> Self {
-            Self {
-                base_rate: 3,
-                tier1: Some(TierConfig {
-                    reward_rate: 5,
-                    required_tenure: 10,
-                }),
-                tier2: None,
-                tier3: Some(TierConfig {
-                    reward_rate: 11,
-                    required_tenure: 30,
-                }),
-                denominator: 1,
-            }
+        pub fn new_tn(tier_count: usize) -> Self {
+            Self::new(3, &tn_tiers(tier_count), 1).unwrap()
         }
     }
 
+    /// tier i kicks in at 10 * (i + 1) with rate i + 4
+    fn tn_tiers(tier_count: usize) -> Vec<TierConfig> {
+        (0..tier_count as u64)
+            .map(|i| TierConfig {
+                reward_rate: i + 4,
+                required_tenure: 10 * (i + 1),
+            })
+            .collect()
+    }
+
     #[test]
     fn test_good_schedule_invariants() {
         let base = FixedRateSchedule::new_base(3, 1);
-        base.verify_schedule_invariants();
+        base.verify_schedule_invariants().unwrap();
 
         let t1 = FixedRateSchedule::new_t1(5, 10);
-        t1.verify_schedule_invariants();
+        t1.verify_schedule_invariants().unwrap();
 
         let t1_min = FixedRateSchedule::new_t1(5, 0);
-        t1_min.verify_schedule_invariants();
+        t1_min.verify_schedule_invariants().unwrap();
 
         let t2 = FixedRateSchedule::new_t2(7, 20);
-        t2.verify_schedule_invariants();
+        t2.verify_schedule_invariants().unwrap();
 
         let t2_min = FixedRateSchedule::new_t2(7, 10);
-        t2_min.verify_schedule_invariants();
+        t2_min.verify_schedule_invariants().unwrap();
 
         let t3 = FixedRateSchedule::new_t3(7, 20, 11, 30);
-        t3.verify_schedule_invariants();
+        t3.verify_schedule_invariants().unwrap();
 
         let t3_min = FixedRateSchedule::new_t3(7, 20, 11, 20);
-        t3_min.verify_schedule_invariants();
+        t3_min.verify_schedule_invariants().unwrap();
     }
 
     #[test]
-    #[should_panic]
     fn test_t2_bad_tenure() {
-        let t2 = FixedRateSchedule::new_t2(7, 9);
-        t2.verify_schedule_invariants();
+        let mut t2 = FixedRateSchedule::new_t2(7, 20);
+        t2.tiers[1].required_tenure = 9;
+        assert_eq!(
+            t2.verify_schedule_invariants().unwrap_err(),
+            error!(ErrorCode::InvalidFixedRateSchedule)
+        );
     }
 
     #[test]
-    #[should_panic]
     fn test_t3_bad_tenure_t2() {
-        let t3 = FixedRateSchedule::new_t3(7, 20, 11, 19);
-        t3.verify_schedule_invariants();
+        let mut t3 = FixedRateSchedule::new_t3(7, 20, 11, 30);
+        t3.tiers[2].required_tenure = 19;
+        assert!(t3.verify_schedule_invariants().is_err());
     }
 
     #[test]
-    #[should_panic]
     fn test_t3_bad_tenure_t3() {
-        let t3 = FixedRateSchedule::new_t3(7, 9, 11, 30);
-        t3.verify_schedule_invariants();
+        let mut t3 = FixedRateSchedule::new_t3(7, 20, 11, 30);
+        t3.tiers[1].required_tenure = 9;
+        assert!(t3.verify_schedule_invariants().is_err());
+    }
+
+    #[test]
+    fn test_max_tiers_schedule_invariants() {
+        let tn = FixedRateSchedule::new_tn(MAX_FIXED_RATE_TIERS);
+        tn.verify_schedule_invariants().unwrap();
+        assert_eq!(tn.tiers().len(), MAX_FIXED_RATE_TIERS);
+    }
+
+    #[test]
+    fn test_too_many_tiers() {
+        let tiers = tn_tiers(MAX_FIXED_RATE_TIERS + 1);
+        assert_eq!(
+            FixedRateSchedule::new(3, &tiers, 1).unwrap_err(),
+            error!(ErrorCode::TooManyFixedRateTiers)
+        );
+    }
+
+    #[test]
+    fn test_too_many_tiers_invariants() {
+        // eg passed in directly through FixedRateConfig
+        let mut tn = FixedRateSchedule::new_tn(MAX_FIXED_RATE_TIERS);
+        tn.tier_count += 1;
+        assert_eq!(
+            tn.verify_schedule_invariants().unwrap_err(),
+            error!(ErrorCode::TooManyFixedRateTiers)
+        );
     }
 
     #[test]
-    #[should_panic]
-    fn test_t2_bad_gap() {
-        let t2 = FixedRateSchedule::bad_t2();
-        t2.verify_schedule_invariants();
+    fn test_tn_bad_tenure() {
+        let mut tn = FixedRateSchedule::new_tn(6);
+        tn.tiers[4].required_tenure = tn.tiers[3].required_tenure - 1;
+        assert!(tn.verify_schedule_invariants().is_err());
     }
 
     #[test]
-    #[should_panic]
-    fn test_t3_bad_gap_t1() {
-        let t3 = FixedRateSchedule::bad_t3_gap_t1();
-        t3.verify_schedule_invariants();
+    fn test_new_rejects_unordered_tiers() {
+        let mut tiers = tn_tiers(3);
+        tiers.swap(0, 2);
+        assert_eq!(
+            FixedRateSchedule::new(3, &tiers, 1).unwrap_err(),
+            error!(ErrorCode::InvalidFixedRateSchedule)
+        );
     }
 
     #[test]
-    #[should_panic]
-    fn test_t3_bad_gap_t2() {
-        let t3 = FixedRateSchedule::bad_t3_gap_t2();
-        t3.verify_schedule_invariants();
+    fn test_schedule_from_v0() {
+        let v0 = FixedRateScheduleV0 {
+            base_rate: 3,
+            tier1: Some(TierConfig {
+                reward_rate: 5,
+                required_tenure: 10,
+            }),
+            tier2: Some(TierConfig {
+                reward_rate: 7,
+                required_tenure: 20,
+            }),
+            tier3: None,
+            denominator: 2,
+        };
+        let schedule = FixedRateSchedule::from(v0);
+        schedule.verify_schedule_invariants().unwrap();
+
+        assert_eq!(schedule.base_rate, 3);
+        assert_eq!(schedule.denominator, 2);
+        assert_eq!(schedule.tiers().len(), 2);
+        assert_eq!(schedule.tiers()[1].reward_rate, 7);
+        assert_eq!(
+            schedule.reward_amount(0, 25, 10).unwrap(),
+            FixedRateSchedule::new_t2(7, 20)
+                .reward_amount(0, 25, 10)
+                .unwrap()
+                / 2
+        );
     }
 
     #[test]
-    #[should_panic]
     fn test_base_bad_denominator() {
-        let base = FixedRateSchedule::new_base(1, 0);
-        base.verify_schedule_invariants();
+        assert_eq!(
+            FixedRateSchedule::new(1, &[], 0).unwrap_err(),
+            error!(ErrorCode::InvalidFixedRateSchedule)
+        );
     }
 
     #[test]
@@ -768,4 +791,66 @@ mod tests {
         let amount = t3.reward_amount(35, 35, 10).unwrap();
         assert_eq!(amount, 0);
     }
+
+    #[test]
+    fn test_tn_reward_amounts() {
+        let tn = FixedRateSchedule::new_tn(MAX_FIXED_RATE_TIERS);
+
+        // zero case
+        let amount = tn.reward_amount(0, 0, 10).unwrap();
+        assert_eq!(amount, 0);
+
+        // base only case
+        let amount = tn.reward_amount(0, 5, 10).unwrap();
+        assert_eq!(amount, 3 * 5 * 10);
+
+        // spanning several middle tiers
+        let amount = tn.reward_amount(25, 55, 10).unwrap();
+        assert_eq!(amount, (5 * 5 + 6 * 10 + 7 * 10 + 8 * 5) * 10);
+
+        // base + all tiers case
+        let amount = tn.reward_amount(0, 105, 10).unwrap();
+        let all_tiers: u64 = (4..13).map(|rate| rate * 10).sum::<u64>() + 13 * 5;
+        assert_eq!(amount, (3 * 10 + all_tiers) * 10);
+
+        // max out case
+        let amount = tn.reward_amount(105, 105, 10).unwrap();
+        assert_eq!(amount, 0);
+    }
+
+    #[test]
+    fn test_enroll_carries_tenure() {
+        let mut fixed_rate = FixedRateReward::deserialize(&mut &[0; 1024][..]).unwrap();
+        fixed_rate.schedule = FixedRateSchedule::new_t1(5, 50);
+        let mut times = TimeTracker {
+            duration_sec: 200,
+            reward_begin_ts: 1000,
+            reward_end_ts: 1200,
+            lock_end_ts: 0,
+        };
+        let mut funds = FundsTracker {
+            total_funded: 450,
+            total_refunded: 0,
+            total_accrued_to_stakers: 0,
+        };
+
+        // fresh stake at 1100 - 50s at the base rate, 50s at tier 1
+        let mut fresh = FarmerReward::new();
+        fixed_rate
+            .enroll_farmer(1100, &mut times, &mut funds, 1, &mut fresh, None)
+            .unwrap();
+        assert_eq!(fresh.fixed_rate.begin_staking_ts, 1100);
+        assert_eq!(fixed_rate.reserved_amount, 3 * 50 + 5 * 50);
+
+        // restaked at 1100 keeping tenure from 1040 - straight into tier 1
+        fixed_rate.reserved_amount = 0;
+        funds.total_funded = 500;
+        let mut restaked = FarmerReward::new();
+        fixed_rate
+            .enroll_farmer(1100, &mut times, &mut funds, 1, &mut restaked, Some(1040))
+            .unwrap();
+        assert_eq!(restaked.fixed_rate.begin_staking_ts, 1040);
+        assert_eq!(restaked.fixed_rate.loyal_staker_bonus_time().unwrap(), 60);
+        assert_eq!(fixed_rate.reserved_amount, 5 * 100);
+    }
 }
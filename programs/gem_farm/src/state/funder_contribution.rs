@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+/// what a single funder put into a single reward (by mint), since the last cancellation
+/// lets cancel_reward refund funders pro rata instead of sending everything to one receiver
+#[proc_macros::assert_size(144)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct FunderContribution {
+    pub farm: Pubkey,
+
+    pub funder: Pubkey,
+
+    pub reward_mint: Pubkey,
+
+    /// mirrors RefundTracker.funding_round at the time of the last contribution
+    pub funding_round: u64,
+
+    /// contributed during funding_round, zeroed once refunded
+    pub contributed: u64,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::RefundTracker;
+
+    impl FunderContribution {
+        pub fn empty() -> Self {
+            Self {
+                farm: Pubkey::default(),
+                funder: Pubkey::new_unique(),
+                reward_mint: Pubkey::default(),
+                funding_round: 0,
+                contributed: 0,
+                _reserved: [0; 32],
+            }
+        }
+    }
+
+    #[test]
+    fn test_refunds_pro_rata() {
+        let mut refunds = RefundTracker::default();
+        let mut alice = FunderContribution::empty();
+        let mut bob = FunderContribution::empty();
+
+        refunds.record_contribution(&mut alice, 700).unwrap();
+        refunds.record_contribution(&mut bob, 200).unwrap();
+        refunds.record_contribution(&mut bob, 100).unwrap();
+
+        // half of it got accrued, 499 is left
+        assert_eq!(refunds.close_round(499).unwrap(), 0);
+        assert_eq!(refunds.claim_refund(&mut alice).unwrap(), 349); //349.3 rounded down
+        assert!(refunds.claim_refund(&mut alice).is_err());
+        // last one gets the dust
+        assert_eq!(refunds.claim_refund(&mut bob).unwrap(), 150);
+        assert_eq!(refunds.unclaimed_refund, 0);
+    }
+
+    #[test]
+    fn test_refunds_pending() {
+        let mut refunds = RefundTracker::default();
+        let mut alice = FunderContribution::empty();
+        let mut bob = FunderContribution::empty();
+
+        refunds.record_contribution(&mut alice, 100).unwrap();
+        refunds.record_contribution(&mut bob, 100).unwrap();
+        refunds.close_round(100).unwrap();
+
+        // no new round until everyone's claimed
+        assert!(refunds.record_contribution(&mut alice, 100).is_err());
+        refunds.claim_refund(&mut alice).unwrap();
+        refunds.claim_refund(&mut bob).unwrap();
+
+        refunds.record_contribution(&mut alice, 100).unwrap();
+        assert_eq!(alice.funding_round, 1);
+        assert_eq!(alice.contributed, 100);
+        // nothing to refund from the new round until it's closed
+        assert!(refunds.claim_refund(&mut alice).is_err());
+    }
+
+    #[test]
+    fn test_untracked_funding_goes_to_receiver() {
+        let mut refunds = RefundTracker::default();
+
+        assert_eq!(refunds.close_round(500).unwrap(), 500);
+        assert_eq!(refunds.funding_round, 0);
+    }
+}
@@ -0,0 +1,180 @@
+//! the layouts farms and farmers were originally deployed with, only used to read accounts
+//! that haven't gone through migrate_account yet (see From<FarmV0> for Farm and From<FarmerV0> for Farmer)
+
+use anchor_lang::prelude::*;
+
+use crate::{number128::Number128, state::*};
+
+/// data len (incl. discriminator) of accounts still on the v0 layout
+pub const FARM_V0_LEN: usize = 8 + 1000;
+pub const FARMER_V0_LEN: usize = 8 + 600;
+
+// --------------------------------------- variable rate
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct VariableRateRewardV0 {
+    pub reward_rate: Number128,
+
+    pub reward_last_updated_ts: u64,
+
+    pub accrued_reward_per_rarity_point: Number128,
+
+    pub _reserved: [u8; 32],
+}
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FarmerVariableRateRewardV0 {
+    pub last_recorded_accrued_reward_per_rarity_point: Number128,
+
+    pub _reserved: [u8; 16],
+}
+
+// --------------------------------------- time tracker
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct TimeTrackerV0 {
+    pub duration_sec: u64,
+
+    pub reward_end_ts: u64,
+
+    pub lock_end_ts: u64,
+}
+
+// --------------------------------------- fixed rate
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FixedRateScheduleV0 {
+    pub base_rate: u64,
+
+    pub tier1: Option<TierConfig>,
+
+    pub tier2: Option<TierConfig>,
+
+    pub tier3: Option<TierConfig>,
+
+    pub denominator: u64,
+}
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FixedRateRewardV0 {
+    pub schedule: FixedRateScheduleV0,
+
+    pub reserved_amount: u64,
+
+    pub _reserved: [u8; 32],
+}
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FarmerFixedRateRewardV0 {
+    pub begin_staking_ts: u64,
+
+    pub begin_schedule_ts: u64,
+
+    pub last_updated_ts: u64,
+
+    pub promised_schedule: FixedRateScheduleV0,
+
+    pub promised_duration: u64,
+
+    pub _reserved: [u8; 16],
+}
+
+// --------------------------------------- farm
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FarmRewardV0 {
+    pub reward_mint: Pubkey,
+
+    pub reward_pot: Pubkey,
+
+    pub reward_type: RewardType,
+
+    pub fixed_rate: FixedRateRewardV0,
+
+    pub variable_rate: VariableRateRewardV0,
+
+    pub funds: FundsTracker,
+
+    pub times: TimeTrackerV0,
+
+    pub _reserved: [u8; 32],
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct FarmV0 {
+    pub version: u16,
+
+    pub farm_manager: Pubkey,
+
+    pub farm_treasury: Pubkey,
+
+    pub farm_authority: Pubkey,
+
+    pub farm_authority_seed: Pubkey,
+
+    pub farm_authority_bump_seed: [u8; 1],
+
+    pub bank: Pubkey,
+
+    pub config: FarmConfig,
+
+    pub farmer_count: u64,
+
+    pub staked_farmer_count: u64,
+
+    pub gems_staked: u64,
+
+    pub rarity_points_staked: u64,
+
+    pub authorized_funder_count: u64,
+
+    pub reward_a: FarmRewardV0,
+
+    pub reward_b: FarmRewardV0,
+
+    pub max_counts: MaxCounts,
+
+    pub _reserved: [u8; 32],
+    pub _reserved2: [u8; 16],
+    pub _reserved3: [u8; 4],
+}
+
+// --------------------------------------- farmer
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct FarmerRewardV0 {
+    pub paid_out_reward: u64,
+
+    pub accrued_reward: u64,
+
+    pub variable_rate: FarmerVariableRateRewardV0,
+
+    pub fixed_rate: FarmerFixedRateRewardV0,
+
+    pub _reserved: [u8; 32],
+}
+
+#[derive(Debug, AnchorSerialize, AnchorDeserialize)]
+pub struct FarmerV0 {
+    pub farm: Pubkey,
+
+    pub identity: Pubkey,
+
+    pub vault: Pubkey,
+
+    pub state: FarmerState,
+
+    pub gems_staked: u64,
+
+    pub rarity_points_staked: u64,
+
+    pub min_staking_ends_ts: u64,
+
+    pub cooldown_ends_ts: u64,
+
+    pub reward_a: FarmerRewardV0,
+
+    pub reward_b: FarmerRewardV0,
+
+    pub _reserved: [u8; 32],
+}
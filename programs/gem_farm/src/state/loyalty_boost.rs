@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+/// upper bound on the number of tiers a boost schedule can have
+pub const MAX_LOYALTY_BOOST_TIERS: usize = 4;
+
+/// 1x, multipliers are expressed in basis points
+pub const LOYALTY_BOOST_BPS: u64 = 10_000;
+
+#[proc_macros::assert_size(16)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct LoyaltyBoostTier {
+    /// min continuous staking time for the multiplier below to kick in
+    pub required_tenure: u64,
+
+    /// eg 15000 = 1.5x the farmer's rarity points
+    pub multiplier_bps: u64,
+}
+
+/// variable-rate rewards only (fixed-rate ones have their own tiers, see FixedRateSchedule)
+/// farmers' points are multiplied by the highest tier they've reached,
+/// the farm keeps the sum of those boosted points and splits the variable reward across it
+#[proc_macros::assert_size(72)] // +7 to make it /8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct LoyaltyBoost {
+    /// only the first tier_count entries are used, ordered by required_tenure (ascending)
+    pub tiers: [LoyaltyBoostTier; MAX_LOYALTY_BOOST_TIERS],
+
+    /// 0 = no boost, variable rewards are split by raw rarity points
+    pub tier_count: u8,
+}
+
+impl LoyaltyBoost {
+    pub fn new(tiers: &[LoyaltyBoostTier]) -> Result<Self> {
+        if tiers.len() > MAX_LOYALTY_BOOST_TIERS {
+            return Err(error!(ErrorCode::InvalidLoyaltyBoost));
+        }
+
+        let mut boost = Self::default();
+        boost.tiers[..tiers.len()].copy_from_slice(tiers);
+        boost.tier_count = tiers.len() as u8;
+        boost.verify()?;
+
+        Ok(boost)
+    }
+
+    pub fn tiers(&self) -> &[LoyaltyBoostTier] {
+        &self.tiers[..self.tier_count as usize]
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.tier_count > 0
+    }
+
+    /// tenures have to go up, and so do multipliers (never below 1x)
+    pub fn verify(&self) -> Result<()> {
+        if self.tier_count as usize > MAX_LOYALTY_BOOST_TIERS {
+            return Err(error!(ErrorCode::InvalidLoyaltyBoost));
+        }
+
+        let mut previous = LoyaltyBoostTier {
+            required_tenure: 0,
+            multiplier_bps: LOYALTY_BOOST_BPS,
+        };
+        for tier in self.tiers() {
+            if tier.required_tenure < previous.required_tenure
+                || tier.multiplier_bps < previous.multiplier_bps
+            {
+                return Err(error!(ErrorCode::InvalidLoyaltyBoost));
+            }
+            previous = *tier;
+        }
+
+        Ok(())
+    }
+
+    pub fn multiplier_bps(&self, staked_for_sec: u64) -> u64 {
+        self.tiers()
+            .iter()
+            .rev()
+            .find(|tier| staked_for_sec >= tier.required_tenure)
+            .map_or(LOYALTY_BOOST_BPS, |tier| tier.multiplier_bps)
+    }
+
+    /// rounds down, so the farm total can never promise more than the pool splits
+    pub fn boosted_points(&self, rarity_points: u64, staked_for_sec: u64) -> Result<u64> {
+        (rarity_points as u128)
+            .try_mul(self.multiplier_bps(staked_for_sec) as u128)?
+            .try_div(LOYALTY_BOOST_BPS as u128)?
+            .try_cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(required_tenure: u64, multiplier_bps: u64) -> LoyaltyBoostTier {
+        LoyaltyBoostTier {
+            required_tenure,
+            multiplier_bps,
+        }
+    }
+
+    #[test]
+    fn test_boosted_points() {
+        let boost = LoyaltyBoost::new(&[tier(100, 15_000), tier(1000, 20_000)]).unwrap();
+
+        assert_eq!(boost.boosted_points(3, 0).unwrap(), 3);
+        assert_eq!(boost.boosted_points(3, 99).unwrap(), 3);
+        assert_eq!(boost.boosted_points(3, 100).unwrap(), 4); //4.5 rounded down
+        assert_eq!(boost.boosted_points(3, 999).unwrap(), 4);
+        assert_eq!(boost.boosted_points(3, 1000).unwrap(), 6);
+        assert_eq!(boost.boosted_points(3, u64::MAX).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_no_boost() {
+        let boost = LoyaltyBoost::default();
+
+        assert!(!boost.is_enabled());
+        assert_eq!(boost.boosted_points(7, 1_000_000).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_invalid_boosts() {
+        // too many tiers
+        assert!(LoyaltyBoost::new(&[tier(1, 10_000); MAX_LOYALTY_BOOST_TIERS + 1]).is_err());
+        // tenures going down
+        assert!(LoyaltyBoost::new(&[tier(100, 15_000), tier(50, 20_000)]).is_err());
+        // multipliers going down
+        assert!(LoyaltyBoost::new(&[tier(100, 15_000), tier(200, 12_000)]).is_err());
+        // below 1x
+        assert!(LoyaltyBoost::new(&[tier(100, 9_000)]).is_err());
+    }
+}
@@ -0,0 +1,325 @@
+use anchor_lang::prelude::*;
+use gem_common::errors::ErrorCode;
+
+use crate::state::*;
+
+/// upper bound on the number of managers in a set, approvals are kept as a bitmask
+pub const MAX_MANAGERS: usize = 8;
+
+#[proc_macros::assert_size(258)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct Managers {
+    /// only the first member_count entries are used
+    pub members: [Pubkey; MAX_MANAGERS],
+
+    pub member_count: u8,
+
+    /// approvals needed for a proposal to go through
+    pub threshold: u8,
+}
+
+impl Managers {
+    pub fn new(members: &[Pubkey], threshold: u8) -> Result<Self> {
+        if members.len() > MAX_MANAGERS {
+            return Err(error!(ErrorCode::InvalidManagerSet));
+        }
+
+        let mut managers = Self::default();
+        managers.members[..members.len()].copy_from_slice(members);
+        managers.member_count = members.len() as u8;
+        managers.threshold = threshold;
+        managers.verify()?;
+
+        Ok(managers)
+    }
+
+    pub fn members(&self) -> &[Pubkey] {
+        &self.members[..self.member_count as usize]
+    }
+
+    /// no duplicates, and a threshold the members can actually reach
+    pub fn verify(&self) -> Result<()> {
+        if self.member_count as usize > MAX_MANAGERS
+            || self.threshold == 0
+            || self.threshold > self.member_count
+        {
+            return Err(error!(ErrorCode::InvalidManagerSet));
+        }
+
+        let members = self.members();
+        for (i, member) in members.iter().enumerate() {
+            if members[..i].contains(member) {
+                return Err(error!(ErrorCode::InvalidManagerSet));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn member_index(&self, key: &Pubkey) -> Result<usize> {
+        self.members()
+            .iter()
+            .position(|member| member == key)
+            .ok_or_else(|| error!(ErrorCode::NotAManager))
+    }
+}
+
+/// an M-of-N set of managers, one per farm - once a farm has one, anything that moves funds or
+/// control (cancelling or replacing rewards, treasury payouts and splits, handing over
+/// farm_manager) needs an approved Proposal on top of farm_manager's signature
+#[proc_macros::assert_size(376)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ManagerSet {
+    pub farm: Pubkey,
+
+    pub managers: Managers,
+
+    /// bumped every time the managers change, approvals collected under an older set lapse
+    pub generation: u64,
+
+    /// used to derive the next proposal's address
+    pub proposal_count: u64,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 64],
+}
+
+impl ManagerSet {
+    pub fn set_managers(&mut self, managers: Managers) -> Result<()> {
+        managers.verify()?;
+
+        self.managers = managers;
+        self.generation = self.generation.wrapping_add(1);
+
+        Ok(())
+    }
+
+    pub fn approve(&self, proposal: &mut Proposal, member: &Pubkey) -> Result<()> {
+        self.assert_open(proposal)?;
+
+        let index = self.managers.member_index(member)?;
+        proposal.approvals |= 1 << index;
+
+        Ok(())
+    }
+
+    /// checks the proposal is for exactly this action and has enough approvals, then marks it
+    /// executed so it can't be replayed
+    pub fn execute(
+        &self,
+        proposal: &mut Proposal,
+        farm: Pubkey,
+        action: &ProposalAction,
+    ) -> Result<()> {
+        self.assert_open(proposal)?;
+
+        if proposal.farm != farm || proposal.action != *action {
+            return Err(error!(ErrorCode::ProposalMismatch));
+        }
+
+        if proposal.approval_count() < self.managers.threshold {
+            return Err(error!(ErrorCode::ProposalNotApproved));
+        }
+
+        proposal.executed = true;
+        Ok(())
+    }
+
+    fn assert_open(&self, proposal: &Proposal) -> Result<()> {
+        if proposal.executed || proposal.generation != self.generation {
+            return Err(error!(ErrorCode::ProposalMismatch));
+        }
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ProposalAction {
+    /// receiver = where the cancelled funds go (reward_destination is its ATA)
+    CancelReward {
+        reward_mint: Pubkey,
+        receiver: Pubkey,
+    },
+    CancelRewardSlot {
+        reward_slot: Pubkey,
+        receiver: Pubkey,
+    },
+    PayoutFromTreasury {
+        destination: Pubkey,
+        lamports: u64,
+    },
+    PayoutTokensFromTreasury {
+        destination: Pubkey,
+        amount: u64,
+    },
+    SetTreasurySplit {
+        recipients: [TreasuryRecipient; MAX_TREASURY_RECIPIENTS],
+        recipient_count: u8,
+    },
+    /// receiver = where the old pot's surplus goes (old_reward_destination is its ATA)
+    ReplaceReward {
+        old_reward_mint: Pubkey,
+        new_reward_mint: Pubkey,
+        receiver: Pubkey,
+    },
+    SetFarmManager {
+        manager: Pubkey,
+    },
+    SetManagers {
+        managers: Managers,
+    },
+}
+
+impl ProposalAction {
+    pub fn set_treasury_split(recipients: &[TreasuryRecipient]) -> Result<Self> {
+        if recipients.len() > MAX_TREASURY_RECIPIENTS {
+            return Err(error!(ErrorCode::InvalidTreasurySplit));
+        }
+
+        let mut padded = [TreasuryRecipient::default(); MAX_TREASURY_RECIPIENTS];
+        padded[..recipients.len()].copy_from_slice(recipients);
+
+        Ok(Self::SetTreasurySplit {
+            recipients: padded,
+            recipient_count: recipients.len() as u8,
+        })
+    }
+}
+
+/// one action, approved by members of the farm's ManagerSet and executed by the ix it's for
+#[proc_macros::assert_size(456)] // +6 to make it /8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Proposal {
+    pub farm: Pubkey,
+
+    pub proposer: Pubkey,
+
+    /// ManagerSet.proposal_count at the time, part of the PDA seeds
+    pub index: u64,
+
+    /// ManagerSet.generation at the time
+    pub generation: u64,
+
+    pub action: ProposalAction,
+
+    /// bit i = ManagerSet.managers.members[i] approved
+    pub approvals: u8,
+
+    pub executed: bool,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+}
+
+impl Proposal {
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager_set(members: &[Pubkey], threshold: u8) -> ManagerSet {
+        ManagerSet {
+            farm: Pubkey::default(),
+            managers: Managers::new(members, threshold).unwrap(),
+            generation: 0,
+            proposal_count: 0,
+            _reserved: [0; 64],
+        }
+    }
+
+    fn proposal(action: ProposalAction) -> Proposal {
+        Proposal {
+            farm: Pubkey::default(),
+            proposer: Pubkey::default(),
+            index: 0,
+            generation: 0,
+            action,
+            approvals: 0,
+            executed: false,
+            _reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_threshold() {
+        let members = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let set = manager_set(&members, 2);
+        let reward_mint = Pubkey::new_unique();
+        let action = ProposalAction::CancelReward {
+            reward_mint,
+            receiver: Pubkey::new_unique(),
+        };
+        let mut proposal = proposal(action);
+
+        set.approve(&mut proposal, &members[0]).unwrap();
+        set.approve(&mut proposal, &members[0]).unwrap(); //approving twice doesn't count twice
+        assert!(set.approve(&mut proposal, &Pubkey::new_unique()).is_err());
+        assert!(set
+            .execute(&mut proposal, Pubkey::default(), &action)
+            .is_err());
+
+        set.approve(&mut proposal, &members[2]).unwrap();
+
+        // has to be for exactly this action, down to where the funds go
+        let other_action = ProposalAction::CancelReward {
+            reward_mint,
+            receiver: Pubkey::new_unique(),
+        };
+        assert!(set
+            .execute(&mut proposal, Pubkey::default(), &other_action)
+            .is_err());
+
+        set.execute(&mut proposal, Pubkey::default(), &action)
+            .unwrap();
+
+        // no replays
+        assert!(set
+            .execute(&mut proposal, Pubkey::default(), &action)
+            .is_err());
+    }
+
+    #[test]
+    fn test_approvals_lapse_with_new_managers() {
+        let members = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut set = manager_set(&members, 1);
+        let action = ProposalAction::SetFarmManager {
+            manager: Pubkey::new_unique(),
+        };
+        let mut proposal = proposal(action);
+
+        set.approve(&mut proposal, &members[0]).unwrap();
+        set.set_managers(Managers::new(&members[1..], 1).unwrap())
+            .unwrap();
+
+        assert!(set
+            .execute(&mut proposal, Pubkey::default(), &action)
+            .is_err());
+    }
+
+    #[test]
+    fn test_invalid_managers() {
+        let member = Pubkey::new_unique();
+
+        // too many members
+        assert!(Managers::new(&[Pubkey::new_unique(); MAX_MANAGERS + 1], 1).is_err());
+        // unreachable threshold
+        assert!(Managers::new(&[member], 2).is_err());
+        assert!(Managers::new(&[member], 0).is_err());
+        // duplicates
+        assert!(Managers::new(&[member, member], 2).is_err());
+    }
+}
@@ -1,11 +1,33 @@
 pub mod authorization_proof;
+pub mod cooling_gem;
 pub mod farm;
 pub mod farmer;
 pub mod fixed_rewards;
+pub mod funder_contribution;
+pub mod legacy;
+pub mod loyalty_boost;
+pub mod manager_set;
+pub mod pending_config;
+pub mod protocol_config;
+pub mod reward_cap;
+pub mod reward_slot;
+pub mod staking_lock;
+pub mod treasury_split;
 pub mod variable_rewards;
 
 pub use authorization_proof::*;
+pub use cooling_gem::*;
 pub use farm::*;
 pub use farmer::*;
 pub use fixed_rewards::*;
+pub use funder_contribution::*;
+pub use legacy::*;
+pub use loyalty_boost::*;
+pub use manager_set::*;
+pub use pending_config::*;
+pub use protocol_config::*;
+pub use reward_cap::*;
+pub use reward_slot::*;
+pub use staking_lock::*;
+pub use treasury_split::*;
 pub use variable_rewards::*;
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+/// a FarmConfig / MaxCounts change queued by the manager (see Farm.config_timelock_sec)
+/// lives in its own PDA so stakers can see it coming, applied by the permissionless apply_pending_config
+#[proc_macros::assert_size(136)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct PendingFarmConfig {
+    pub farm: Pubkey,
+
+    /// can't be applied before this
+    pub effective_ts: u64,
+
+    /// None = left unchanged
+    pub config: Option<FarmConfig>,
+
+    pub max_counts: Option<MaxCounts>,
+
+    /// lowering the timelock has to go through the timelock too
+    pub config_timelock_sec: Option<u64>,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+}
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+pub const LATEST_PROTOCOL_CONFIG_VERSION: u16 = 0;
+
+/// what the protocol charges on top of any farm-level fees (see FarmConfig.unstaking_fee_lamp)
+/// amounts are in lamports, 0 = free
+#[proc_macros::assert_size(48)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct ProtocolFees {
+    /// originally 2.5 SOL
+    pub init_farm: u64,
+
+    /// originally 0.01 SOL
+    pub init_farmer: u64,
+
+    /// init_farmer + stake in one go, originally 0.012 SOL
+    pub enter_farm: u64,
+
+    /// stake, restake and flash deposits that start a new stake, originally 0.002 SOL
+    pub stake: u64,
+
+    /// charged on both unstake calls (staked -> cooldown -> unstaked), originally 0.001 SOL
+    /// partial_unstake and withdraw_unstaked_gem are the same two steps for a subset of gems, so pay the same
+    /// flash_withdraw pays it once
+    pub unstake: u64,
+
+    /// flash deposits into an existing stake, originally 0.001 SOL
+    pub flash_deposit: u64,
+}
+
+/// singleton PDA, every fee-charging ix reads the fee wallet and amounts from here
+#[proc_macros::assert_size(184)] // +5 to make it /8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ProtocolConfig {
+    pub version: u16,
+
+    pub bump: u8,
+
+    /// the only key that can update this config
+    pub admin: Pubkey,
+
+    /// where protocol fees go
+    pub fee_wallet: Pubkey,
+
+    pub fees: ProtocolFees,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 64],
+}
+
+impl ProtocolConfig {
+    /// only whoever deployed the program can create the config, so it can't be front-run
+    pub fn is_upgrade_authority(program_data: &ProgramData, authority: &Pubkey) -> bool {
+        program_data.upgrade_authority_address == Some(*authority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::*;
+    use crate::instructions::UpdateProtocolConfig;
+
+    fn config_with_admin(admin: Pubkey) -> (Pubkey, ProtocolConfig) {
+        let (key, bump) = Pubkey::find_program_address(&[b"protocol_config"], &crate::ID);
+        let mut config = ProtocolConfig::deserialize(&mut &[0; 256][..]).unwrap();
+        config.bump = bump;
+        config.admin = admin;
+        (key, config)
+    }
+
+    /// runs the account constraints of update_protocol_config
+    fn try_update_accounts(admin: Pubkey, signer: Pubkey, is_signer: bool) -> Result<()> {
+        let (config_key, config) = config_with_admin(admin);
+        let mut config_data = vec![];
+        config.try_serialize(&mut config_data).unwrap();
+        let (mut config_lamports, mut signer_lamports) = (1, 1);
+        let mut signer_data = vec![];
+        let system_program = System::id();
+
+        let accounts = [
+            AccountInfo::new(
+                &config_key,
+                false,
+                true,
+                &mut config_lamports,
+                &mut config_data,
+                &crate::ID,
+                false,
+                0,
+            ),
+            AccountInfo::new(
+                &signer,
+                is_signer,
+                false,
+                &mut signer_lamports,
+                &mut signer_data,
+                &system_program,
+                false,
+                0,
+            ),
+        ];
+
+        UpdateProtocolConfig::try_accounts(
+            &crate::ID,
+            &mut &accounts[..],
+            &[],
+            &mut BTreeMap::new(),
+            &mut BTreeSet::new(),
+        )
+        .map(|_| ())
+    }
+
+    #[test]
+    fn test_update_requires_admin() {
+        let admin = Pubkey::new_unique();
+
+        try_update_accounts(admin, admin, true).unwrap();
+
+        // someone else
+        assert!(try_update_accounts(admin, Pubkey::new_unique(), true).is_err());
+
+        // the admin, but not signing
+        assert!(try_update_accounts(admin, admin, false).is_err());
+    }
+
+    #[test]
+    fn test_init_requires_upgrade_authority() {
+        let authority = Pubkey::new_unique();
+        let program_data = |upgrade_authority_address| ProgramData {
+            slot: 0,
+            upgrade_authority_address,
+        };
+
+        assert!(ProtocolConfig::is_upgrade_authority(
+            &program_data(Some(authority)),
+            &authority
+        ));
+        assert!(!ProtocolConfig::is_upgrade_authority(
+            &program_data(Some(Pubkey::new_unique())),
+            &authority
+        ));
+
+        // frozen programs can't create one at all
+        assert!(!ProtocolConfig::is_upgrade_authority(
+            &program_data(None),
+            &authority
+        ));
+    }
+}
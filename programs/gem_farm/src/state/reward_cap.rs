@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use gem_common::errors::ErrorCode;
+
+/// where accrual above a farmer's cap goes
+#[proc_macros::assert_size(1)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum CapOverflow {
+    /// variable: split across the other stakers, else (or if no one else is staked) back to pending funds
+    Redistribute,
+
+    /// back to pending funds - refunded to funders on cancellation, or rolled into the next funding
+    Refund,
+}
+
+/// optional per-farmer caps on a single reward stream (see FarmReward.farmer_cap)
+/// accrual settled across several periods is assumed to have been earned evenly over them
+#[proc_macros::assert_size(32)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct RewardCap {
+    /// 0 = no per-period cap
+    pub period_sec: u64,
+
+    pub max_per_period: u64,
+
+    /// 0 = no lifetime cap, compared against FarmerReward.accrued_reward
+    pub max_lifetime: u64,
+
+    pub overflow: CapOverflow,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 7],
+}
+
+impl Default for RewardCap {
+    fn default() -> Self {
+        Self {
+            period_sec: 0,
+            max_per_period: 0,
+            max_lifetime: 0,
+            overflow: CapOverflow::Redistribute,
+            _reserved: [0; 7],
+        }
+    }
+}
+
+impl RewardCap {
+    pub fn new(
+        period_sec: u64,
+        max_per_period: u64,
+        max_lifetime: u64,
+        overflow: CapOverflow,
+    ) -> Result<Self> {
+        let cap = Self {
+            period_sec,
+            max_per_period,
+            max_lifetime,
+            overflow,
+            _reserved: [0; 7],
+        };
+        cap.verify()?;
+
+        Ok(cap)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.period_sec > 0 || self.max_lifetime > 0
+    }
+
+    /// a period without a max would cap everything
+    pub fn verify(&self) -> Result<()> {
+        if self.period_sec > 0 && self.max_per_period == 0 {
+            return Err(error!(ErrorCode::InvalidRewardCap));
+        }
+        Ok(())
+    }
+}
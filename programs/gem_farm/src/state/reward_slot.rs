@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+use gem_common::*;
+
+use crate::state::*;
+
+pub const LATEST_REWARD_SLOT_VERSION: u16 = 0;
+
+/// an extra reward stream on top of the farm's built-in reward_a / reward_b
+/// lives in its own PDA (one per reward mint), so a farm can run as many of these as it wants
+#[proc_macros::assert_size(688)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct FarmRewardSlot {
+    pub version: u16,
+
+    pub farm: Pubkey,
+
+    /// sum of all farmer snapshots (see FarmerRewardSlot), the variable reward is split across these
+    /// can lag behind farm.rarity_points_staked until every farmer has been refreshed
+    pub rarity_points_staked: u64,
+
+    pub reward: FarmReward,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 64],
+}
+
+impl FarmRewardSlot {
+    /// updates the slot-level accrued reward, without touching any farmers
+    pub fn update_reward(&mut self, now_ts: u64) -> Result<()> {
+        self.reward.update_accrued_reward_by_type(
+            now_ts,
+            self.rarity_points_staked,
+            None,
+            None,
+            true,
+        )
+    }
+
+    pub fn fund_reward(
+        &mut self,
+        now_ts: u64,
+        variable_rate_config: Option<VariableRateConfig>,
+        fixed_rate_config: Option<FixedRateConfig>,
+    ) -> Result<()> {
+        self.update_reward(now_ts)?;
+        self.reward
+            .fund_reward_by_type(now_ts, variable_rate_config, fixed_rate_config)
+    }
+
+    pub fn cancel_reward(&mut self, now_ts: u64) -> Result<u64> {
+        self.update_reward(now_ts)?;
+        self.reward.cancel_reward_by_type(now_ts)
+    }
+
+    pub fn lock_reward(&mut self) -> Result<()> {
+        self.reward.lock_reward()
+    }
+
+    /// brings the farmer's slot up to date and re-snapshots their rarity points
+    /// snapshots are only refreshed here, so accrual uses min(snapshot, current)
+    ///
+    /// (!) min() alone isn't enough - gems can leave the vault and come back (or go to another
+    /// farmer) between two refreshes. If the farmer began a new staking session since the last
+    /// refresh, we can't tell who held the gems over the interval, so nothing is credited for it
+    pub fn refresh_farmer(
+        &mut self,
+        now_ts: u64,
+        farmer: &Farmer,
+        farmer_slot: &mut FarmerRewardSlot,
+        reenroll: bool,
+    ) -> Result<()> {
+        let snapshot_points = farmer_slot.rarity_points_staked;
+        // the farmer's lock multiplier applies to slots too
+        let current_points = farmer.lock_weighted(farmer.rarity_points_staked)?;
+        let restaked_since_refresh = farmer.begin_staking_ts > farmer_slot.last_refreshed_ts;
+        let effective_points = if restaked_since_refresh {
+            0
+        } else {
+            std::cmp::min(snapshot_points, current_points)
+        };
+
+        match self.reward.reward_type {
+            RewardType::Variable => {
+                // see FarmReward.max_rounding_dust
+                self.reward.max_rounding_dust.try_add_assign(3)?;
+
+                self.reward.variable_rate.update_accrued_reward(
+                    now_ts,
+                    &self.reward.times,
+                    &mut self.reward.funds,
+                    &self.reward.farmer_cap,
+                    self.rarity_points_staked,
+                    Some(effective_points),
+                    Some(&mut farmer_slot.reward),
+                )?;
+            }
+            RewardType::Fixed => {
+                let fixed_rate = &mut self.reward.fixed_rate;
+
+                // funds were reserved for the snapshot, but only the effective points get paid
+                // the difference goes back into the pool
+                let promised_reward = farmer_slot
+                    .reward
+                    .fixed_rate
+                    .newly_accrued_reward(now_ts, snapshot_points)?;
+                let newly_accrued_reward = farmer_slot
+                    .reward
+                    .fixed_rate
+                    .newly_accrued_reward(now_ts, effective_points)?;
+
+                self.reward
+                    .funds
+                    .total_accrued_to_stakers
+                    .try_add_assign(newly_accrued_reward)?;
+                fixed_rate.reserved_amount.try_sub_assign(promised_reward)?;
+
+                farmer_slot
+                    .reward
+                    .update_fixed_reward(now_ts, newly_accrued_reward)?;
+
+                // graduate with the SNAPSHOT (that's what was reserved), re-enroll with CURRENT
+                let original_begin_staking_ts =
+                    fixed_rate.graduate_farmer(snapshot_points, &mut farmer_slot.reward)?;
+
+                if reenroll && current_points > 0 {
+                    fixed_rate.enroll_farmer(
+                        now_ts,
+                        &mut self.reward.times,
+                        &mut self.reward.funds,
+                        current_points,
+                        &mut farmer_slot.reward,
+                        (original_begin_staking_ts > 0).then_some(original_begin_staking_ts),
+                    )?;
+                }
+            }
+        }
+
+        // re-snapshot
+        self.rarity_points_staked = self
+            .rarity_points_staked
+            .try_sub(snapshot_points)?
+            .try_add(current_points)?;
+        farmer_slot.rarity_points_staked = current_points;
+        farmer_slot.last_refreshed_ts = now_ts;
+
+        Ok(())
+    }
+}
+
+/// farmer-side counterpart of FarmRewardSlot, one per (slot, farmer)
+#[proc_macros::assert_size(504)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct FarmerRewardSlot {
+    pub version: u16,
+
+    pub farmer: Pubkey,
+
+    pub reward_slot: Pubkey,
+
+    /// farmer's (lock-weighted) rarity points as of the last refresh of this slot
+    pub rarity_points_staked: u64,
+
+    pub reward: FarmerReward,
+
+    /// when this slot was last refreshed, 0 if never
+    pub last_refreshed_ts: u64,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 32],
+    _reserved2: [u8; 16],
+    _reserved3: [u8; 8],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number192::Number192;
+
+    fn variable_slot() -> FarmRewardSlot {
+        let mut slot = FarmRewardSlot::deserialize(&mut &[0; 1024][..]).unwrap();
+        slot.reward.reward_type = RewardType::Variable;
+        slot.reward.variable_rate.reward_rate = Number192::from(10u64);
+        slot.reward.times.duration_sec = 1000;
+        slot.reward.times.reward_end_ts = 1000;
+        slot.reward.funds.total_funded = 10_000;
+        slot
+    }
+
+    fn farmer_slot() -> FarmerRewardSlot {
+        FarmerRewardSlot::deserialize(&mut &[0; 1024][..]).unwrap()
+    }
+
+    #[test]
+    fn test_refresh_continuous_staker() {
+        let mut slot = variable_slot();
+        let mut farmer = Farmer::unstaked();
+        let mut f_slot = farmer_slot();
+
+        farmer.begin_staking(0, 0, 1, 10).unwrap();
+        slot.refresh_farmer(0, &farmer, &mut f_slot, true).unwrap();
+        assert_eq!(f_slot.last_refreshed_ts, 0);
+
+        slot.refresh_farmer(100, &farmer, &mut f_slot, true)
+            .unwrap();
+        assert_eq!(f_slot.reward.accrued_reward, 1000);
+        assert_eq!(f_slot.last_refreshed_ts, 100);
+    }
+
+    #[test]
+    fn test_refresh_after_restake_no_double_count() {
+        let mut slot = variable_slot();
+        let mut farmer_a = Farmer::unstaked();
+        let mut farmer_b = Farmer::unstaked();
+        let mut a_slot = farmer_slot();
+        let mut b_slot = farmer_slot();
+
+        // A stakes the gem and refreshes once
+        farmer_a.begin_staking(0, 0, 1, 10).unwrap();
+        slot.refresh_farmer(0, &farmer_a, &mut a_slot, true)
+            .unwrap();
+
+        // A unstakes without refreshing, the gem goes to B for 100..150
+        farmer_a.end_staking_begin_cooldown(100, 0).unwrap();
+        farmer_a.end_cooldown(100).unwrap();
+        farmer_b.begin_staking(0, 100, 1, 10).unwrap();
+        slot.refresh_farmer(100, &farmer_b, &mut b_slot, true)
+            .unwrap();
+        farmer_b.end_staking_begin_cooldown(150, 0).unwrap();
+        farmer_b.end_cooldown(150).unwrap();
+
+        // ...and back to A, whose snapshot still says 10 since t=0
+        farmer_a.begin_staking(0, 150, 1, 10).unwrap();
+        slot.refresh_farmer(200, &farmer_a, &mut a_slot, true)
+            .unwrap();
+        slot.refresh_farmer(200, &farmer_b, &mut b_slot, true)
+            .unwrap();
+
+        // the interval spanning A's restake isn't credited at all
+        assert_eq!(a_slot.reward.accrued_reward, 0);
+        assert_eq!(a_slot.rarity_points_staked, 10);
+        assert_eq!(b_slot.rarity_points_staked, 0);
+        assert_eq!(slot.rarity_points_staked, 10);
+
+        // from here on A's snapshot is trusted again
+        slot.refresh_farmer(300, &farmer_a, &mut a_slot, true)
+            .unwrap();
+        assert_eq!(a_slot.reward.accrued_reward, 1000);
+
+        // nobody got paid for more than was emitted
+        assert!(
+            a_slot.reward.accrued_reward + b_slot.reward.accrued_reward
+                <= slot.reward.funds.total_accrued_to_stakers
+        );
+    }
+}
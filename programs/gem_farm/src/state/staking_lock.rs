@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+/// upper bound on the number of locks a farm can offer
+pub const MAX_LOCK_OPTIONS: usize = 4;
+
+/// 1x, multipliers are expressed in basis points
+pub const LOCK_MULTIPLIER_BPS: u64 = 10_000;
+
+#[proc_macros::assert_size(16)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct LockOption {
+    /// replaces the farm's min_staking_period_sec for farmers who pick this lock
+    pub lock_sec: u64,
+
+    /// eg 20000 = the farmer's rarity points count double, for all reward types
+    pub multiplier_bps: u64,
+}
+
+/// the menu of locks farmers can pick from when they stake
+/// farmers who don't pick one get the farm's min_staking_period_sec at 1x, as before
+#[proc_macros::assert_size(72)] // +7 to make it /8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct LockOptions {
+    /// only the first option_count entries are used, farmers pick by index
+    pub options: [LockOption; MAX_LOCK_OPTIONS],
+
+    pub option_count: u8,
+}
+
+impl LockOptions {
+    pub fn new(options: &[LockOption]) -> Result<Self> {
+        if options.len() > MAX_LOCK_OPTIONS {
+            return Err(error!(ErrorCode::InvalidLockOptions));
+        }
+
+        let mut lock_options = Self::default();
+        lock_options.options[..options.len()].copy_from_slice(options);
+        lock_options.option_count = options.len() as u8;
+        lock_options.verify()?;
+
+        Ok(lock_options)
+    }
+
+    pub fn options(&self) -> &[LockOption] {
+        &self.options[..self.option_count as usize]
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.option_count > 0
+    }
+
+    /// every option has to actually lock, and never below 1x
+    pub fn verify(&self) -> Result<()> {
+        if self.option_count as usize > MAX_LOCK_OPTIONS {
+            return Err(error!(ErrorCode::InvalidLockOptions));
+        }
+
+        for option in self.options() {
+            if option.lock_sec == 0 || option.multiplier_bps < LOCK_MULTIPLIER_BPS {
+                return Err(error!(ErrorCode::InvalidLockOptions));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get(&self, index: u8) -> Result<LockOption> {
+        self.options()
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| error!(ErrorCode::InvalidLockOptions))
+    }
+}
+
+/// rounds down, same as the other multipliers
+pub fn apply_lock_multiplier(rarity_points: u64, multiplier_bps: u64) -> Result<u64> {
+    if multiplier_bps == 0 {
+        return Ok(rarity_points);
+    }
+
+    (rarity_points as u128)
+        .try_mul(multiplier_bps as u128)?
+        .try_div(LOCK_MULTIPLIER_BPS as u128)?
+        .try_cast()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(lock_sec: u64, multiplier_bps: u64) -> LockOption {
+        LockOption {
+            lock_sec,
+            multiplier_bps,
+        }
+    }
+
+    #[test]
+    fn test_lock_options() {
+        let lock_options = LockOptions::new(&[
+            option(7 * 86400, 10_000),
+            option(30 * 86400, 12_500),
+            option(90 * 86400, 20_000),
+        ])
+        .unwrap();
+
+        assert_eq!(lock_options.get(1).unwrap(), option(30 * 86400, 12_500));
+        assert!(lock_options.get(3).is_err());
+    }
+
+    #[test]
+    fn test_invalid_lock_options() {
+        // too many options
+        assert!(LockOptions::new(&[option(1, 10_000); MAX_LOCK_OPTIONS + 1]).is_err());
+        // no lock
+        assert!(LockOptions::new(&[option(0, 20_000)]).is_err());
+        // below 1x
+        assert!(LockOptions::new(&[option(100, 9_999)]).is_err());
+    }
+
+    #[test]
+    fn test_apply_lock_multiplier() {
+        assert_eq!(apply_lock_multiplier(3, 0).unwrap(), 3); //no lock picked
+        assert_eq!(apply_lock_multiplier(3, 12_500).unwrap(), 3); //3.75 rounded down
+        assert_eq!(apply_lock_multiplier(3, 20_000).unwrap(), 6);
+        assert!(apply_lock_multiplier(u64::MAX, 20_000).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use gem_common::{errors::ErrorCode, *};
+
+pub const LATEST_TREASURY_SPLIT_VERSION: u16 = 0;
+
+/// upper bound on the number of recipients a split can have
+pub const MAX_TREASURY_RECIPIENTS: usize = 8;
+
+/// 100%, shares are expressed in basis points
+pub const TREASURY_SPLIT_BPS: u64 = 10_000;
+
+#[proc_macros::assert_size(40)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub struct TreasuryRecipient {
+    pub destination: Pubkey,
+
+    pub share_bps: u64,
+}
+
+/// a fixed split of the farm treasury's lamports, paid out by the permissionless distribute_treasury
+/// lives in its own PDA (one per farm) - only farms that want a split need one
+#[proc_macros::assert_size(432)]
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct TreasurySplit {
+    pub version: u16,
+
+    pub farm: Pubkey,
+
+    /// only the first recipient_count entries are used, shares add up to 100%
+    pub recipients: [TreasuryRecipient; MAX_TREASURY_RECIPIENTS],
+
+    pub recipient_count: u8,
+
+    /// reserved for future updates, has to be /8
+    _reserved: [u8; 64],
+}
+
+impl TreasurySplit {
+    pub fn set_recipients(&mut self, recipients: &[TreasuryRecipient]) -> Result<()> {
+        if recipients.is_empty() || recipients.len() > MAX_TREASURY_RECIPIENTS {
+            return Err(error!(ErrorCode::InvalidTreasurySplit));
+        }
+
+        self.recipients = [TreasuryRecipient::default(); MAX_TREASURY_RECIPIENTS];
+        self.recipients[..recipients.len()].copy_from_slice(recipients);
+        self.recipient_count = recipients.len() as u8;
+
+        self.verify()
+    }
+
+    pub fn recipients(&self) -> &[TreasuryRecipient] {
+        &self.recipients[..self.recipient_count as usize]
+    }
+
+    pub fn verify(&self) -> Result<()> {
+        let mut total_bps: u64 = 0;
+        for recipient in self.recipients() {
+            total_bps.try_add_assign(recipient.share_bps)?;
+        }
+
+        if total_bps != TREASURY_SPLIT_BPS {
+            return Err(error!(ErrorCode::InvalidTreasurySplit));
+        }
+        Ok(())
+    }
+
+    /// rounds down, except for the last recipient who gets whatever is left over,
+    /// so the whole amount is always paid out
+    pub fn payouts(&self, amount: u64) -> Result<Vec<u64>> {
+        let mut payouts = Vec::with_capacity(self.recipient_count as usize);
+        let mut left = amount;
+
+        for (i, recipient) in self.recipients().iter().enumerate() {
+            let payout = if i + 1 == self.recipient_count as usize {
+                left
+            } else {
+                (amount as u128)
+                    .try_mul(recipient.share_bps as u128)?
+                    .try_div(TREASURY_SPLIT_BPS as u128)?
+                    .try_cast()?
+            };
+
+            left.try_sub_assign(payout)?;
+            payouts.push(payout);
+        }
+
+        Ok(payouts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl TreasurySplit {
+        pub fn with_shares(shares_bps: &[u64]) -> Result<Self> {
+            let recipients: Vec<TreasuryRecipient> = shares_bps
+                .iter()
+                .map(|&share_bps| TreasuryRecipient {
+                    destination: Pubkey::new_unique(),
+                    share_bps,
+                })
+                .collect();
+
+            let mut split = Self {
+                version: LATEST_TREASURY_SPLIT_VERSION,
+                farm: Pubkey::default(),
+                recipients: [TreasuryRecipient::default(); MAX_TREASURY_RECIPIENTS],
+                recipient_count: 0,
+                _reserved: [0; 64],
+            };
+            split.set_recipients(&recipients)?;
+
+            Ok(split)
+        }
+    }
+
+    #[test]
+    fn test_treasury_payouts() {
+        let split = TreasurySplit::with_shares(&[7000, 2000, 1000]).unwrap();
+
+        assert_eq!(split.payouts(1000).unwrap(), vec![700, 200, 100]);
+        // dust goes to the last recipient
+        assert_eq!(split.payouts(999).unwrap(), vec![699, 199, 101]);
+        assert_eq!(split.payouts(0).unwrap(), vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn test_invalid_treasury_split() {
+        // no recipients
+        assert!(TreasurySplit::with_shares(&[]).is_err());
+        // too many recipients
+        assert!(TreasurySplit::with_shares(&[1000; MAX_TREASURY_RECIPIENTS + 2]).is_err());
+        // not adding up to 100%
+        assert!(TreasurySplit::with_shares(&[7000, 2000]).is_err());
+        assert!(TreasurySplit::with_shares(&[7000, 4000]).is_err());
+    }
+}
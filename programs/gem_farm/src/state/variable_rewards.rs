@@ -1,9 +1,40 @@
 use anchor_lang::prelude::*;
-use gem_common::*;
+use gem_common::{errors::ErrorCode, *};
 
-use crate::{number128::Number128, state::*};
+use crate::{number192::Number192, state::*};
 
-#[proc_macros::assert_size(16)]
+/// how the emission rate evolves over the funded duration
+/// u8-sized (unlike RewardType) so it can be carved out of VariableRateReward's reserve
+#[proc_macros::assert_size(1)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum EmissionCurve {
+    /// same rate throughout
+    Constant,
+
+    /// rate halves every halving_period_sec (piecewise-constant)
+    Halving,
+
+    /// rate goes down linearly, hitting 0 at the end of the duration
+    LinearDecay,
+}
+
+/// what a new funding round does to the running schedule
+#[proc_macros::assert_size(4)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum FundingMode {
+    /// starts a new schedule over duration_sec, spreading the new amount + whatever's pending
+    Reset,
+
+    /// keeps the current rate, pushes the end out by however long the new amount lasts
+    ExtendAtCurrentRate,
+
+    /// keeps the current end, spreads the new amount over the remaining time
+    AddToRate,
+}
+
+#[proc_macros::assert_size(48)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub struct VariableRateConfig {
@@ -11,15 +42,28 @@ pub struct VariableRateConfig {
     pub amount: u64,
 
     /// over which period it's active
+    /// only used by FundingMode::Reset, as are start_ts and the curve
     pub duration_sec: u64,
+
+    /// when the period starts, if not now - nothing accrues before it
+    pub start_ts: Option<u64>,
+
+    pub emission_curve: EmissionCurve,
+
+    /// anything other than Reset needs a running (or scheduled) reward with a constant curve
+    pub funding_mode: FundingMode,
+
+    /// only used by EmissionCurve::Halving, has to be > 0 there
+    pub halving_period_sec: u64,
 }
 
-#[proc_macros::assert_size(72)]
+#[proc_macros::assert_size(88)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct VariableRateReward {
     /// in tokens/s, = calculated as total reward pot at initialization / reward duration
-    pub reward_rate: Number128,
+    /// for decaying curves, this is the rate at the beginning of the reward
+    pub reward_rate: Number192,
 
     /// set to upper bound, not just now_ts (except funding, when there is no upper bound)
     pub reward_last_updated_ts: u64,
@@ -30,10 +74,28 @@ pub struct VariableRateReward {
     /// 1) compare their latest record of flag position, with actual flag position
     /// 2) multiply the difference by the amount they have staked
     /// 3) update their record of flag position, so that next time we don't count this distance again
-    pub accrued_reward_per_rarity_point: Number128,
+    pub accrued_reward_per_rarity_point: Number192,
+
+    /// see VariableRateConfig, set on each funding
+    pub halving_period_sec: u64,
+
+    pub emission_curve: EmissionCurve,
 
-    /// reserved for future updates, has to be /8
-    _reserved: [u8; 32],
+    /// reserved for future updates, has to be /8 (together with emission_curve)
+    _reserved: [u8; 23],
+}
+
+impl From<VariableRateRewardV0> for VariableRateReward {
+    fn from(v0: VariableRateRewardV0) -> Self {
+        Self {
+            reward_rate: v0.reward_rate.into(),
+            reward_last_updated_ts: v0.reward_last_updated_ts,
+            accrued_reward_per_rarity_point: v0.accrued_reward_per_rarity_point.into(),
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
+        }
+    }
 }
 
 impl VariableRateReward {
@@ -43,31 +105,121 @@ impl VariableRateReward {
         times: &mut TimeTracker,
         funds: &mut FundsTracker,
         new_config: VariableRateConfig,
+    ) -> Result<()> {
+        let amount = new_config.amount;
+
+        match new_config.funding_mode {
+            FundingMode::Reset => self.reset_schedule(now_ts, times, funds, new_config)?,
+            mode @ (FundingMode::ExtendAtCurrentRate | FundingMode::AddToRate) => {
+                self.top_up_schedule(now_ts, times, amount, mode)?
+            }
+        }
+
+        funds.total_funded.try_add_assign(amount)?;
+
+        self.reward_last_updated_ts = times.reward_upper_bound(now_ts);
+
+        // msg!("recorded new funding of {}", amount);
+        Ok(())
+    }
+
+    /// "the current rate" only means something for a constant, running reward
+    pub fn can_top_up(&self, now_ts: u64, times: &TimeTracker) -> Result<bool> {
+        Ok(self.emission_curve == EmissionCurve::Constant
+            && times.remaining_duration(now_ts)? > 0
+            && self.reward_rate != Number192::ZERO)
+    }
+
+    /// donations - always go into the running emission, at the current end time
+    pub fn top_up(
+        &mut self,
+        now_ts: u64,
+        times: &mut TimeTracker,
+        funds: &mut FundsTracker,
+        amount: u64,
+    ) -> Result<()> {
+        self.top_up_schedule(now_ts, times, amount, FundingMode::AddToRate)?;
+
+        funds.total_funded.try_add_assign(amount)?;
+
+        self.reward_last_updated_ts = times.reward_upper_bound(now_ts);
+
+        Ok(())
+    }
+
+    /// starts over, with a new duration / curve, merging in whatever's still pending
+    fn reset_schedule(
+        &mut self,
+        now_ts: u64,
+        times: &mut TimeTracker,
+        funds: &FundsTracker,
+        new_config: VariableRateConfig,
     ) -> Result<()> {
         let VariableRateConfig {
             amount,
             duration_sec,
+            start_ts,
+            emission_curve,
+            halving_period_sec,
+            ..
         } = new_config;
 
+        if emission_curve == EmissionCurve::Halving && halving_period_sec == 0 {
+            return Err(error!(ErrorCode::InvalidEmissionCurve));
+        }
+        self.emission_curve = emission_curve;
+        self.halving_period_sec = halving_period_sec;
+
+        // how many "seconds at the initial rate" the whole duration is worth
+        let full_weight = self.curve_weight(duration_sec, duration_sec)?;
+
         // if previous reward has been exhausted
         if now_ts > times.reward_end_ts {
-            self.reward_rate = Number128::from(amount).try_div(Number128::from(duration_sec))?;
+            // rates round down, any dust is left in the pot and can be refunded on cancel
+            self.reward_rate = Number192::from(amount).try_div(full_weight)?;
         // else if previous reward is still active (merge the two)
         } else {
-            self.reward_rate = Number128::from(amount)
-                .try_add(Number128::from(funds.pending_amount()?))?
-                .try_div(Number128::from(duration_sec))?;
+            self.reward_rate = Number192::from(amount)
+                .try_add(Number192::from(funds.pending_amount()?))?
+                .try_div(full_weight)?;
         }
 
-        times.duration_sec = duration_sec;
-        times.reward_end_ts = now_ts.try_add(duration_sec)?;
+        times.begin_reward(now_ts, start_ts, duration_sec)?;
 
-        funds.total_funded.try_add_assign(amount)?;
+        Ok(())
+    }
 
-        self.reward_last_updated_ts = times.reward_upper_bound(now_ts);
+    /// tops up the running schedule without reshaping it
+    fn top_up_schedule(
+        &mut self,
+        now_ts: u64,
+        times: &mut TimeTracker,
+        amount: u64,
+        funding_mode: FundingMode,
+    ) -> Result<()> {
+        if !self.can_top_up(now_ts, times)? {
+            return Err(error!(ErrorCode::InvalidFundingMode));
+        }
 
-        // msg!("recorded new funding of {}", amount);
-        Ok(())
+        let remaining_duration = times.remaining_duration(now_ts)?;
+
+        let new_duration = if funding_mode == FundingMode::ExtendAtCurrentRate {
+            // round down, any dust is left in the pot and can be refunded on cancel
+            let extra_duration = Number192::from(amount)
+                .try_div(self.reward_rate)?
+                .as_u64(0)?;
+            remaining_duration.try_add(extra_duration)?
+        } else {
+            self.reward_rate.try_add_assign(
+                Number192::from(amount).try_div(Number192::from(remaining_duration))?,
+            )?;
+            remaining_duration
+        };
+
+        // the curve is integrated from its beginning (see emitted_until),
+        // so re-anchor it to where accrual left off before changing it
+        let begin_ts = times.reward_upper_bound(now_ts);
+        times.begin_reward(now_ts, Some(begin_ts), new_duration)
     }
 
     pub fn cancel_reward(
@@ -81,18 +233,20 @@ impl VariableRateReward {
 
         times.end_reward(now_ts)?;
 
-        self.reward_rate = Number128::ZERO;
+        self.reward_rate = Number192::ZERO;
         self.reward_last_updated_ts = times.reward_upper_bound(now_ts);
 
         // msg!("prepared a total refund of {}", refund_amount);
         Ok(refund_amount)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_accrued_reward(
         &mut self,
         now_ts: u64,
         times: &TimeTracker,
         funds: &mut FundsTracker,
+        cap: &RewardCap,
         farm_rarity_points_staked: u64,
         farmer_rarity_points_staked: Option<u64>,
         farmer_reward: Option<&mut FarmerReward>,
@@ -100,8 +254,11 @@ impl VariableRateReward {
         let reward_upper_bound = times.reward_upper_bound(now_ts);
 
         // calc & update reward per rarity point
-        let newly_accrued_reward_per_rarity_point = self
-            .newly_accrued_reward_per_rarity_point(farm_rarity_points_staked, reward_upper_bound)?;
+        let newly_accrued_reward_per_rarity_point = self.newly_accrued_reward_per_rarity_point(
+            times,
+            farm_rarity_points_staked,
+            reward_upper_bound,
+        )?;
 
         self.accrued_reward_per_rarity_point
             .try_add_assign(newly_accrued_reward_per_rarity_point)?;
@@ -109,25 +266,37 @@ impl VariableRateReward {
         // update overall reward
         funds.total_accrued_to_stakers.try_add_assign(
             newly_accrued_reward_per_rarity_point
-                .try_mul(Number128::from(farm_rarity_points_staked))?
+                .try_mul_ceil(Number192::from(farm_rarity_points_staked))?
                 .as_u64_ceil(0)?, //overestimate at farm level
         )?;
 
         // update farmer, if one was passed
         if let Some(farmer_reward) = farmer_reward {
-            let newly_accrued_to_farmer = Number128::from(farmer_rarity_points_staked.unwrap())
+            let farmer_rarity_points_staked = farmer_rarity_points_staked.unwrap();
+            let newly_accrued_to_farmer = Number192::from(farmer_rarity_points_staked)
                 .try_mul(
                     self.accrued_reward_per_rarity_point.try_sub(
                         farmer_reward
                             .variable_rate
                             .last_recorded_accrued_reward_per_rarity_point,
                     )?,
-                )?;
+                )?
+                .as_u64(0)?; //underestimate at farmer level
 
-            farmer_reward.update_variable_reward(
-                newly_accrued_to_farmer.as_u64(0)?, //underestimate at farmer level
-                self.accrued_reward_per_rarity_point,
-            )?;
+            let capped = farmer_reward.apply_cap(cap, now_ts, newly_accrued_to_farmer)?;
+
+            farmer_reward.update_variable_reward(capped, self.accrued_reward_per_rarity_point)?;
+
+            let overflow = newly_accrued_to_farmer.try_sub(capped)?;
+            if overflow > 0 {
+                self.place_cap_overflow(
+                    cap,
+                    funds,
+                    overflow,
+                    farm_rarity_points_staked.saturating_sub(farmer_rarity_points_staked),
+                    farmer_reward,
+                )?;
+            }
         }
 
         self.reward_last_updated_ts = reward_upper_bound;
@@ -136,51 +305,155 @@ impl VariableRateReward {
         Ok(())
     }
 
+    /// counted as accrued to stakers already, so redistributing just re-splits it
+    fn place_cap_overflow(
+        &mut self,
+        cap: &RewardCap,
+        funds: &mut FundsTracker,
+        overflow: u64,
+        other_rarity_points_staked: u64,
+        farmer_reward: &mut FarmerReward,
+    ) -> Result<()> {
+        match cap.overflow {
+            CapOverflow::Redistribute if other_rarity_points_staked > 0 => {
+                self.accrued_reward_per_rarity_point.try_add_assign(
+                    Number192::from(overflow)
+                        .try_div(Number192::from(other_rarity_points_staked))?,
+                )?;
+
+                // the capped farmer doesn't get a cut of their own overflow
+                farmer_reward
+                    .variable_rate
+                    .last_recorded_accrued_reward_per_rarity_point =
+                    self.accrued_reward_per_rarity_point;
+            }
+            _ => {
+                funds.total_accrued_to_stakers.try_sub_assign(overflow)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn newly_accrued_reward_per_rarity_point(
         &self,
+        times: &TimeTracker,
         farm_rarity_points_staked: u64,
         reward_upper_bound: u64,
-    ) -> Result<Number128> {
+    ) -> Result<Number192> {
         if farm_rarity_points_staked == 0 {
             msg!("no gems are staked at the farm, means no new rewards accrue");
-            return Ok(Number128::ZERO);
+            return Ok(Number192::ZERO);
         }
 
-        let time_since_last_calc = reward_upper_bound.try_sub(self.reward_last_updated_ts)?;
+        // integrate the curve as a difference of totals, so rounding never piles up across updates
+        let emitted_since_last_calc = self
+            .emitted_until(times, reward_upper_bound)?
+            .try_sub(self.emitted_until(times, self.reward_last_updated_ts)?)?;
+
+        // round down, so that the pool never promises more than it has
+        emitted_since_last_calc.try_div(Number192::from(farm_rarity_points_staked))
+    }
+
+    /// total emitted between the beginning of the reward and ts
+    /// rounds down, and the full duration never emits more than the funding the rate was set from
+    fn emitted_until(&self, times: &TimeTracker, ts: u64) -> Result<Number192> {
+        let elapsed = std::cmp::min(ts.saturating_sub(times.reward_begin_ts), times.duration_sec);
+
+        self.reward_rate
+            .try_mul(self.curve_weight(elapsed, times.duration_sec)?)
+    }
 
-        Number128::from(time_since_last_calc)
-            .try_mul(self.reward_rate)?
-            .try_div(Number128::from(farm_rarity_points_staked))
+    /// integral of the curve over the first `elapsed` secs, in units of the initial rate
+    fn curve_weight(&self, elapsed: u64, duration_sec: u64) -> Result<Number192> {
+        match self.emission_curve {
+            EmissionCurve::Constant => Ok(Number192::from(elapsed)),
+            // rate * (1 - t/D) integrates to x * (2D - x) / 2D
+            EmissionCurve::LinearDecay => {
+                let double_duration = duration_sec.try_mul(2)?;
+                Number192::from(elapsed)
+                    .try_mul(Number192::from(double_duration.try_sub(elapsed)?))?
+                    .try_div(Number192::from(double_duration))
+            }
+            // k full periods + rem secs emit 2P - (2P - rem) / 2^k
+            EmissionCurve::Halving => {
+                let period = self.halving_period_sec;
+                let halvings = elapsed.try_div(period)?;
+                let rem = elapsed.try_rem(period)?;
+                let double_period = Number192::from(period.try_mul(2)?);
+
+                if halvings >= 128 {
+                    return Ok(double_period);
+                }
+
+                double_period.try_sub(
+                    Number192::from(period.try_mul(2)?.try_sub(rem)?)
+                        .try_ceil_div(Number192::from(1u128 << halvings))?,
+                )
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::number128::Number128;
 
     #[test]
     fn test_accrued_reward_per_rarity_point() {
         let var_reward = VariableRateReward {
-            reward_rate: Number128::from(10u64),
+            reward_rate: Number192::from(10u64),
             reward_last_updated_ts: 200,
-            accrued_reward_per_rarity_point: Number128::from(1234u64),
-            _reserved: [0; 32],
+            accrued_reward_per_rarity_point: Number192::from(1234u64),
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
         };
 
+        let times = TimeTracker {
+            duration_sec: 100,
+            reward_begin_ts: 150,
+            reward_end_ts: 250,
+            lock_end_ts: 0,
+        };
         let farm_points_staked = 25;
         let reward_upper_bound = 205;
 
         let newly_accrued = var_reward
-            .newly_accrued_reward_per_rarity_point(farm_points_staked, reward_upper_bound)
+            .newly_accrued_reward_per_rarity_point(&times, farm_points_staked, reward_upper_bound)
             .unwrap();
 
-        assert_eq!(newly_accrued, Number128::from(2u64));
+        assert_eq!(newly_accrued, Number192::from(2u64));
+    }
+
+    #[test]
+    fn test_from_v0_keeps_values() {
+        let v0 = VariableRateRewardV0 {
+            reward_rate: Number128::from_decimal(125u64, -3i32),
+            reward_last_updated_ts: 200,
+            accrued_reward_per_rarity_point: Number128::from(1234u64),
+            _reserved: [0; 32],
+        };
+
+        let var_reward = VariableRateReward::from(v0);
+
+        assert_eq!(
+            var_reward.reward_rate,
+            Number192::from_decimal(125u64, -3i32).unwrap()
+        );
+        assert_eq!(var_reward.reward_last_updated_ts, 200);
+        assert_eq!(
+            var_reward.accrued_reward_per_rarity_point,
+            Number192::from(1234u64)
+        );
     }
 
     #[test]
     fn test_fund_reward_fresh() {
         let mut times = TimeTracker {
             duration_sec: 10,
+            reward_begin_ts: 190,
             reward_end_ts: 200,
             lock_end_ts: 0,
         };
@@ -192,15 +465,21 @@ mod tests {
         let new_config = VariableRateConfig {
             amount: 10,
             duration_sec: 80,
+            start_ts: None,
+            emission_curve: EmissionCurve::Constant,
+            halving_period_sec: 0,
+            funding_mode: FundingMode::Reset,
         };
 
         let now_ts = 201; //just after the previous reward ends at 200s
 
         let mut var_reward = VariableRateReward {
-            reward_rate: Number128::from(10u64),
+            reward_rate: Number192::from(10u64),
             reward_last_updated_ts: 0,
-            accrued_reward_per_rarity_point: Number128::from(1234u64),
-            _reserved: [0; 32],
+            accrued_reward_per_rarity_point: Number192::from(1234u64),
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
         };
 
         var_reward
@@ -209,12 +488,12 @@ mod tests {
 
         assert_eq!(
             var_reward.reward_rate,
-            Number128::from_decimal(125u64, -3i32)
+            Number192::from_decimal(125u64, -3i32).unwrap()
         );
         assert_eq!(var_reward.reward_last_updated_ts, 201);
         assert_eq!(
             var_reward.accrued_reward_per_rarity_point,
-            Number128::from(1234u64)
+            Number192::from(1234u64)
         );
 
         assert_eq!(funds.total_funded, 110);
@@ -227,6 +506,7 @@ mod tests {
     fn test_fund_reward_merged_1() {
         let mut times = TimeTracker {
             duration_sec: 10,
+            reward_begin_ts: 190,
             reward_end_ts: 200,
             lock_end_ts: 0,
         };
@@ -238,26 +518,35 @@ mod tests {
         let new_config = VariableRateConfig {
             amount: 100,
             duration_sec: 400,
+            start_ts: None,
+            emission_curve: EmissionCurve::Constant,
+            halving_period_sec: 0,
+            funding_mode: FundingMode::Reset,
         };
 
         let now_ts = 199; //just before the previous reward, which triggers a merge
 
         let mut var_reward = VariableRateReward {
-            reward_rate: Number128::from(10u64),
+            reward_rate: Number192::from(10u64),
             reward_last_updated_ts: 0,
-            accrued_reward_per_rarity_point: Number128::from(1234u64),
-            _reserved: [0; 32],
+            accrued_reward_per_rarity_point: Number192::from(1234u64),
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
         };
 
         var_reward
             .fund_reward(now_ts, &mut times, &mut funds, new_config)
             .unwrap();
 
-        assert_eq!(var_reward.reward_rate, Number128::from_decimal(5u64, -1i32));
+        assert_eq!(
+            var_reward.reward_rate,
+            Number192::from_decimal(5u64, -1i32).unwrap()
+        );
         assert_eq!(var_reward.reward_last_updated_ts, 199);
         assert_eq!(
             var_reward.accrued_reward_per_rarity_point,
-            Number128::from(1234u64)
+            Number192::from(1234u64)
         );
 
         assert_eq!(funds.total_funded, 200);
@@ -271,6 +560,7 @@ mod tests {
     fn test_fund_reward_merged_2() {
         let mut times = TimeTracker {
             duration_sec: 10,
+            reward_begin_ts: 190,
             reward_end_ts: 200,
             lock_end_ts: 0,
         };
@@ -282,15 +572,21 @@ mod tests {
         let new_config = VariableRateConfig {
             amount: 100,
             duration_sec: 400,
+            start_ts: None,
+            emission_curve: EmissionCurve::Constant,
+            halving_period_sec: 0,
+            funding_mode: FundingMode::Reset,
         };
 
         let now_ts = 199; //just before the previous reward, which triggers a merge
 
         let mut var_reward = VariableRateReward {
-            reward_rate: Number128::from(10u64),
+            reward_rate: Number192::from(10u64),
             reward_last_updated_ts: 0,
-            accrued_reward_per_rarity_point: Number128::from(1234u64),
-            _reserved: [0; 32],
+            accrued_reward_per_rarity_point: Number192::from(1234u64),
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
         };
 
         var_reward
@@ -299,12 +595,12 @@ mod tests {
 
         assert_eq!(
             var_reward.reward_rate,
-            Number128::from_decimal(375u64, -3i32)
+            Number192::from_decimal(375u64, -3i32).unwrap()
         );
         assert_eq!(var_reward.reward_last_updated_ts, 199);
         assert_eq!(
             var_reward.accrued_reward_per_rarity_point,
-            Number128::from(1234u64)
+            Number192::from(1234u64)
         );
 
         assert_eq!(funds.total_funded, 200);
@@ -312,4 +608,461 @@ mod tests {
         assert_eq!(times.duration_sec, 400);
         assert_eq!(times.reward_end_ts, 599);
     }
+
+    #[test]
+    fn test_fund_reward_scheduled() {
+        let mut times = TimeTracker {
+            duration_sec: 0,
+            reward_begin_ts: 0,
+            reward_end_ts: 0,
+            lock_end_ts: 0,
+        };
+        let mut funds = FundsTracker {
+            total_funded: 0,
+            total_refunded: 0,
+            total_accrued_to_stakers: 0,
+        };
+        let new_config = VariableRateConfig {
+            amount: 100,
+            duration_sec: 100,
+            start_ts: Some(300),
+            emission_curve: EmissionCurve::Constant,
+            halving_period_sec: 0,
+            funding_mode: FundingMode::Reset,
+        };
+
+        let mut var_reward = VariableRateReward {
+            reward_rate: Number192::ZERO,
+            reward_last_updated_ts: 0,
+            accrued_reward_per_rarity_point: Number192::ZERO,
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
+        };
+
+        var_reward
+            .fund_reward(200, &mut times, &mut funds, new_config)
+            .unwrap();
+
+        assert_eq!(var_reward.reward_rate, Number192::from(1u64));
+        assert_eq!(var_reward.reward_last_updated_ts, 300);
+        assert_eq!(times.reward_begin_ts, 300);
+        assert_eq!(times.reward_end_ts, 400);
+
+        // before the start nothing accrues
+        var_reward
+            .update_accrued_reward(
+                250,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                10,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(var_reward.accrued_reward_per_rarity_point, Number192::ZERO);
+        assert_eq!(funds.total_accrued_to_stakers, 0);
+
+        // after it does, only from the start
+        var_reward
+            .update_accrued_reward(
+                320,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                10,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            var_reward.accrued_reward_per_rarity_point,
+            Number192::from(2u64)
+        );
+        assert_eq!(funds.total_accrued_to_stakers, 20);
+    }
+
+    fn fund_curve(
+        emission_curve: EmissionCurve,
+        halving_period_sec: u64,
+    ) -> Result<(VariableRateReward, TimeTracker, FundsTracker)> {
+        let mut times = TimeTracker {
+            duration_sec: 0,
+            reward_begin_ts: 0,
+            reward_end_ts: 0,
+            lock_end_ts: 0,
+        };
+        let mut funds = FundsTracker {
+            total_funded: 0,
+            total_refunded: 0,
+            total_accrued_to_stakers: 0,
+        };
+        let mut var_reward = VariableRateReward {
+            reward_rate: Number192::ZERO,
+            reward_last_updated_ts: 0,
+            accrued_reward_per_rarity_point: Number192::ZERO,
+            halving_period_sec: 0,
+            emission_curve: EmissionCurve::Constant,
+            _reserved: [0; 23],
+        };
+
+        var_reward.fund_reward(
+            100,
+            &mut times,
+            &mut funds,
+            VariableRateConfig {
+                amount: 1_000_000,
+                duration_sec: 400,
+                start_ts: None,
+                emission_curve,
+                halving_period_sec,
+                funding_mode: FundingMode::Reset,
+            },
+        )?;
+
+        Ok((var_reward, times, funds))
+    }
+
+    #[test]
+    fn test_halving_curve() {
+        let (mut var_reward, times, mut funds) = fund_curve(EmissionCurve::Halving, 100).unwrap();
+
+        // weight = 100 + 50 + 25 + 12.5 = 187.5
+        assert_eq!(
+            var_reward.reward_rate.as_u64(0).unwrap(),
+            1_000_000 * 2 / 375
+        );
+
+        // first period emits 8/15 of the total, the second 4/15
+        var_reward
+            .update_accrued_reward(
+                200,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 533_334);
+        var_reward
+            .update_accrued_reward(
+                300,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 533_334 + 266_667);
+
+        // the full duration never emits more than was funded
+        let emitted = var_reward
+            .emitted_until(&times, times.reward_end_ts)
+            .unwrap();
+        assert!(emitted <= Number192::from(1_000_000u64));
+        assert!(emitted >= Number192::from(1_000_000u64 - 1));
+    }
+
+    #[test]
+    fn test_linear_decay_curve() {
+        let (mut var_reward, times, mut funds) = fund_curve(EmissionCurve::LinearDecay, 0).unwrap();
+
+        // starts at twice the average rate
+        assert_eq!(var_reward.reward_rate, Number192::from(5000u64));
+
+        // first half emits 3/4 of the total
+        var_reward
+            .update_accrued_reward(
+                300,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 750_000);
+
+        var_reward
+            .update_accrued_reward(
+                500,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 1_000_000);
+    }
+
+    #[test]
+    fn test_curve_cancel_is_exact() {
+        let (mut var_reward, mut times, mut funds) =
+            fund_curve(EmissionCurve::Halving, 30).unwrap();
+
+        for now_ts in [117, 155, 199, 263] {
+            var_reward
+                .update_accrued_reward(
+                    now_ts,
+                    &times,
+                    &mut funds,
+                    &RewardCap::default(),
+                    7,
+                    None,
+                    None,
+                )
+                .unwrap();
+        }
+        let refund = var_reward
+            .cancel_reward(263, &mut times, &mut funds)
+            .unwrap();
+
+        assert_eq!(funds.total_accrued_to_stakers + refund, 1_000_000);
+        assert_eq!(funds.pending_amount().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_halving_needs_period() {
+        assert!(fund_curve(EmissionCurve::Halving, 0).is_err());
+    }
+
+    fn top_up_config(amount: u64, funding_mode: FundingMode) -> VariableRateConfig {
+        VariableRateConfig {
+            amount,
+            duration_sec: 0,
+            start_ts: None,
+            emission_curve: EmissionCurve::Constant,
+            halving_period_sec: 0,
+            funding_mode,
+        }
+    }
+
+    #[test]
+    fn test_extend_at_current_rate() {
+        // 2500/s from 100 to 500
+        let (mut var_reward, mut times, mut funds) =
+            fund_curve(EmissionCurve::Constant, 0).unwrap();
+
+        var_reward
+            .update_accrued_reward(
+                200,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        var_reward
+            .fund_reward(
+                200,
+                &mut times,
+                &mut funds,
+                top_up_config(500_000, FundingMode::ExtendAtCurrentRate),
+            )
+            .unwrap();
+
+        assert_eq!(var_reward.reward_rate, Number192::from(2500u64));
+        assert_eq!(times.reward_end_ts, 700);
+        assert_eq!(funds.total_funded, 1_500_000);
+
+        var_reward
+            .update_accrued_reward(
+                700,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 1_500_000);
+        assert_eq!(funds.pending_amount().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_add_to_rate() {
+        // 2500/s from 100 to 500
+        let (mut var_reward, mut times, mut funds) =
+            fund_curve(EmissionCurve::Constant, 0).unwrap();
+
+        var_reward
+            .update_accrued_reward(
+                300,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        var_reward
+            .fund_reward(
+                300,
+                &mut times,
+                &mut funds,
+                top_up_config(400_000, FundingMode::AddToRate),
+            )
+            .unwrap();
+
+        assert_eq!(var_reward.reward_rate, Number192::from(4500u64));
+        assert_eq!(times.reward_end_ts, 500);
+
+        var_reward
+            .update_accrued_reward(
+                400,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 500_000 + 450_000);
+
+        var_reward
+            .update_accrued_reward(
+                500,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(funds.total_accrued_to_stakers, 1_400_000);
+    }
+
+    #[test]
+    fn test_top_up_needs_running_constant_reward() {
+        let (mut var_reward, mut times, mut funds) =
+            fund_curve(EmissionCurve::LinearDecay, 0).unwrap();
+        assert!(var_reward
+            .fund_reward(
+                200,
+                &mut times,
+                &mut funds,
+                top_up_config(100, FundingMode::AddToRate),
+            )
+            .is_err());
+
+        let (mut var_reward, mut times, mut funds) =
+            fund_curve(EmissionCurve::Constant, 0).unwrap();
+        assert!(var_reward
+            .fund_reward(
+                500,
+                &mut times,
+                &mut funds,
+                top_up_config(100, FundingMode::ExtendAtCurrentRate),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_public_top_up() {
+        // 2500/s from 100 to 500
+        let (mut var_reward, mut times, mut funds) =
+            fund_curve(EmissionCurve::Constant, 0).unwrap();
+
+        var_reward
+            .update_accrued_reward(
+                300,
+                &times,
+                &mut funds,
+                &RewardCap::default(),
+                1,
+                None,
+                None,
+            )
+            .unwrap();
+        var_reward
+            .top_up(300, &mut times, &mut funds, 400_000)
+            .unwrap();
+
+        assert_eq!(var_reward.reward_rate, Number192::from(4500u64));
+        assert_eq!(times.reward_end_ts, 500);
+        assert_eq!(funds.total_funded, 1_400_000);
+
+        // nothing left to top up
+        assert!(var_reward.top_up(500, &mut times, &mut funds, 1).is_err());
+    }
+
+    #[test]
+    fn test_cap_overflow() {
+        for overflow in [CapOverflow::Redistribute, CapOverflow::Refund] {
+            // 2500/s from 100 to 500, 4 points staked, 1 of them the capped farmer's
+            let (mut var_reward, times, mut funds) =
+                fund_curve(EmissionCurve::Constant, 0).unwrap();
+            let cap = RewardCap::new(0, 0, 100_000, overflow).unwrap();
+            let mut farmer_reward = FarmerReward::new();
+            farmer_reward.accrued_reward = 0;
+            farmer_reward
+                .variable_rate
+                .last_recorded_accrued_reward_per_rarity_point = Number192::ZERO;
+
+            var_reward
+                .update_accrued_reward(
+                    200,
+                    &times,
+                    &mut funds,
+                    &cap,
+                    4,
+                    Some(1),
+                    Some(&mut farmer_reward),
+                )
+                .unwrap();
+            assert_eq!(farmer_reward.accrued_reward, 62_500);
+
+            // 62_500 more, only 37_500 fit under the cap
+            var_reward
+                .update_accrued_reward(
+                    300,
+                    &times,
+                    &mut funds,
+                    &cap,
+                    4,
+                    Some(1),
+                    Some(&mut farmer_reward),
+                )
+                .unwrap();
+            assert_eq!(farmer_reward.accrued_reward, 100_000);
+
+            match overflow {
+                // the other 3 points get the 25_000 on top of their 375_000
+                CapOverflow::Redistribute => {
+                    assert_eq!(funds.total_accrued_to_stakers, 500_000);
+                    assert_eq!(
+                        var_reward
+                            .accrued_reward_per_rarity_point
+                            .as_u64(0)
+                            .unwrap(),
+                        133_333
+                    );
+                    assert_eq!(
+                        farmer_reward
+                            .variable_rate
+                            .last_recorded_accrued_reward_per_rarity_point,
+                        var_reward.accrued_reward_per_rarity_point
+                    );
+                }
+                CapOverflow::Refund => {
+                    assert_eq!(funds.total_accrued_to_stakers, 475_000);
+                    assert_eq!(funds.pending_amount().unwrap(), 525_000);
+                }
+            }
+        }
+    }
 }
@@ -30,6 +30,8 @@ import {
   findFarmAuthorityPDA,
   findFarmerPDA,
   findFarmTreasuryPDA,
+  findFunderContributionPDA,
+  findProtocolConfigPDA,
   findRewardsPotPDA,
 } from './gem-farm.pda';
 import { PROGRAM_ID as AUTH_PROG_ID } from '@metaplex-foundation/mpl-token-auth-rules/dist/src/generated';
@@ -39,12 +41,23 @@ export const feeAccount = new PublicKey(
   '2xhBxVVuXkdq2MRKerE9mr2s1szfHSedy21MVqf8gPoM'
 );
 
+const BPF_UPGRADEABLE_LOADER_ID = new PublicKey(
+  'BPFLoaderUpgradeab1e11111111111111111111111'
+);
+
 //acts as an enum
 export const RewardType = {
   Variable: { variable: {} },
   Fixed: { fixed: {} },
 };
 
+//acts as an enum
+export const FundableReward = {
+  Any: { any: {} },
+  RewardA: { rewardA: {} },
+  RewardB: { rewardB: {} },
+};
+
 export interface FarmConfig {
   minStakingPeriodSec: BN;
   cooldownPeriodSec: BN;
@@ -57,6 +70,28 @@ export interface MaxCounts {
   maxRarityPoints: number;
 }
 
+export interface LoyaltyBoostTier {
+  requiredTenure: BN;
+  multiplierBps: BN;
+}
+
+//tiers always has MAX_LOYALTY_BOOST_TIERS (4) entries, only the first tierCount are used
+export interface LoyaltyBoost {
+  tiers: LoyaltyBoostTier[];
+  tierCount: number;
+}
+
+export interface LockOption {
+  lockSec: BN;
+  multiplierBps: BN;
+}
+
+//options always has MAX_LOCK_OPTIONS (4) entries, only the first optionCount are used
+export interface LockOptions {
+  options: LockOption[];
+  optionCount: number;
+}
+
 export interface TierConfig {
   rewardRate: BN;
   requiredTenure: BN;
@@ -81,6 +116,16 @@ export interface VariableRateConfig {
   durationSec: BN;
 }
 
+//all amounts in lamports, 0 = free
+export interface ProtocolFees {
+  initFarm: BN;
+  initFarmer: BN;
+  enterFarm: BN;
+  stake: BN;
+  unstake: BN;
+  flashDeposit: BN;
+}
+
 export interface RarityConfig {
   mint: PublicKey;
   rarityPoints: number;
@@ -132,6 +177,11 @@ export class GemFarmClient extends GemBankClient {
     );
   }
 
+  async fetchProtocolConfigAcc() {
+    const [protocolConfig] = await findProtocolConfigPDA();
+    return this.farmProgram.account.protocolConfig.fetch(protocolConfig);
+  }
+
   async fetchTokenAcc(rewardMint: PublicKey, rewardAcc: PublicKey) {
     return this.deserializeTokenAccount(rewardMint, rewardAcc);
   }
@@ -206,6 +256,68 @@ export class GemFarmClient extends GemBankClient {
     return pdas;
   }
 
+  // --------------------------------------- protocol ixs
+
+  //can only be called once, by the program's upgrade authority
+  async initProtocolConfig(
+    upgradeAuthority: PublicKey | Keypair,
+    admin: PublicKey,
+    feeWallet: PublicKey,
+    fees: ProtocolFees
+  ) {
+    const upgradeAuthorityPk = isKp(upgradeAuthority)
+      ? (<Keypair>upgradeAuthority).publicKey
+      : <PublicKey>upgradeAuthority;
+
+    const [protocolConfig, protocolConfigBump] = await findProtocolConfigPDA();
+    const [programData] = await PublicKey.findProgramAddress(
+      [this.farmProgram.programId.toBytes()],
+      BPF_UPGRADEABLE_LOADER_ID
+    );
+
+    const signers = [];
+    if (isKp(upgradeAuthority)) signers.push(<Keypair>upgradeAuthority);
+
+    console.log('starting protocol config at', protocolConfig.toBase58());
+    const txSig = await this.farmProgram.methods
+      .initProtocolConfig(admin, feeWallet, fees)
+      .accounts({
+        protocolConfig,
+        upgradeAuthority: upgradeAuthorityPk,
+        gemFarm: this.farmProgram.programId,
+        programData,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(signers)
+      .rpc();
+
+    return { protocolConfig, protocolConfigBump, programData, txSig };
+  }
+
+  async updateProtocolConfig(
+    admin: PublicKey | Keypair,
+    newAdmin: PublicKey | null = null,
+    feeWallet: PublicKey | null = null,
+    fees: ProtocolFees | null = null
+  ) {
+    const [protocolConfig] = await findProtocolConfigPDA();
+
+    const signers = [];
+    if (isKp(admin)) signers.push(<Keypair>admin);
+
+    console.log('updating protocol config');
+    const txSig = await this.farmProgram.methods
+      .updateProtocolConfig(newAdmin, feeWallet, fees)
+      .accounts({
+        protocolConfig,
+        admin: isKp(admin) ? (<Keypair>admin).publicKey : admin,
+      })
+      .signers(signers)
+      .rpc();
+
+    return { protocolConfig, txSig };
+  }
+
   // --------------------------------------- core ixs
 
   async initFarm(
@@ -232,6 +344,7 @@ export class GemFarmClient extends GemBankClient {
       farm.publicKey,
       rewardBMint
     );
+    const [protocolConfig] = await findProtocolConfigPDA();
 
     const signers = [farm, bank];
     if (isKp(farmManager)) signers.push(<Keypair>farmManager);
@@ -255,6 +368,7 @@ export class GemFarmClient extends GemBankClient {
           : farmManager,
         farmAuthority: farmAuth,
         payer: isKp(payer) ? (<Keypair>payer).publicKey : farmManager,
+        protocolConfig,
         feeAcc: feeAccount,
         rewardAPot,
         rewardAMint,
@@ -287,14 +401,24 @@ export class GemFarmClient extends GemBankClient {
     farmManager: PublicKey | Keypair,
     config: FarmConfig | null = null,
     newManager: PublicKey | null = null,
-    maxCounts?: MaxCounts
+    maxCounts?: MaxCounts,
+    restakeKeepsTenure: boolean | null = null,
+    loyaltyBoost: LoyaltyBoost | null = null,
+    lockOptions: LockOptions | null = null
   ) {
     const signers = [];
     if (isKp(farmManager)) signers.push(<Keypair>farmManager);
 
     console.log('updating farm');
     const txSig = await this.farmProgram.methods
-      .updateFarm(config, newManager, maxCounts ?? null)
+      .updateFarm(
+        config,
+        newManager,
+        maxCounts ?? null,
+        restakeKeepsTenure,
+        loyaltyBoost,
+        lockOptions
+      )
       .accounts({
         farm,
         farmManager: isKp(farmManager)
@@ -474,6 +598,7 @@ export class GemFarmClient extends GemBankClient {
     const [farmer, farmerBump] = await findFarmerPDA(farm, identityPk);
     const [vault, vaultBump] = await findVaultPDA(farmAcc.bank, identityPk);
     const [vaultAuth, vaultAuthBump] = await findVaultAuthorityPDA(vault); //nice-to-have
+    const [protocolConfig] = await findProtocolConfigPDA();
 
     const signers = [];
     if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
@@ -487,6 +612,7 @@ export class GemFarmClient extends GemBankClient {
         farmer,
         identity: identityPk,
         payer: isKp(payer) ? (<Keypair>payer).publicKey : payer,
+        protocolConfig,
         feeAcc: feeAccount,
         bank: farmAcc.bank,
         vault,
@@ -510,7 +636,8 @@ export class GemFarmClient extends GemBankClient {
     farm: PublicKey,
     farmerIdentity: PublicKey | Keypair,
     unstake = false,
-    skipRewards = false
+    skipRewards = false,
+    lockOption: number | null = null
   ) {
     const {
       farmer,
@@ -522,7 +649,13 @@ export class GemFarmClient extends GemBankClient {
       farmTreasury,
       farmTreasuryBump,
       builder,
-    } = await this.buildStakeCommon(farm, farmerIdentity, unstake, skipRewards);
+    } = await this.buildStakeCommon(
+      farm,
+      farmerIdentity,
+      unstake,
+      skipRewards,
+      lockOption
+    );
 
     const txSig = await builder.rpc();
 
@@ -543,7 +676,8 @@ export class GemFarmClient extends GemBankClient {
     farm: PublicKey,
     farmerIdentity: PublicKey | Keypair,
     unstake = false,
-    skipRewards = false
+    skipRewards = false,
+    lockOption: number | null = null
   ) {
     const identityPk = isKp(farmerIdentity)
       ? (<Keypair>farmerIdentity).publicKey
@@ -555,6 +689,7 @@ export class GemFarmClient extends GemBankClient {
     const [vault, vaultBump] = await findVaultPDA(farmAcc.bank, identityPk);
     const [farmAuth, farmAuthBump] = await findFarmAuthorityPDA(farm);
     const [farmTreasury, farmTreasuryBump] = await findFarmTreasuryPDA(farm);
+    const [protocolConfig] = await findProtocolConfigPDA();
 
     const signers = [];
     if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
@@ -572,11 +707,12 @@ export class GemFarmClient extends GemBankClient {
             farmAuthority: farmAuth,
             gemBank: this.bankProgram.programId,
             systemProgram: SystemProgram.programId,
+            protocolConfig,
             feeAcc: feeAccount,
           })
           .signers(signers)
       : await this.farmProgram.methods
-          .stake(farmAuthBump, farmerBump)
+          .stake(farmAuthBump, farmerBump, lockOption)
           .accounts({
             farm,
             farmer,
@@ -585,6 +721,7 @@ export class GemFarmClient extends GemBankClient {
             vault,
             farmAuthority: farmAuth,
             gemBank: this.bankProgram.programId,
+            protocolConfig,
             feeAcc: feeAccount,
             systemProgram: SystemProgram.programId,
           })
@@ -603,8 +740,13 @@ export class GemFarmClient extends GemBankClient {
     };
   }
 
-  async stake(farm: PublicKey, farmerIdentity: PublicKey | Keypair) {
-    return this.stakeCommon(farm, farmerIdentity, false);
+  //lockOption indexes into the farm's lockOptions, null stakes w/ the farm's minStakingPeriodSec
+  async stake(
+    farm: PublicKey,
+    farmerIdentity: PublicKey | Keypair,
+    lockOption: number | null = null
+  ) {
+    return this.stakeCommon(farm, farmerIdentity, false, false, lockOption);
   }
 
   async unstake(
@@ -615,6 +757,39 @@ export class GemFarmClient extends GemBankClient {
     return this.stakeCommon(farm, farmerIdentity, true, skipRewards);
   }
 
+  //cancels a pending cooldown, only works between the first and second unstake
+  async restake(farm: PublicKey, farmerIdentity: PublicKey | Keypair) {
+    const identityPk = isKp(farmerIdentity)
+      ? (<Keypair>farmerIdentity).publicKey
+      : <PublicKey>farmerIdentity;
+
+    const farmAcc = await this.fetchFarmAcc(farm);
+
+    const [farmer, farmerBump] = await findFarmerPDA(farm, identityPk);
+    const [vault, vaultBump] = await findVaultPDA(farmAcc.bank, identityPk);
+    const [protocolConfig] = await findProtocolConfigPDA();
+
+    const signers = [];
+    if (isKp(farmerIdentity)) signers.push(<Keypair>farmerIdentity);
+
+    console.log('restaking for', identityPk.toBase58());
+    const txSig = await this.farmProgram.methods
+      .restake(farmerBump)
+      .accounts({
+        farm,
+        farmer,
+        identity: identityPk,
+        vault,
+        protocolConfig,
+        feeAcc: feeAccount,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(signers)
+      .rpc();
+
+    return { farmer, farmerBump, vault, vaultBump, txSig };
+  }
+
   async claim(
     farm: PublicKey,
     farmerIdentity: PublicKey | Keypair,
@@ -692,6 +867,7 @@ export class GemFarmClient extends GemBankClient {
     const [farmer, farmerBump] = await findFarmerPDA(farm, identityPk);
     const [vault, vaultBump] = await findVaultPDA(farmAcc.bank, identityPk);
     const [farmAuth, farmAuthBump] = await findFarmAuthorityPDA(farm);
+    const [protocolConfig] = await findProtocolConfigPDA();
 
     const [gemBox, gemBoxBump] = await findGemBoxPDA(vault, gemMint);
     const [GDR, GDRBump] = await findGdrPDA(vault, gemMint);
@@ -748,6 +924,7 @@ export class GemFarmClient extends GemBankClient {
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           gemBank: this.bankProgram.programId,
+          protocolConfig,
           feeAcc: feeAccount,
         },
         remainingAccounts,
@@ -805,6 +982,7 @@ export class GemFarmClient extends GemBankClient {
     const [farmer, farmerBump] = await findFarmerPDA(farm, identityPk);
     const [vault, vaultBump] = await findVaultPDA(farmAcc.bank, identityPk);
     const [farmAuth, farmAuthBump] = await findFarmAuthorityPDA(farm);
+    const [protocolConfig] = await findProtocolConfigPDA();
 
     const [gemBox, gemBoxBump] = await findGemBoxPDA(vault, gemMint);
     const [GDR, GDRBump] = await findGdrPDA(vault, gemMint);
@@ -879,6 +1057,7 @@ export class GemFarmClient extends GemBankClient {
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           gemBank: this.bankProgram.programId,
+          protocolConfig,
           feeAcc: feeAccount,
           gemMetadata: meta,
           gemEdition: nftEditionPda,
@@ -982,7 +1161,10 @@ export class GemFarmClient extends GemBankClient {
     farm: PublicKey,
     farmManager: PublicKey | Keypair,
     funder: PublicKey,
-    deauthorize = false
+    deauthorize = false,
+    maxAmount = new BN(0),
+    expiresTs = new BN(0),
+    allowedReward: any = FundableReward.Any //FundableReward instance
   ) {
     const [authorizationProof, authorizationProofBump] =
       await findAuthorizationProofPDA(farm, funder);
@@ -1009,7 +1191,7 @@ export class GemFarmClient extends GemBankClient {
     } else {
       console.log('authorizing funder', funder.toBase58());
       txSig = await this.farmProgram.methods
-        .authorizeFunder()
+        .authorizeFunder(maxAmount, expiresTs, allowedReward)
         .accounts({
           farm,
           farmManager: isKp(farmManager)
@@ -1029,9 +1211,20 @@ export class GemFarmClient extends GemBankClient {
   async authorizeFunder(
     farm: PublicKey,
     farmManager: PublicKey | Keypair,
-    funderToAuthorize: PublicKey
+    funderToAuthorize: PublicKey,
+    maxAmount = new BN(0), //0 = no cap
+    expiresTs = new BN(0), //0 = never expires
+    allowedReward: any = FundableReward.Any //FundableReward instance
   ) {
-    return this.authorizeCommon(farm, farmManager, funderToAuthorize, false);
+    return this.authorizeCommon(
+      farm,
+      farmManager,
+      funderToAuthorize,
+      false,
+      maxAmount,
+      expiresTs,
+      allowedReward
+    );
   }
 
   async deauthorizeFunder(
@@ -1060,6 +1253,11 @@ export class GemFarmClient extends GemBankClient {
     const [authorizationProof, authorizationProofBump] =
       await findAuthorizationProofPDA(farm, funderPk);
     const [pot, potBump] = await findRewardsPotPDA(farm, rewardMint);
+    const [funderContribution] = await findFunderContributionPDA(
+      farm,
+      rewardMint,
+      funderPk
+    );
 
     const signers = [];
     if (isKp(funder)) signers.push(<Keypair>funder);
@@ -1076,6 +1274,7 @@ export class GemFarmClient extends GemBankClient {
         farm,
         authorizationProof,
         authorizedFunder: funderPk,
+        funderContribution,
         rewardPot: pot,
         rewardSource,
         rewardMint,
@@ -1090,6 +1289,7 @@ export class GemFarmClient extends GemBankClient {
       farmAuthBump,
       authorizationProof,
       authorizationProofBump,
+      funderContribution,
       pot,
       potBump,
       txSig,
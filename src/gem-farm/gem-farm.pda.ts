@@ -35,3 +35,26 @@ export const findRewardsPotPDA = (farm: PublicKey, rewardMint: PublicKey) => {
     GEM_FARM_PROG_ID
   );
 };
+
+export const findProtocolConfigPDA = () => {
+  return PublicKey.findProgramAddress(
+    [Buffer.from('protocol_config')],
+    GEM_FARM_PROG_ID
+  );
+};
+
+export const findFunderContributionPDA = (
+  farm: PublicKey,
+  rewardMint: PublicKey,
+  funder: PublicKey
+) => {
+  return PublicKey.findProgramAddress(
+    [
+      Buffer.from('contribution'),
+      farm.toBytes(),
+      rewardMint.toBytes(),
+      funder.toBytes(),
+    ],
+    GEM_FARM_PROG_ID
+  );
+};
@@ -2,6 +2,89 @@ export type GemFarm = {
   "version": "0.1.0",
   "name": "gem_farm",
   "instructions": [
+    {
+      "name": "initProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gemFarm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "publicKey"
+        },
+        {
+          "name": "feeWallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "fees",
+          "type": {
+            "defined": "ProtocolFees"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "feeWallet",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "fees",
+          "type": {
+            "option": {
+              "defined": "ProtocolFees"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "initFarm",
       "accounts": [
@@ -55,6 +138,11 @@ export type GemFarm = {
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "feeAcc",
           "isMut": true,
@@ -153,11 +241,33 @@ export type GemFarm = {
               "defined": "MaxCounts"
             }
           }
+        },
+        {
+          "name": "restakeKeepsTenure",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "loyaltyBoost",
+          "type": {
+            "option": {
+              "defined": "LoyaltyBoost"
+            }
+          }
+        },
+        {
+          "name": "lockOptions",
+          "type": {
+            "option": {
+              "defined": "LockOptions"
+            }
+          }
         }
       ]
     },
     {
-      "name": "payoutFromTreasury",
+      "name": "setConfigTimelock",
       "accounts": [
         {
           "name": "farm",
@@ -168,19 +278,30 @@ export type GemFarm = {
           "name": "farmManager",
           "isMut": false,
           "isSigner": true
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "farmAuthority",
+          "name": "configTimelockSec",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "queueConfigChange",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmTreasury",
+          "name": "farmManager",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "destination",
+          "name": "pendingConfig",
           "isMut": true,
           "isSigner": false
         },
@@ -192,80 +313,94 @@ export type GemFarm = {
       ],
       "args": [
         {
-          "name": "bumpAuth",
-          "type": "u8"
+          "name": "config",
+          "type": {
+            "option": {
+              "defined": "FarmConfig"
+            }
+          }
         },
         {
-          "name": "bumpTreasury",
-          "type": "u8"
+          "name": "maxCounts",
+          "type": {
+            "option": {
+              "defined": "MaxCounts"
+            }
+          }
         },
         {
-          "name": "lamports",
+          "name": "configTimelockSec",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "effectiveTs",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "addToBankWhitelist",
+      "name": "applyPendingConfig",
+      "docs": [
+        "permissionless - once the queued change's effective_ts has passed"
+      ],
       "accounts": [
         {
           "name": "farm",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "farmManager",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bank",
+          "name": "pendingConfig",
           "isMut": true,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "addressToWhitelist",
+          "name": "bumpPending",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelPendingConfig",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
+          "name": "farmManager",
           "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "gemBank",
-          "isMut": false,
+          "name": "pendingConfig",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "whitelistType",
+          "name": "bumpPending",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "removeFromBankWhitelist",
+      "name": "initManagerSet",
       "accounts": [
         {
           "name": "farm",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -274,95 +409,131 @@ export type GemFarm = {
           "isSigner": true
         },
         {
-          "name": "farmAuthority",
+          "name": "managerSet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bank",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "addressToRemove",
+          "name": "managers",
+          "type": {
+            "defined": "Managers"
+          }
+        }
+      ]
+    },
+    {
+      "name": "propose",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "whitelistProof",
+          "name": "managerSet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bumpManagerSet",
           "type": "u8"
         },
         {
-          "name": "bumpWl",
-          "type": "u8"
+          "name": "action",
+          "type": {
+            "defined": "ProposalAction"
+          }
         }
       ]
     },
     {
-      "name": "initFarmer",
+      "name": "approveProposal",
       "accounts": [
         {
           "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "identity",
+          "name": "managerSet",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "bank",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "proposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "manager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpManagerSet",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateManagerSet",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "payer",
-          "isMut": true,
+          "name": "farmManager",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "feeAcc",
+          "name": "managerSet",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "proposal",
+          "isMut": true,
           "isSigner": false
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "bumpManagerSet",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "stake",
+      "name": "payoutFromTreasury",
       "accounts": [
         {
           "name": "farm",
@@ -370,37 +541,22 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "farmManager",
           "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "bank",
+          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "farmTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc",
+          "name": "destination",
           "isMut": true,
           "isSigner": false
         },
@@ -416,144 +572,118 @@ export type GemFarm = {
           "type": "u8"
         },
         {
-          "name": "bumpFarmer",
+          "name": "bumpTreasury",
           "type": "u8"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "unstake",
+      "name": "payoutTokensFromTreasury",
       "accounts": [
         {
           "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
+          "name": "farmManager",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "bank",
+          "name": "farmTreasury",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "vault",
+          "name": "treasuryTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "mint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "feeAcc",
+          "name": "destination",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
         {
           "name": "bumpTreasury",
           "type": "u8"
         },
         {
-          "name": "bumpFarmer",
-          "type": "u8"
-        },
-        {
-          "name": "skipRewards",
-          "type": "bool"
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "claim",
+      "name": "setTreasurySplit",
       "accounts": [
         {
           "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
+          "name": "farmManager",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "rewardAPot",
+          "name": "treasurySplit",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardAMint",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rewardADestination",
-          "isMut": true,
-          "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "rewardBPot",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "TreasuryRecipient"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "distributeTreasury",
+      "docs": [
+        "permissionless - pays the treasury out according to the farm's treasury split"
+      ],
+      "accounts": [
         {
-          "name": "rewardBMint",
+          "name": "farm",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardBDestination",
+          "name": "farmTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
+          "name": "treasurySplit",
           "isMut": false,
           "isSigner": false
         },
@@ -561,34 +691,40 @@ export type GemFarm = {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bumpTreasury",
           "type": "u8"
-        },
+        }
+      ]
+    },
+    {
+      "name": "setUnstakePenalty",
+      "accounts": [
         {
-          "name": "bumpFarmer",
-          "type": "u8"
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "bumpPotA",
-          "type": "u8"
-        },
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
         {
-          "name": "bumpPotB",
-          "type": "u8"
+          "name": "penalty",
+          "type": {
+            "defined": "EarlyUnstakePenalty"
+          }
         }
       ]
     },
     {
-      "name": "flashDeposit",
+      "name": "setPublicTopUps",
       "accounts": [
         {
           "name": "farm",
@@ -596,62 +732,81 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "farmManager",
           "isMut": false,
-          "isSigner": false
-        },
+          "isSigner": true
+        }
+      ],
+      "args": [
         {
-          "name": "farmer",
+          "name": "allowPublicTopUps",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRewardCap",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "identity",
-          "isMut": true,
+          "name": "farmManager",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "bank",
+          "name": "rewardMint",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "cap",
+          "type": {
+            "defined": "RewardCap"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTokenFees",
+      "accounts": [
         {
-          "name": "vaultAuthority",
-          "isMut": false,
+          "name": "farm",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemBox",
+          "name": "farmManager",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "gemDepositReceipt",
-          "isMut": true,
+          "name": "farmTreasury",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemSource",
+          "name": "treasuryTokenAccount",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemMint",
+          "name": "feeMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemRarity",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "associatedTokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -664,120 +819,143 @@ export type GemFarm = {
           "name": "rent",
           "isMut": false,
           "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpFarmer",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVaultAuth",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
-          "name": "bumpRarity",
-          "type": "u8"
+          "name": "stakeFee",
+          "type": "u64"
         },
         {
-          "name": "amount",
+          "name": "unstakeFee",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "refreshFarmer",
+      "name": "addToBankWhitelist",
       "accounts": [
         {
           "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
           "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "farmer",
+          "name": "bank",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "identity",
+          "name": "addressToWhitelist",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "whitelistType",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "refreshFarmerSigned",
-      "docs": [
-        "this one needs to be called by the farmer themselves",
-        "it's useful if for some reason they can't re-enroll in another fixed reward cycle (eg reward exhausted)",
-        "but they want to be able to refresh themselves and claim their earned rewards up to this point"
-      ],
+      "name": "removeFromBankWhitelist",
       "accounts": [
         {
           "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmer",
+          "name": "bank",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "identity",
+          "name": "addressToRemove",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bump",
+          "name": "bumpAuth",
           "type": "u8"
         },
         {
-          "name": "reenroll",
-          "type": "bool"
+          "name": "bumpWl",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "authorizeFunder",
+      "name": "migrateAccount",
       "accounts": [
         {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
+          "name": "account",
           "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "funderToAuthorize",
-          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationProof",
+          "name": "payer",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "covers the extra rent for the bigger layout"
+          ]
         },
         {
           "name": "systemProgram",
@@ -788,7 +966,7 @@ export type GemFarm = {
       "args": []
     },
     {
-      "name": "deauthorizeFunder",
+      "name": "initFarmer",
       "accounts": [
         {
           "name": "farm",
@@ -796,17 +974,42 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "funderToDeauthorize",
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationProof",
+          "name": "feeAcc",
           "isMut": true,
           "isSigner": false
         },
@@ -816,15 +1019,13 @@ export type GemFarm = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "fundReward",
+      "name": "enterFarm",
+      "docs": [
+        "init farmer + vault, deposit a gem and stake it, all in one go"
+      ],
       "accounts": [
         {
           "name": "farm",
@@ -832,103 +1033,72 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "authorizationProof",
+          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizedFunder",
+          "name": "farmer",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "rewardPot",
+          "name": "identity",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "rewardSource",
+          "name": "bank",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "vaultAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "gemBox",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpProof",
-          "type": "u8"
-        },
-        {
-          "name": "bumpPot",
-          "type": "u8"
-        },
-        {
-          "name": "variableRateConfig",
-          "type": {
-            "option": {
-              "defined": "VariableRateConfig"
-            }
-          }
         },
         {
-          "name": "fixedRateConfig",
-          "type": {
-            "option": {
-              "defined": "FixedRateConfig"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "cancelReward",
-      "accounts": [
-        {
-          "name": "farm",
+          "name": "gemDepositReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "gemSource",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "farmAuthority",
+          "name": "gemMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardPot",
-          "isMut": true,
+          "name": "gemRarity",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardDestination",
-          "isMut": true,
+          "name": "gemBank",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "rewardMint",
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "feeAcc",
           "isMut": true,
           "isSigner": false
         },
@@ -937,11 +1107,6 @@ export type GemFarm = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -955,17 +1120,27 @@ export type GemFarm = {
       ],
       "args": [
         {
-          "name": "bumpAuth",
+          "name": "bumpVaultAuth",
           "type": "u8"
         },
         {
-          "name": "bumpPot",
+          "name": "bumpRarity",
           "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "lockOption",
+          "type": {
+            "option": "u8"
+          }
         }
       ]
     },
     {
-      "name": "lockReward",
+      "name": "stake",
       "accounts": [
         {
           "name": "farm",
@@ -973,39 +1148,28 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rewardMint",
+          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "addRaritiesToBank",
-      "accounts": [
+        },
         {
-          "name": "farm",
-          "isMut": false,
+          "name": "farmer",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "farmManager",
+          "name": "identity",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "farmAuthority",
+          "name": "bank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bank",
-          "isMut": false,
+          "name": "vault",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1013,6 +1177,16 @@ export type GemFarm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1025,17 +1199,19 @@ export type GemFarm = {
           "type": "u8"
         },
         {
-          "name": "rarityConfigs",
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "lockOption",
           "type": {
-            "vec": {
-              "defined": "RarityConfig"
-            }
+            "option": "u8"
           }
         }
       ]
     },
     {
-      "name": "flashDepositPnft",
+      "name": "unstake",
       "accounts": [
         {
           "name": "farm",
@@ -1047,6 +1223,11 @@ export type GemFarm = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "farmer",
           "isMut": true,
@@ -1068,97 +1249,242 @@ export type GemFarm = {
           "isSigner": false
         },
         {
-          "name": "vaultAuthority",
+          "name": "gemBank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemBox",
-          "isMut": true,
+          "name": "protocolConfig",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemDepositReceipt",
+          "name": "feeAcc",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemSource",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
-        {
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "skipRewards",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "partialUnstake",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
           "name": "gemMint",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "gemRarity",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "coolingGem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
         },
         {
-          "name": "rent",
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawUnstakedGem",
+      "accounts": [
+        {
+          "name": "farm",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemBank",
+          "name": "farmAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "feeAcc",
+          "name": "farmer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "associatedTokenProgram",
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "gemMetadata",
+          "name": "vault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "gemEdition",
+          "name": "vaultAuthority",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "ownerTokenRecord",
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "destTokenRecord",
+          "name": "gemDepositReceipt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenMetadataProgram",
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "instructions",
+          "name": "gemRarity",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "authorizationRulesProgram",
+          "name": "coolingGem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
           "isMut": false,
           "isSigner": false
         }
@@ -1172,122 +1498,8587 @@ export type GemFarm = {
           "name": "bumpVaultAuth",
           "type": "u8"
         },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
         {
           "name": "bumpRarity",
           "type": "u8"
         },
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "bumpCoolingGem",
+          "type": "u8"
         },
         {
-          "name": "rulesAccPresent",
-          "type": "bool"
+          "name": "amount",
+          "type": "u64"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
     {
-      "name": "authorizationProof",
+      "name": "restake",
       "docs": [
-        "if this PDA exists, this means the funder recorded below has been authorized by the",
-        "farm recorded below to fund rewards"
+        "cancels a pending cooldown and puts the farmer back to staked"
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authorizedFunder",
-            "type": "publicKey"
-          },
-          {
-            "name": "farm",
-            "type": "publicKey"
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        ]
-      }
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "farmer",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "farm",
+      "name": "claim",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardAPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardADestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotA",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotB",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "flashDeposit",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "flashWithdraw",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coolingGem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapGem",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newGemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newGemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newGemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newGemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newGemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldRarity",
+          "type": "u8"
+        },
+        {
+          "name": "bumpNewRarity",
+          "type": "u8"
+        },
+        {
+          "name": "oldAmount",
+          "type": "u64"
+        },
+        {
+          "name": "newAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "refreshFarmer",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "refreshFarmerSigned",
+      "docs": [
+        "this one needs to be called by the farmer themselves",
+        "it's useful if for some reason they can't re-enroll in another fixed reward cycle (eg reward exhausted)",
+        "but they want to be able to refresh themselves and claim their earned rewards up to this point"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "reenroll",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "authorizeFunder",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funderToAuthorize",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxAmount",
+          "type": "u64"
+        },
+        {
+          "name": "expiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "allowedReward",
+          "type": {
+            "defined": "FundableReward"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deauthorizeFunder",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funderToDeauthorize",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "fundReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizedFunder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funderContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpProof",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "variableRateConfig",
+          "type": {
+            "option": {
+              "defined": "VariableRateConfig"
+            }
+          }
+        },
+        {
+          "name": "fixedRateConfig",
+          "type": {
+            "option": {
+              "defined": "FixedRateConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "topUpReward",
+      "docs": [
+        "permissionless (if the farm allows it) - adds to a running variable reward, same end time"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "donor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "syncRewardPot",
+      "docs": [
+        "permissionless - folds tokens sent straight to the pot into the reward"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimRefund",
+      "docs": [
+        "permissionless - pays a funder their share of the last cancelled funding round"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpContribution",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "collectPenalties",
+      "docs": [
+        "permissionless - moves early unstake penalties owed to the treasury out of the reward pot"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "lockReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "replaceReward",
+      "docs": [
+        "retires an ended, unlocked & drained reward and replaces it with a new mint / type"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldRewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldRewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldRewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newRewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newRewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldPot",
+          "type": "u8"
+        },
+        {
+          "name": "newRewardType",
+          "type": {
+            "defined": "RewardType"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rewardType",
+          "type": {
+            "defined": "RewardType"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizedFunder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpProof",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "variableRateConfig",
+          "type": {
+            "option": {
+              "defined": "VariableRateConfig"
+            }
+          }
+        },
+        {
+          "name": "fixedRateConfig",
+          "type": {
+            "option": {
+              "defined": "FixedRateConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "lockRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFarmerRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmerRewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refreshRewardSlot",
+      "accounts": [
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmerRewardSlot",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmerSlot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmerRewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmerSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addRaritiesToBank",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rarityConfigs",
+          "type": {
+            "vec": {
+              "defined": "RarityConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "flashDepositPnft",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rulesAccPresent",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "flashWithdrawPnft",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coolingGem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rulesAccPresent",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "enterFarmPnft",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rulesAccPresent",
+          "type": "bool"
+        },
+        {
+          "name": "lockOption",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authorizationProof",
+      "docs": [
+        "if this PDA exists, this means the funder recorded below has been authorized by the",
+        "farm recorded below to fund rewards"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorizedFunder",
+            "type": "publicKey"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "maxAmount",
+            "docs": [
+              "max cumulative amount the funder can ever fund (across all mints), 0 = no cap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresTs",
+            "docs": [
+              "funding isn't possible from this ts onwards, 0 = never expires"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalFunded",
+            "docs": [
+              "all funding so far, across all rewards. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allowedReward",
+            "type": {
+              "defined": "FundableReward"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "coolingGem",
+      "docs": [
+        "a gem moved into cooldown by partial_unstake, one per (farmer, gem mint)",
+        "withdraw_unstaked_gem only lets out gems that have one of these, once their own cooldown is over"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "gemMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "beginStakingTs",
+            "docs": [
+              "Farmer.begin_staking_ts at the time of the partial unstake",
+              "if it no longer matches, the farmer has fully unstaked since and the record is void"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u64"
+          },
+          {
+            "name": "cooldownEndsTs",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farmer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "identity",
+            "docs": [
+              "the identity of the farmer = their public key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "docs": [
+              "vault storing all of the farmer's gems"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "FarmerState"
+            }
+          },
+          {
+            "name": "gemsStaked",
+            "docs": [
+              "total number of gems at the time when the vault is locked"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "docs": [
+              "total number of gems * rarity of each gem (1 if un-appraised)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minStakingEndsTs",
+            "docs": [
+              "this will be updated when they decide to unstake taking into acc. config set at farm level"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "cooldownEndsTs",
+            "docs": [
+              "this will be updated when they decide to unstake taking into acc. config set at farm level"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardA",
+            "type": {
+              "defined": "FarmerReward"
+            }
+          },
+          {
+            "name": "rewardB",
+            "type": {
+              "defined": "FarmerReward"
+            }
+          },
+          {
+            "name": "gemsCoolingDown",
+            "docs": [
+              "gems that have been unstaked while the rest of the vault keeps earning",
+              "they sit in the (still locked) vault until their own cooldown is over (see CoolingGem)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rarityPointsCoolingDown",
+            "type": "u64"
+          },
+          {
+            "name": "partialCooldownEndsTs",
+            "docs": [
+              "when the most recent partial cooldown ends"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "beginStakingTs",
+            "docs": [
+              "when the farmer last went from unstaked to staked, kept across restakes if the farm allows it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "boostedPointsStaked",
+            "docs": [
+              "rarity points x loyalty boost, as of the farmer's last refresh (0 unless staked & the boost is on)",
+              "what the farmer's share of variable rewards is based on, see Farm.loyalty_boost"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockPeriodSec",
+            "docs": [
+              "the lock the farmer picked when they started staking (0 = none, the farm's min_staking_period_sec applies)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lockMultiplierBps",
+            "docs": [
+              "comes with the lock (0 = none, ie 1x), applies to all reward types until the farmer fully unstakes"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          },
+          {
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved3",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farm",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farmManager",
+            "docs": [
+              "authorizes funders, whitelists mints/creators, sets farm config params",
+              "can update itself to another Pubkey"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "farmTreasury",
+            "docs": [
+              "used for collecting any fees earned by the farm"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "farmAuthority",
+            "docs": [
+              "signs off on treasury payouts and on any operations related to the bank",
+              "(configured as bank manager)"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "farmAuthoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "bank",
+            "docs": [
+              "each farm controls a single bank. each farmer gets a vault in that bank"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "FarmConfig"
+            }
+          },
+          {
+            "name": "farmerCount",
+            "docs": [
+              "total count, including initialized but inactive farmers"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakedFarmerCount",
+            "docs": [
+              "currently staked farmer count"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gemsStaked",
+            "docs": [
+              "currently staked gem count"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "docs": [
+              "currently staked gem count, where each gem is multiplied by its rarity score (1 if absent)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "authorizedFunderCount",
+            "docs": [
+              "how many accounts can create funding schedules"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardA",
+            "type": {
+              "defined": "FarmReward"
+            }
+          },
+          {
+            "name": "rewardB",
+            "type": {
+              "defined": "FarmReward"
+            }
+          },
+          {
+            "name": "maxCounts",
+            "type": {
+              "defined": "MaxCounts"
+            }
+          },
+          {
+            "name": "rewardSlotCount",
+            "docs": [
+              "extra reward streams living in their own PDAs (see FarmRewardSlot)"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "restakeKeepsTenure",
+            "docs": [
+              "if set, farmers who restake out of cooldown keep their original begin_staking_ts"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowPublicTopUps",
+            "docs": [
+              "if set, anyone can add tokens to a running variable reward (see top_up_reward)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "requiresApprovals",
+            "docs": [
+              "if set, sensitive ixs need an approved Proposal from the farm's ManagerSet"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                12
+              ]
+            }
+          },
+          {
+            "name": "reserved3",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "boostedPointsStaked",
+            "docs": [
+              "sum of all staked farmers' boosted points (see Farmer), only kept while the boost is on",
+              "variable rewards are split across this instead of rarity_points_staked"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "loyaltyBoost",
+            "type": {
+              "defined": "LoyaltyBoost"
+            }
+          },
+          {
+            "name": "lockOptions",
+            "docs": [
+              "locks farmers can pick from when they stake, see LockOptions"
+            ],
+            "type": {
+              "defined": "LockOptions"
+            }
+          },
+          {
+            "name": "earlyUnstakePenalty",
+            "type": {
+              "defined": "EarlyUnstakePenalty"
+            }
+          },
+          {
+            "name": "tokenFees",
+            "type": {
+              "defined": "TokenFees"
+            }
+          },
+          {
+            "name": "configTimelockSec",
+            "docs": [
+              "min delay between queueing a config / max counts change and it taking effect",
+              "0 = update_farm changes them instantly, as before (see PendingFarmConfig)",
+              "while > 0, token fees, the early unstake penalty, lock options, the loyalty boost",
+              "and restake_keeps_tenure can't be changed at all"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved4",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                24
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "funderContribution",
+      "docs": [
+        "what a single funder put into a single reward (by mint), since the last cancellation",
+        "lets cancel_reward refund funders pro rata instead of sending everything to one receiver"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "funder",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "fundingRound",
+            "docs": [
+              "mirrors RefundTracker.funding_round at the time of the last contribution"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "contributed",
+            "docs": [
+              "contributed during funding_round, zeroed once refunded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "managerSet",
+      "docs": [
+        "an M-of-N set of managers, one per farm - once a farm has one, anything that moves funds or",
+        "control (cancelling or replacing rewards, treasury payouts and splits, handing over",
+        "farm_manager) needs an approved Proposal on top of farm_manager's signature"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "managers",
+            "type": {
+              "defined": "Managers"
+            }
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "bumped every time the managers change, approvals collected under an older set lapse"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proposalCount",
+            "docs": [
+              "used to derive the next proposal's address"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "docs": [
+        "one action, approved by members of the farm's ManagerSet and executed by the ix it's for"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "ManagerSet.proposal_count at the time, part of the PDA seeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "ManagerSet.generation at the time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "ProposalAction"
+            }
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "bit i = ManagerSet.managers.members[i] approved"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "pendingFarmConfig",
+      "docs": [
+        "a FarmConfig / MaxCounts change queued by the manager (see Farm.config_timelock_sec)",
+        "lives in its own PDA so stakers can see it coming, applied by the permissionless apply_pending_config"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveTs",
+            "docs": [
+              "can't be applied before this"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "None = left unchanged"
+            ],
+            "type": {
+              "option": {
+                "defined": "FarmConfig"
+              }
+            }
+          },
+          {
+            "name": "maxCounts",
+            "type": {
+              "option": {
+                "defined": "MaxCounts"
+              }
+            }
+          },
+          {
+            "name": "configTimelockSec",
+            "docs": [
+              "lowering the timelock has to go through the timelock too"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolConfig",
+      "docs": [
+        "singleton PDA, every fee-charging ix reads the fee wallet and amounts from here"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "the only key that can update this config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeWallet",
+            "docs": [
+              "where protocol fees go"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "fees",
+            "type": {
+              "defined": "ProtocolFees"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farmRewardSlot",
+      "docs": [
+        "an extra reward stream on top of the farm's built-in reward_a / reward_b",
+        "lives in its own PDA (one per reward mint), so a farm can run as many of these as it wants"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "docs": [
+              "sum of all farmer snapshots (see FarmerRewardSlot), the variable reward is split across these",
+              "can lag behind farm.rarity_points_staked until every farmer has been refreshed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": {
+              "defined": "FarmReward"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farmerRewardSlot",
+      "docs": [
+        "farmer-side counterpart of FarmRewardSlot, one per (slot, farmer)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardSlot",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "docs": [
+              "farmer's (lock-weighted) rarity points as of the last refresh of this slot"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": {
+              "defined": "FarmerReward"
+            }
+          },
+          {
+            "name": "lastRefreshedTs",
+            "docs": [
+              "when this slot was last refreshed, 0 if never"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "reserved3",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "treasurySplit",
+      "docs": [
+        "a fixed split of the farm treasury's lamports, paid out by the permissionless distribute_treasury",
+        "lives in its own PDA (one per farm) - only farms that want a split need one"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipients",
+            "docs": [
+              "only the first recipient_count entries are used, shares add up to 100%"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "TreasuryRecipient"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "recipientCount",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RarityConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Number128",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "n",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Number192",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "n",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paidOutReward",
+            "docs": [
+              "total, not per rarity point. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "accruedReward",
+            "docs": [
+              "total, not per rarity point. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "variableRate",
+            "docs": [
+              "only one of these two (fixed and variable) will actually be used, per reward"
+            ],
+            "type": {
+              "defined": "FarmerVariableRateReward"
+            }
+          },
+          {
+            "name": "fixedRate",
+            "type": {
+              "defined": "FarmerFixedRateReward"
+            }
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "mirrors FarmReward.generation, if they differ the farm reward has been replaced"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "forfeitedReward",
+            "docs": [
+              "total lost to early unstake penalties, never claimable. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capPeriodStartTs",
+            "docs": [
+              "the current period under FarmReward.farmer_cap, and what's been accrued during it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capPeriodAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "capSettledTs",
+            "docs": [
+              "when accrual was last settled against the cap, so it can be spread over the periods since"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerVariableRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastRecordedAccruedRewardPerRarityPoint",
+            "docs": [
+              "used to keep track of how much of the variable reward has been updated for this farmer",
+              "(read more in variable rate config)"
+            ],
+            "type": {
+              "defined": "Number192"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerFixedRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beginStakingTs",
+            "docs": [
+              "this is the time the farmer staked",
+              "can be WAY BACK in the past, if we've rolled them multiple times"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "beginScheduleTs",
+            "docs": [
+              "this is the time the latest reward schedule they subscribed to begins",
+              "(this + promised duration = end_schedule_ts)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdatedTs",
+            "docs": [
+              "always set to upper bound, not just now_ts (except funding)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "promisedSchedule",
+            "docs": [
+              "when a farmer stakes with the fixed schedule, at the time of staking,",
+              "we promise them a schedule for a certain duration (eg 1 token/rarity point/s for 100s)",
+              "that then \"reserves\" a certain amount of funds so that they can't be promised to other farmers",
+              "only if the farmer unstakes, will the reserve be void, and the funds become available again",
+              "for either funding other farmers or withdrawing (when the reward is cancelled)"
+            ],
+            "type": {
+              "defined": "FixedRateSchedule"
+            }
+          },
+          {
+            "name": "promisedDuration",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minStakingPeriodSec",
+            "type": "u64"
+          },
+          {
+            "name": "cooldownPeriodSec",
+            "type": "u64"
+          },
+          {
+            "name": "unstakingFeeLamp",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TokenFees",
+      "docs": [
+        "optional fees in the farm's own token, paid into the farm treasury's token account for fee_mint",
+        "(on top of the lamport ones - see FarmConfig.unstaking_fee_lamp)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeFee",
+            "docs": [
+              "charged whenever a farmer starts staking (stake, restake)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unstakeFee",
+            "docs": [
+              "charged wherever the lamport unstaking fee is"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MaxCounts",
+      "docs": [
+        "refers to staked counts"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxFarmers",
+            "type": "u32"
+          },
+          {
+            "name": "maxGems",
+            "type": "u32"
+          },
+          {
+            "name": "maxRarityPoints",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EarlyUnstakePenalty",
+      "docs": [
+        "farmers who unstake before min_tenure_sec forfeit penalty_bps of their outstanding reward_a / reward_b",
+        "(and can't claim until they reach it, else the penalty could be dodged by claiming first)",
+        "partial unstakes forfeit pro rata to the rarity points taken out"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minTenureSec",
+            "docs": [
+              "continuous staking time (see Farmer.begin_staking_ts) after which no penalty applies"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "penaltyBps",
+            "docs": [
+              "0 = no penalty"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "destination",
+            "type": {
+              "defined": "PenaltyDestination"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundsTracker",
+      "docs": [
+        "these numbers should only ever go up - ie they are cummulative"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalRefunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalAccruedToStakers",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RefundTracker",
+      "docs": [
+        "per-funder refunds (see FunderContribution)",
+        "funding is tracked in rounds - each cancellation closes the current one, and what it refunds is",
+        "split across that round's funders, pro rata to what they contributed (claimed via claim_refund)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fundingRound",
+            "docs": [
+              "bumped by every cancellation that has contributions to refund"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "roundContributions",
+            "docs": [
+              "contributed by all funders during the current round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundAmount",
+            "docs": [
+              "the next 4 fields describe the last closed round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundContributions",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedContributions",
+            "docs": [
+              "contributions / tokens not yet claimed back by their funders"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedRefund",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TimeTracker",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "durationSec",
+            "docs": [
+              "total duration for which the reward has been funded",
+              "updated with each new funding round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBeginTs",
+            "docs": [
+              "nothing accrues before this - can be in the future if funding was scheduled ahead of time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardEndTs",
+            "type": "u64"
+          },
+          {
+            "name": "lockEndTs",
+            "docs": [
+              "this will be set = to reward_end_ts if farm manager decides to lock up their reward",
+              "gives stakers the certainty it won't be withdrawn"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardMint",
+            "docs": [
+              "the next 3 fields (mint, pot type) are set at farm init",
+              "and can only be changed by replace_reward, once the reward is ended, unlocked and drained"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardPot",
+            "docs": [
+              "where the reward is stored"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardType",
+            "type": {
+              "defined": "RewardType"
+            }
+          },
+          {
+            "name": "fixedRate",
+            "docs": [
+              "only one of these two (fixed and variable) will actually be used, per reward"
+            ],
+            "type": {
+              "defined": "FixedRateReward"
+            }
+          },
+          {
+            "name": "variableRate",
+            "type": {
+              "defined": "VariableRateReward"
+            }
+          },
+          {
+            "name": "funds",
+            "type": {
+              "defined": "FundsTracker"
+            }
+          },
+          {
+            "name": "times",
+            "type": {
+              "defined": "TimeTracker"
+            }
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "bumped every time the reward is replaced, farmers on an older generation get voided"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalForfeitedToTreasury",
+            "docs": [
+              "early unstake penalties (see EarlyUnstakePenalty) sitting in the pot, owed to the treasury",
+              "both are cumulative, the difference is what collect_penalties can still move"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPaidToTreasury",
+            "type": "u64"
+          },
+          {
+            "name": "refunds",
+            "type": {
+              "defined": "RefundTracker"
+            }
+          },
+          {
+            "name": "totalPaidOut",
+            "docs": [
+              "claimed by farmers so far, only tracked since this was added (see sync_pot)",
+              "never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "farmerCap",
+            "docs": [
+              "optional caps on what a single farmer can accrue from this reward"
+            ],
+            "type": {
+              "defined": "RewardCap"
+            }
+          },
+          {
+            "name": "maxRoundingDust",
+            "docs": [
+              "upper bound on what variable accrual has rounded away - it's ceiled at the farm level and",
+              "floored at the farmer level, < 1 each per update. Never goes down (ie is cumulative)",
+              "lets replace_reward tell rounding dust apart from rewards farmers haven't claimed yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TierConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardRate",
+            "docs": [
+              "tokens/denominator/rarity point / sec"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "requiredTenure",
+            "docs": [
+              "min amount of time that needs to pass for the above rate to come into effect"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseRate",
+            "docs": [
+              "tokens/denominator / sec"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tiers",
+            "docs": [
+              "only the first tier_count entries are used, ordered by required_tenure (ascending)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "TierConfig"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "needed to slow down the payout schedule (else min would be 1 token/rarity point/s or 86k/rarity point/day",
+              "only used in fixed rate - in variable overall duration serves as sufficient speed regulator"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tierCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "type": {
+              "defined": "FixedRateSchedule"
+            }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "total amount that is being sent with the ix - will be added ON TOP of existing available funding"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "durationSec",
+            "docs": [
+              "duration the funding is being committed for",
+              "eg if commit funding for 100s and a farmer shows up 3s in, they will be promised 97s at above schedule",
+              "set this carefully!",
+              "every farmer enrolled will be \"reserved\" an amount to cover the schedule for this duration"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "docs": [
+              "when the above duration starts, if not now",
+              "farmers enrolling before then have their schedule (and reserve) start at this time"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "docs": [
+              "configured on funding"
+            ],
+            "type": {
+              "defined": "FixedRateSchedule"
+            }
+          },
+          {
+            "name": "reservedAmount",
+            "docs": [
+              "amount that has been promised to existing stakers and hence can't be withdrawn"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "VariableRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardRate",
+            "type": {
+              "defined": "Number128"
+            }
+          },
+          {
+            "name": "rewardLastUpdatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "accruedRewardPerRarityPoint",
+            "type": {
+              "defined": "Number128"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerVariableRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastRecordedAccruedRewardPerRarityPoint",
+            "type": {
+              "defined": "Number128"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TimeTrackerV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "durationSec",
+            "type": "u64"
+          },
+          {
+            "name": "rewardEndTs",
+            "type": "u64"
+          },
+          {
+            "name": "lockEndTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateScheduleV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseRate",
+            "type": "u64"
+          },
+          {
+            "name": "tier1",
+            "type": {
+              "option": {
+                "defined": "TierConfig"
+              }
+            }
+          },
+          {
+            "name": "tier2",
+            "type": {
+              "option": {
+                "defined": "TierConfig"
+              }
+            }
+          },
+          {
+            "name": "tier3",
+            "type": {
+              "option": {
+                "defined": "TierConfig"
+              }
+            }
+          },
+          {
+            "name": "denominator",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "type": {
+              "defined": "FixedRateScheduleV0"
+            }
+          },
+          {
+            "name": "reservedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerFixedRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beginStakingTs",
+            "type": "u64"
+          },
+          {
+            "name": "beginScheduleTs",
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "promisedSchedule",
+            "type": {
+              "defined": "FixedRateScheduleV0"
+            }
+          },
+          {
+            "name": "promisedDuration",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardPot",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardType",
+            "type": {
+              "defined": "RewardType"
+            }
+          },
+          {
+            "name": "fixedRate",
+            "type": {
+              "defined": "FixedRateRewardV0"
+            }
+          },
+          {
+            "name": "variableRate",
+            "type": {
+              "defined": "VariableRateRewardV0"
+            }
+          },
+          {
+            "name": "funds",
+            "type": {
+              "defined": "FundsTracker"
+            }
+          },
+          {
+            "name": "times",
+            "type": {
+              "defined": "TimeTrackerV0"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farmManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmAuthoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "farmAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "bank",
+            "type": "publicKey"
+          },
+          {
+            "name": "config",
+            "type": {
+              "defined": "FarmConfig"
+            }
+          },
+          {
+            "name": "farmerCount",
+            "type": "u64"
+          },
+          {
+            "name": "stakedFarmerCount",
+            "type": "u64"
+          },
+          {
+            "name": "gemsStaked",
+            "type": "u64"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "type": "u64"
+          },
+          {
+            "name": "authorizedFunderCount",
+            "type": "u64"
+          },
+          {
+            "name": "rewardA",
+            "type": {
+              "defined": "FarmRewardV0"
+            }
+          },
+          {
+            "name": "rewardB",
+            "type": {
+              "defined": "FarmRewardV0"
+            }
+          },
+          {
+            "name": "maxCounts",
+            "type": {
+              "defined": "MaxCounts"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "reserved3",
+            "type": {
+              "array": [
+                "u8",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paidOutReward",
+            "type": "u64"
+          },
+          {
+            "name": "accruedReward",
+            "type": "u64"
+          },
+          {
+            "name": "variableRate",
+            "type": {
+              "defined": "FarmerVariableRateRewardV0"
+            }
+          },
+          {
+            "name": "fixedRate",
+            "type": {
+              "defined": "FarmerFixedRateRewardV0"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "identity",
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "state",
+            "type": {
+              "defined": "FarmerState"
+            }
+          },
+          {
+            "name": "gemsStaked",
+            "type": "u64"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "type": "u64"
+          },
+          {
+            "name": "minStakingEndsTs",
+            "type": "u64"
+          },
+          {
+            "name": "cooldownEndsTs",
+            "type": "u64"
+          },
+          {
+            "name": "rewardA",
+            "type": {
+              "defined": "FarmerRewardV0"
+            }
+          },
+          {
+            "name": "rewardB",
+            "type": {
+              "defined": "FarmerRewardV0"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LoyaltyBoostTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "requiredTenure",
+            "docs": [
+              "min continuous staking time for the multiplier below to kick in"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "multiplierBps",
+            "docs": [
+              "eg 15000 = 1.5x the farmer's rarity points"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LoyaltyBoost",
+      "docs": [
+        "variable-rate rewards only (fixed-rate ones have their own tiers, see FixedRateSchedule)",
+        "farmers' points are multiplied by the highest tier they've reached,",
+        "the farm keeps the sum of those boosted points and splits the variable reward across it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tiers",
+            "docs": [
+              "only the first tier_count entries are used, ordered by required_tenure (ascending)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "LoyaltyBoostTier"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "tierCount",
+            "docs": [
+              "0 = no boost, variable rewards are split by raw rarity points"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Managers",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "only the first member_count entries are used"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "memberCount",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "approvals needed for a proposal to go through"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProtocolFees",
+      "docs": [
+        "what the protocol charges on top of any farm-level fees (see FarmConfig.unstaking_fee_lamp)",
+        "amounts are in lamports, 0 = free"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "initFarm",
+            "docs": [
+              "originally 2.5 SOL"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "initFarmer",
+            "docs": [
+              "originally 0.01 SOL"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "enterFarm",
+            "docs": [
+              "init_farmer + stake in one go, originally 0.012 SOL"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stake",
+            "docs": [
+              "stake, restake and flash deposits that start a new stake, originally 0.002 SOL"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unstake",
+            "docs": [
+              "charged on both unstake calls (staked -> cooldown -> unstaked), originally 0.001 SOL",
+              "partial_unstake and withdraw_unstaked_gem are the same two steps for a subset of gems, so pay the same",
+              "flash_withdraw pays it once"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "flashDeposit",
+            "docs": [
+              "flash deposits into an existing stake, originally 0.001 SOL"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RewardCap",
+      "docs": [
+        "optional per-farmer caps on a single reward stream (see FarmReward.farmer_cap)",
+        "accrual settled across several periods is assumed to have been earned evenly over them"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "periodSec",
+            "docs": [
+              "0 = no per-period cap"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxPerPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "maxLifetime",
+            "docs": [
+              "0 = no lifetime cap, compared against FarmerReward.accrued_reward"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "overflow",
+            "type": {
+              "defined": "CapOverflow"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "LockOption",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lockSec",
+            "docs": [
+              "replaces the farm's min_staking_period_sec for farmers who pick this lock"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "multiplierBps",
+            "docs": [
+              "eg 20000 = the farmer's rarity points count double, for all reward types"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LockOptions",
+      "docs": [
+        "the menu of locks farmers can pick from when they stake",
+        "farmers who don't pick one get the farm's min_staking_period_sec at 1x, as before"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "options",
+            "docs": [
+              "only the first option_count entries are used, farmers pick by index"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "LockOption"
+                },
+                4
+              ]
+            }
+          },
+          {
+            "name": "optionCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TreasuryRecipient",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "destination",
+            "type": "publicKey"
+          },
+          {
+            "name": "shareBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VariableRateConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "docs": [
+              "total amount of reward"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "durationSec",
+            "docs": [
+              "over which period it's active",
+              "only used by FundingMode::Reset, as are start_ts and the curve"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "docs": [
+              "when the period starts, if not now - nothing accrues before it"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "emissionCurve",
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "fundingMode",
+            "docs": [
+              "anything other than Reset needs a running (or scheduled) reward with a constant curve"
+            ],
+            "type": {
+              "defined": "FundingMode"
+            }
+          },
+          {
+            "name": "halvingPeriodSec",
+            "docs": [
+              "only used by EmissionCurve::Halving, has to be > 0 there"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VariableRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardRate",
+            "docs": [
+              "in tokens/s, = calculated as total reward pot at initialization / reward duration",
+              "for decaying curves, this is the rate at the beginning of the reward"
+            ],
+            "type": {
+              "defined": "Number192"
+            }
+          },
+          {
+            "name": "rewardLastUpdatedTs",
+            "docs": [
+              "set to upper bound, not just now_ts (except funding, when there is no upper bound)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "accruedRewardPerRarityPoint",
+            "docs": [
+              "this is somewhat redundant with total_accrued_to_stakers in funds, but necessary",
+              "think of it as a \"flag in the ground\" that gets moved forward as more rewards accrue to the pool",
+              "when a farmer tries to figure out how much they're due from the pool, we:",
+              "1) compare their latest record of flag position, with actual flag position",
+              "2) multiply the difference by the amount they have staked",
+              "3) update their record of flag position, so that next time we don't count this distance again"
+            ],
+            "type": {
+              "defined": "Number192"
+            }
+          },
+          {
+            "name": "halvingPeriodSec",
+            "docs": [
+              "see VariableRateConfig, set on each funding"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "emissionCurve",
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8 (together with emission_curve)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                23
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundableReward",
+      "docs": [
+        "which of the farm's rewards a funder may fund"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Any"
+          },
+          {
+            "name": "RewardA"
+          },
+          {
+            "name": "RewardB"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unstaked"
+          },
+          {
+            "name": "Staked"
+          },
+          {
+            "name": "PendingCooldown"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PenaltyDestination",
+      "docs": [
+        "where rewards forfeited by an early unstake go"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RewardPool"
+          },
+          {
+            "name": "Treasury"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RewardType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Variable"
+          },
+          {
+            "name": "Fixed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposalAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CancelReward",
+            "fields": [
+              {
+                "name": "reward_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CancelRewardSlot",
+            "fields": [
+              {
+                "name": "reward_slot",
+                "type": "publicKey"
+              },
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "PayoutFromTreasury",
+            "fields": [
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "PayoutTokensFromTreasury",
+            "fields": [
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetTreasurySplit",
+            "fields": [
+              {
+                "name": "recipients",
+                "type": {
+                  "array": [
+                    {
+                      "defined": "TreasuryRecipient"
+                    },
+                    8
+                  ]
+                }
+              },
+              {
+                "name": "recipient_count",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "ReplaceReward",
+            "fields": [
+              {
+                "name": "old_reward_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_reward_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetFarmManager",
+            "fields": [
+              {
+                "name": "manager",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetManagers",
+            "fields": [
+              {
+                "name": "managers",
+                "type": {
+                  "defined": "Managers"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "CapOverflow",
+      "docs": [
+        "where accrual above a farmer's cap goes"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Redistribute"
+          },
+          {
+            "name": "Refund"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionCurve",
+      "docs": [
+        "how the emission rate evolves over the funded duration",
+        "u8-sized (unlike RewardType) so it can be carved out of VariableRateReward's reserve"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Constant"
+          },
+          {
+            "name": "Halving"
+          },
+          {
+            "name": "LinearDecay"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundingMode",
+      "docs": [
+        "what a new funding round does to the running schedule"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Reset"
+          },
+          {
+            "name": "ExtendAtCurrentRate"
+          },
+          {
+            "name": "AddToRate"
+          }
+        ]
+      }
+    }
+  ]
+};
+
+export const IDL: GemFarm = {
+  "version": "0.1.0",
+  "name": "gem_farm",
+  "instructions": [
+    {
+      "name": "initProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "upgradeAuthority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gemFarm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "publicKey"
+        },
+        {
+          "name": "feeWallet",
+          "type": "publicKey"
+        },
+        {
+          "name": "fees",
+          "type": {
+            "defined": "ProtocolFees"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateProtocolConfig",
+      "accounts": [
+        {
+          "name": "protocolConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "feeWallet",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "fees",
+          "type": {
+            "option": {
+              "defined": "ProtocolFees"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initFarm",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "rewardTypeA",
+          "type": {
+            "defined": "RewardType"
+          }
+        },
+        {
+          "name": "rewardTypeB",
+          "type": {
+            "defined": "RewardType"
+          }
+        },
+        {
+          "name": "farmConfig",
+          "type": {
+            "defined": "FarmConfig"
+          }
+        },
+        {
+          "name": "maxCounts",
+          "type": {
+            "option": {
+              "defined": "MaxCounts"
+            }
+          }
+        },
+        {
+          "name": "farmTreasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateFarm",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "option": {
+              "defined": "FarmConfig"
+            }
+          }
+        },
+        {
+          "name": "manager",
+          "type": {
+            "option": "publicKey"
+          }
+        },
+        {
+          "name": "maxCounts",
+          "type": {
+            "option": {
+              "defined": "MaxCounts"
+            }
+          }
+        },
+        {
+          "name": "restakeKeepsTenure",
+          "type": {
+            "option": "bool"
+          }
+        },
+        {
+          "name": "loyaltyBoost",
+          "type": {
+            "option": {
+              "defined": "LoyaltyBoost"
+            }
+          }
+        },
+        {
+          "name": "lockOptions",
+          "type": {
+            "option": {
+              "defined": "LockOptions"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setConfigTimelock",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "configTimelockSec",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "queueConfigChange",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "config",
+          "type": {
+            "option": {
+              "defined": "FarmConfig"
+            }
+          }
+        },
+        {
+          "name": "maxCounts",
+          "type": {
+            "option": {
+              "defined": "MaxCounts"
+            }
+          }
+        },
+        {
+          "name": "configTimelockSec",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "effectiveTs",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "applyPendingConfig",
+      "docs": [
+        "permissionless - once the queued change's effective_ts has passed"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "pendingConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpPending",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelPendingConfig",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pendingConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpPending",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "initManagerSet",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "managerSet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "managers",
+          "type": {
+            "defined": "Managers"
+          }
+        }
+      ]
+    },
+    {
+      "name": "propose",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerSet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpManagerSet",
+          "type": "u8"
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "ProposalAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveProposal",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "managerSet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "manager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpManagerSet",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateManagerSet",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "managerSet",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpManagerSet",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTreasury",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "lamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "payoutTokensFromTreasury",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "destination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setTreasurySplit",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "treasurySplit",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "recipients",
+          "type": {
+            "vec": {
+              "defined": "TreasuryRecipient"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "distributeTreasury",
+      "docs": [
+        "permissionless - pays the treasury out according to the farm's treasury split"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasurySplit",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "setUnstakePenalty",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "penalty",
+          "type": {
+            "defined": "EarlyUnstakePenalty"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPublicTopUps",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "allowPublicTopUps",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setRewardCap",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "cap",
+          "type": {
+            "defined": "RewardCap"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTokenFees",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "feeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "stakeFee",
+          "type": "u64"
+        },
+        {
+          "name": "unstakeFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addToBankWhitelist",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "addressToWhitelist",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "whitelistType",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeFromBankWhitelist",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "addressToRemove",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "whitelistProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWl",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "migrateAccount",
+      "accounts": [
+        {
+          "name": "account",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "covers the extra rent for the bigger layout"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFarmer",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "enterFarm",
+      "docs": [
+        "init farmer + vault, deposit a gem and stake it, all in one go"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "lockOption",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "stake",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "lockOption",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unstake",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "skipRewards",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "partialUnstake",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coolingGem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdrawUnstakedGem",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coolingGem",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "bumpCoolingGem",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "restake",
+      "docs": [
+        "cancels a pending cooldown and puts the farmer back to staked"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "vault",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claim",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardAPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardADestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotA",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPotB",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "flashDeposit",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "flashWithdraw",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coolingGem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "swapGem",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldGemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newGemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newGemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newGemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newGemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newGemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldRarity",
+          "type": "u8"
+        },
+        {
+          "name": "bumpNewRarity",
+          "type": "u8"
+        },
+        {
+          "name": "oldAmount",
+          "type": "u64"
+        },
+        {
+          "name": "newAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "refreshFarmer",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "refreshFarmerSigned",
+      "docs": [
+        "this one needs to be called by the farmer themselves",
+        "it's useful if for some reason they can't re-enroll in another fixed reward cycle (eg reward exhausted)",
+        "but they want to be able to refresh themselves and claim their earned rewards up to this point"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": false,
+          "isSigner": true
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "reenroll",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "authorizeFunder",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funderToAuthorize",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxAmount",
+          "type": "u64"
+        },
+        {
+          "name": "expiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "allowedReward",
+          "type": {
+            "defined": "FundableReward"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deauthorizeFunder",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funderToDeauthorize",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "fundReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizedFunder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "funderContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpProof",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "variableRateConfig",
+          "type": {
+            "option": {
+              "defined": "VariableRateConfig"
+            }
+          }
+        },
+        {
+          "name": "fixedRateConfig",
+          "type": {
+            "option": {
+              "defined": "FixedRateConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "topUpReward",
+      "docs": [
+        "permissionless (if the farm allows it) - adds to a running variable reward, same end time"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "donor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "syncRewardPot",
+      "docs": [
+        "permissionless - folds tokens sent straight to the pot into the reward"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "cancelReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimRefund",
+      "docs": [
+        "permissionless - pays a funder their share of the last cancelled funding round"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "funderContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "refundDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpContribution",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "collectPenalties",
+      "docs": [
+        "permissionless - moves early unstake penalties owed to the treasury out of the reward pot"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "lockReward",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "replaceReward",
+      "docs": [
+        "retires an ended, unlocked & drained reward and replaces it with a new mint / type"
+      ],
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldRewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oldRewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "oldRewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newRewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newRewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpOldPot",
+          "type": "u8"
+        },
+        {
+          "name": "newRewardType",
+          "type": {
+            "defined": "RewardType"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rewardType",
+          "type": {
+            "defined": "RewardType"
+          }
+        }
+      ]
+    },
+    {
+      "name": "fundRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationProof",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorizedFunder",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpProof",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        },
+        {
+          "name": "variableRateConfig",
+          "type": {
+            "option": {
+              "defined": "VariableRateConfig"
+            }
+          }
+        },
+        {
+          "name": "fixedRateConfig",
+          "type": {
+            "option": {
+              "defined": "FixedRateConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "cancelRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "lockRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initFarmerRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmerRewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "refreshRewardSlot",
+      "accounts": [
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmerRewardSlot",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmerSlot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimRewardSlot",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "rewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmerRewardSlot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardPot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rewardDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmerSlot",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPot",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addRaritiesToBank",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpAuth",
+          "type": "u8"
+        },
+        {
+          "name": "rarityConfigs",
+          "type": {
+            "vec": {
+              "defined": "RarityConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "flashDepositPnft",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rulesAccPresent",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "flashWithdrawPnft",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDestination",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "coolingGem",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFarmer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGemBox",
+          "type": "u8"
+        },
+        {
+          "name": "bumpGdr",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rulesAccPresent",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "enterFarmPnft",
+      "accounts": [
+        {
+          "name": "farm",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "farmAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "farmer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "identity",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bank",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "vaultAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBox",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemDepositReceipt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemSource",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemRarity",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemBank",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "protocolConfig",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "feeAcc",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "associatedTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "gemMetadata",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "gemEdition",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "ownerTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "destTokenRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "instructions",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authorizationRulesProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpVaultAuth",
+          "type": "u8"
+        },
+        {
+          "name": "bumpRarity",
+          "type": "u8"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rulesAccPresent",
+          "type": "bool"
+        },
+        {
+          "name": "lockOption",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "authorizationProof",
+      "docs": [
+        "if this PDA exists, this means the funder recorded below has been authorized by the",
+        "farm recorded below to fund rewards"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authorizedFunder",
             "type": "publicKey"
           },
           {
-            "name": "identity",
-            "docs": [
-              "the identity of the farmer = their public key"
-            ],
+            "name": "farm",
             "type": "publicKey"
           },
           {
-            "name": "vault",
+            "name": "maxAmount",
             "docs": [
-              "vault storing all of the farmer's gems"
+              "max cumulative amount the funder can ever fund (across all mints), 0 = no cap"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "state",
-            "type": {
-              "defined": "FarmerState"
-            }
+            "name": "expiresTs",
+            "docs": [
+              "funding isn't possible from this ts onwards, 0 = never expires"
+            ],
+            "type": "u64"
           },
           {
-            "name": "gemsStaked",
+            "name": "totalFunded",
             "docs": [
-              "total number of gems at the time when the vault is locked"
+              "all funding so far, across all rewards. Never goes down (ie is cumulative)"
             ],
             "type": "u64"
           },
           {
-            "name": "rarityPointsStaked",
+            "name": "allowedReward",
+            "type": {
+              "defined": "FundableReward"
+            }
+          },
+          {
+            "name": "reserved",
             "docs": [
-              "total number of gems * rarity of each gem (1 if un-appraised)"
+              "reserved for future updates, has to be /8"
             ],
-            "type": "u64"
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "coolingGem",
+      "docs": [
+        "a gem moved into cooldown by partial_unstake, one per (farmer, gem mint)",
+        "withdraw_unstaked_gem only lets out gems that have one of these, once their own cooldown is over"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farmer",
+            "type": "publicKey"
           },
           {
-            "name": "minStakingEndsTs",
+            "name": "gemMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "beginStakingTs",
             "docs": [
-              "this will be updated when they decide to unstake taking into acc. config set at farm level"
+              "Farmer.begin_staking_ts at the time of the partial unstake",
+              "if it no longer matches, the farmer has fully unstaked since and the record is void"
             ],
             "type": "u64"
           },
           {
-            "name": "cooldownEndsTs",
-            "docs": [
-              "this will be updated when they decide to unstake taking into acc. config set at farm level"
-            ],
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "rewardA",
-            "type": {
-              "defined": "FarmerReward"
-            }
+            "name": "rarityPoints",
+            "type": "u64"
           },
           {
-            "name": "rewardB",
-            "type": {
-              "defined": "FarmerReward"
-            }
+            "name": "cooldownEndsTs",
+            "type": "u64"
           },
           {
             "name": "reserved",
@@ -1305,7 +10096,7 @@ export type GemFarm = {
       }
     },
     {
-      "name": "farm",
+      "name": "farmer",
       "type": {
         "kind": "struct",
         "fields": [
@@ -1314,292 +10105,115 @@ export type GemFarm = {
             "type": "u16"
           },
           {
-            "name": "farmManager",
-            "docs": [
-              "authorizes funders, whitelists mints/creators, sets farm config params",
-              "can update itself to another Pubkey"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "farmTreasury",
-            "docs": [
-              "used for collecting any fees earned by the farm"
-            ],
+            "name": "farm",
             "type": "publicKey"
           },
           {
-            "name": "farmAuthority",
+            "name": "identity",
             "docs": [
-              "signs off on treasury payouts and on any operations related to the bank",
-              "(configured as bank manager)"
+              "the identity of the farmer = their public key"
             ],
             "type": "publicKey"
           },
           {
-            "name": "farmAuthoritySeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "farmAuthorityBumpSeed",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "bank",
+            "name": "vault",
             "docs": [
-              "each farm controls a single bank. each farmer gets a vault in that bank"
+              "vault storing all of the farmer's gems"
             ],
             "type": "publicKey"
           },
           {
-            "name": "config",
+            "name": "state",
             "type": {
-              "defined": "FarmConfig"
+              "defined": "FarmerState"
             }
           },
           {
-            "name": "farmerCount",
-            "docs": [
-              "total count, including initialized but inactive farmers"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "stakedFarmerCount",
+            "name": "gemsStaked",
             "docs": [
-              "currently staked farmer count"
+              "total number of gems at the time when the vault is locked"
             ],
             "type": "u64"
           },
           {
-            "name": "gemsStaked",
+            "name": "rarityPointsStaked",
             "docs": [
-              "currently staked gem count"
+              "total number of gems * rarity of each gem (1 if un-appraised)"
             ],
             "type": "u64"
           },
           {
-            "name": "rarityPointsStaked",
+            "name": "minStakingEndsTs",
             "docs": [
-              "currently staked gem count, where each gem is multiplied by its rarity score (1 if absent)"
+              "this will be updated when they decide to unstake taking into acc. config set at farm level"
             ],
             "type": "u64"
           },
           {
-            "name": "authorizedFunderCount",
+            "name": "cooldownEndsTs",
             "docs": [
-              "how many accounts can create funding schedules"
+              "this will be updated when they decide to unstake taking into acc. config set at farm level"
             ],
             "type": "u64"
           },
           {
             "name": "rewardA",
             "type": {
-              "defined": "FarmReward"
+              "defined": "FarmerReward"
             }
           },
           {
             "name": "rewardB",
             "type": {
-              "defined": "FarmReward"
-            }
-          },
-          {
-            "name": "maxCounts",
-            "type": {
-              "defined": "MaxCounts"
-            }
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "reserved2",
-            "type": {
-              "array": [
-                "u8",
-                16
-              ]
-            }
-          },
-          {
-            "name": "reserved3",
-            "type": {
-              "array": [
-                "u8",
-                4
-              ]
+              "defined": "FarmerReward"
             }
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "RarityConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
           },
           {
-            "name": "rarityPoints",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Number128",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "n",
-            "type": "u128"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FarmerReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "paidOutReward",
+            "name": "gemsCoolingDown",
             "docs": [
-              "total, not per rarity point. Never goes down (ie is cumulative)"
+              "gems that have been unstaked while the rest of the vault keeps earning",
+              "they sit in the (still locked) vault until their own cooldown is over (see CoolingGem)"
             ],
             "type": "u64"
           },
           {
-            "name": "accruedReward",
-            "docs": [
-              "total, not per rarity point. Never goes down (ie is cumulative)"
-            ],
+            "name": "rarityPointsCoolingDown",
             "type": "u64"
           },
           {
-            "name": "variableRate",
-            "docs": [
-              "only one of these two (fixed and variable) will actually be used, per reward"
-            ],
-            "type": {
-              "defined": "FarmerVariableRateReward"
-            }
-          },
-          {
-            "name": "fixedRate",
-            "type": {
-              "defined": "FarmerFixedRateReward"
-            }
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "FarmerVariableRateReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "lastRecordedAccruedRewardPerRarityPoint",
-            "docs": [
-              "used to keep track of how much of the variable reward has been updated for this farmer",
-              "(read more in variable rate config)"
-            ],
-            "type": {
-              "defined": "Number128"
-            }
-          },
-          {
-            "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                16
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "FarmerFixedRateReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "beginStakingTs",
+            "name": "partialCooldownEndsTs",
             "docs": [
-              "this is the time the farmer staked",
-              "can be WAY BACK in the past, if we've rolled them multiple times"
+              "when the most recent partial cooldown ends"
             ],
             "type": "u64"
           },
           {
-            "name": "beginScheduleTs",
+            "name": "beginStakingTs",
             "docs": [
-              "this is the time the latest reward schedule they subscribed to begins",
-              "(this + promised duration = end_schedule_ts)"
+              "when the farmer last went from unstaked to staked, kept across restakes if the farm allows it"
             ],
             "type": "u64"
           },
           {
-            "name": "lastUpdatedTs",
+            "name": "boostedPointsStaked",
             "docs": [
-              "always set to upper bound, not just now_ts (except funding)"
+              "rarity points x loyalty boost, as of the farmer's last refresh (0 unless staked & the boost is on)",
+              "what the farmer's share of variable rewards is based on, see Farm.loyalty_boost"
             ],
             "type": "u64"
           },
           {
-            "name": "promisedSchedule",
-            "docs": [
-              "when a farmer stakes with the fixed schedule, at the time of staking,",
-              "we promise them a schedule for a certain duration (eg 1 token/rarity point/s for 100s)",
-              "that then \"reserves\" a certain amount of funds so that they can't be promised to other farmers",
-              "only if the farmer unstakes, will the reserve be void, and the funds become available again",
-              "for either funding other farmers or withdrawing (when the reward is cancelled)"
-            ],
-            "type": {
-              "defined": "FixedRateSchedule"
-            }
+            "name": "lockPeriodSec",
+            "docs": [
+              "the lock the farmer picked when they started staking (0 = none, the farm's min_staking_period_sec applies)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "promisedDuration",
+            "name": "lockMultiplierBps",
+            "docs": [
+              "comes with the lock (0 = none, ie 1x), applies to all reward types until the farmer fully unstakes"
+            ],
             "type": "u64"
           },
           {
@@ -1610,306 +10224,254 @@ export type GemFarm = {
             "type": {
               "array": [
                 "u8",
-                16
+                64
               ]
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "FarmConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "minStakingPeriodSec",
-            "type": "u64"
           },
           {
-            "name": "cooldownPeriodSec",
-            "type": "u64"
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
           },
           {
-            "name": "unstakingFeeLamp",
-            "type": "u64"
+            "name": "reserved3",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
           }
         ]
       }
     },
     {
-      "name": "MaxCounts",
-      "docs": [
-        "refers to staked counts"
-      ],
+      "name": "farm",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "maxFarmers",
-            "type": "u32"
+            "name": "version",
+            "type": "u16"
           },
           {
-            "name": "maxGems",
-            "type": "u32"
+            "name": "farmManager",
+            "docs": [
+              "authorizes funders, whitelists mints/creators, sets farm config params",
+              "can update itself to another Pubkey"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "maxRarityPoints",
-            "type": "u32"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FundsTracker",
-      "docs": [
-        "these numbers should only ever go up - ie they are cummulative"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "farmTreasury",
+            "docs": [
+              "used for collecting any fees earned by the farm"
+            ],
+            "type": "publicKey"
+          },
           {
-            "name": "totalFunded",
-            "type": "u64"
+            "name": "farmAuthority",
+            "docs": [
+              "signs off on treasury payouts and on any operations related to the bank",
+              "(configured as bank manager)"
+            ],
+            "type": "publicKey"
           },
           {
-            "name": "totalRefunded",
-            "type": "u64"
+            "name": "farmAuthoritySeed",
+            "type": "publicKey"
           },
           {
-            "name": "totalAccruedToStakers",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "TimeTracker",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "farmAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
           {
-            "name": "durationSec",
+            "name": "bank",
             "docs": [
-              "total duration for which the reward has been funded",
-              "updated with each new funding round"
+              "each farm controls a single bank. each farmer gets a vault in that bank"
             ],
-            "type": "u64"
+            "type": "publicKey"
           },
           {
-            "name": "rewardEndTs",
-            "type": "u64"
+            "name": "config",
+            "type": {
+              "defined": "FarmConfig"
+            }
           },
           {
-            "name": "lockEndTs",
+            "name": "farmerCount",
             "docs": [
-              "this will be set = to reward_end_ts if farm manager decides to lock up their reward",
-              "gives stakers the certainty it won't be withdrawn"
+              "total count, including initialized but inactive farmers"
             ],
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FarmReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "rewardMint",
+            "name": "stakedFarmerCount",
             "docs": [
-              "in v0 the next 3 fields (mint, pot type) are set ONLY once, at farm init",
-              "and can't ever be changed for security reasons",
-              "potentially in v1++ might find a way around it, but for now just use a new farm"
+              "currently staked farmer count"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "rewardPot",
+            "name": "gemsStaked",
             "docs": [
-              "where the reward is stored"
+              "currently staked gem count"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "rewardType",
-            "type": {
-              "defined": "RewardType"
-            }
+            "name": "rarityPointsStaked",
+            "docs": [
+              "currently staked gem count, where each gem is multiplied by its rarity score (1 if absent)"
+            ],
+            "type": "u64"
           },
           {
-            "name": "fixedRate",
+            "name": "authorizedFunderCount",
             "docs": [
-              "only one of these two (fixed and variable) will actually be used, per reward"
+              "how many accounts can create funding schedules"
             ],
-            "type": {
-              "defined": "FixedRateReward"
-            }
+            "type": "u64"
           },
           {
-            "name": "variableRate",
+            "name": "rewardA",
             "type": {
-              "defined": "VariableRateReward"
+              "defined": "FarmReward"
             }
           },
           {
-            "name": "funds",
+            "name": "rewardB",
             "type": {
-              "defined": "FundsTracker"
+              "defined": "FarmReward"
             }
           },
           {
-            "name": "times",
+            "name": "maxCounts",
             "type": {
-              "defined": "TimeTracker"
+              "defined": "MaxCounts"
             }
           },
           {
-            "name": "reserved",
+            "name": "rewardSlotCount",
             "docs": [
-              "reserved for future updates, has to be /8"
+              "extra reward streams living in their own PDAs (see FarmRewardSlot)"
             ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "TierConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": "u32"
+          },
           {
-            "name": "rewardRate",
+            "name": "restakeKeepsTenure",
             "docs": [
-              "tokens/denominator/rarity point / sec"
+              "if set, farmers who restake out of cooldown keep their original begin_staking_ts"
             ],
-            "type": "u64"
+            "type": "bool"
           },
           {
-            "name": "requiredTenure",
+            "name": "allowPublicTopUps",
             "docs": [
-              "min amount of time that needs to pass for the above rate to come into effect"
+              "if set, anyone can add tokens to a running variable reward (see top_up_reward)"
             ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FixedRateSchedule",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "type": "bool"
+          },
           {
-            "name": "baseRate",
+            "name": "requiresApprovals",
             "docs": [
-              "tokens/denominator / sec"
+              "if set, sensitive ixs need an approved Proposal from the farm's ManagerSet"
             ],
-            "type": "u64"
-          },
-          {
-            "name": "tier1",
-            "type": {
-              "option": {
-                "defined": "TierConfig"
-              }
-            }
+            "type": "bool"
           },
           {
-            "name": "tier2",
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
             "type": {
-              "option": {
-                "defined": "TierConfig"
-              }
+              "array": [
+                "u8",
+                32
+              ]
             }
           },
           {
-            "name": "tier3",
+            "name": "reserved2",
             "type": {
-              "option": {
-                "defined": "TierConfig"
-              }
-            }
-          },
-          {
-            "name": "denominator",
-            "docs": [
-              "needed to slow down the payout schedule (else min would be 1 token/rarity point/s or 86k/rarity point/day",
-              "only used in fixed rate - in variable overall duration serves as sufficient speed regulator"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FixedRateConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
+              "array": [
+                "u8",
+                12
+              ]
+            }
+          },
           {
-            "name": "schedule",
+            "name": "reserved3",
             "type": {
-              "defined": "FixedRateSchedule"
+              "array": [
+                "u8",
+                1
+              ]
             }
           },
           {
-            "name": "amount",
+            "name": "boostedPointsStaked",
             "docs": [
-              "total amount that is being sent with the ix - will be added ON TOP of existing available funding"
+              "sum of all staked farmers' boosted points (see Farmer), only kept while the boost is on",
+              "variable rewards are split across this instead of rarity_points_staked"
             ],
             "type": "u64"
           },
           {
-            "name": "durationSec",
-            "docs": [
-              "duration the funding is being committed for",
-              "eg if commit funding for 100s and a farmer shows up 3s in, they will be promised 97s at above schedule",
-              "set this carefully!",
-              "every farmer enrolled will be \"reserved\" an amount to cover the schedule for this duration"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FixedRateReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "loyaltyBoost",
+            "type": {
+              "defined": "LoyaltyBoost"
+            }
+          },
           {
-            "name": "schedule",
+            "name": "lockOptions",
             "docs": [
-              "configured on funding"
+              "locks farmers can pick from when they stake, see LockOptions"
             ],
             "type": {
-              "defined": "FixedRateSchedule"
+              "defined": "LockOptions"
             }
           },
           {
-            "name": "reservedAmount",
+            "name": "earlyUnstakePenalty",
+            "type": {
+              "defined": "EarlyUnstakePenalty"
+            }
+          },
+          {
+            "name": "tokenFees",
+            "type": {
+              "defined": "TokenFees"
+            }
+          },
+          {
+            "name": "configTimelockSec",
             "docs": [
-              "amount that has been promised to existing stakers and hence can't be withdrawn"
+              "min delay between queueing a config / max counts change and it taking effect",
+              "0 = update_farm changes them instantly, as before (see PendingFarmConfig)",
+              "while > 0, token fees, the early unstake penalty, lock options, the loyalty boost",
+              "and restake_keeps_tenure can't be changed at all"
             ],
             "type": "u64"
           },
           {
-            "name": "reserved",
+            "name": "reserved4",
             "docs": [
               "reserved for future updates, has to be /8"
             ],
             "type": {
               "array": [
                 "u8",
-                32
+                24
               ]
             }
           }
@@ -1917,61 +10479,39 @@ export type GemFarm = {
       }
     },
     {
-      "name": "VariableRateConfig",
+      "name": "funderContribution",
+      "docs": [
+        "what a single funder put into a single reward (by mint), since the last cancellation",
+        "lets cancel_reward refund funders pro rata instead of sending everything to one receiver"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "amount",
-            "docs": [
-              "total amount of reward"
-            ],
-            "type": "u64"
+            "name": "farm",
+            "type": "publicKey"
           },
           {
-            "name": "durationSec",
-            "docs": [
-              "over which period it's active"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "VariableRateReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
+            "name": "funder",
+            "type": "publicKey"
+          },
           {
-            "name": "rewardRate",
-            "docs": [
-              "in tokens/s, = calculated as total reward pot at initialization / reward duration"
-            ],
-            "type": {
-              "defined": "Number128"
-            }
+            "name": "rewardMint",
+            "type": "publicKey"
           },
           {
-            "name": "rewardLastUpdatedTs",
+            "name": "fundingRound",
             "docs": [
-              "set to upper bound, not just now_ts (except funding, when there is no upper bound)"
+              "mirrors RefundTracker.funding_round at the time of the last contribution"
             ],
             "type": "u64"
           },
           {
-            "name": "accruedRewardPerRarityPoint",
+            "name": "contributed",
             "docs": [
-              "this is somewhat redundant with total_accrued_to_stakers in funds, but necessary",
-              "think of it as a \"flag in the ground\" that gets moved forward as more rewards accrue to the pool",
-              "when a farmer tries to figure out how much they're due from the pool, we:",
-              "1) compare their latest record of flag position, with actual flag position",
-              "2) multiply the difference by the amount they have staked",
-              "3) update their record of flag position, so that next time we don't count this distance again"
+              "contributed during funding_round, zeroed once refunded"
             ],
-            "type": {
-              "defined": "Number128"
-            }
+            "type": "u64"
           },
           {
             "name": "reserved",
@@ -1989,1265 +10529,1073 @@ export type GemFarm = {
       }
     },
     {
-      "name": "FarmerState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Unstaked"
-          },
-          {
-            "name": "Staked"
-          },
-          {
-            "name": "PendingCooldown"
-          }
-        ]
-      }
-    },
-    {
-      "name": "RewardType",
+      "name": "managerSet",
+      "docs": [
+        "an M-of-N set of managers, one per farm - once a farm has one, anything that moves funds or",
+        "control (cancelling or replacing rewards, treasury payouts and splits, handing over",
+        "farm_manager) needs an approved Proposal on top of farm_manager's signature"
+      ],
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "Variable"
+            "name": "farm",
+            "type": "publicKey"
           },
           {
-            "name": "Fixed"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FixedRateRewardTier",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Base"
+            "name": "managers",
+            "type": {
+              "defined": "Managers"
+            }
           },
           {
-            "name": "Tier1"
+            "name": "generation",
+            "docs": [
+              "bumped every time the managers change, approvals collected under an older set lapse"
+            ],
+            "type": "u64"
           },
           {
-            "name": "Tier2"
+            "name": "proposalCount",
+            "docs": [
+              "used to derive the next proposal's address"
+            ],
+            "type": "u64"
           },
           {
-            "name": "Tier3"
-          }
-        ]
-      }
-    }
-  ]
-};
-
-export const IDL: GemFarm = {
-  "version": "0.1.0",
-  "name": "gem_farm",
-  "instructions": [
-    {
-      "name": "initFarm",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "farmManager",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardAPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardAMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bank",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "rewardTypeA",
-          "type": {
-            "defined": "RewardType"
-          }
-        },
-        {
-          "name": "rewardTypeB",
-          "type": {
-            "defined": "RewardType"
-          }
-        },
-        {
-          "name": "farmConfig",
-          "type": {
-            "defined": "FarmConfig"
-          }
-        },
-        {
-          "name": "maxCounts",
-          "type": {
-            "option": {
-              "defined": "MaxCounts"
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
             }
           }
-        },
-        {
-          "name": "farmTreasury",
-          "type": "publicKey"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "updateFarm",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": false,
-          "isSigner": true
-        }
+      "name": "proposal",
+      "docs": [
+        "one action, approved by members of the farm's ManagerSet and executed by the ix it's for"
       ],
-      "args": [
-        {
-          "name": "config",
-          "type": {
-            "option": {
-              "defined": "FarmConfig"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "index",
+            "docs": [
+              "ManagerSet.proposal_count at the time, part of the PDA seeds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "ManagerSet.generation at the time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "ProposalAction"
             }
-          }
-        },
-        {
-          "name": "manager",
-          "type": {
-            "option": "publicKey"
-          }
-        },
-        {
-          "name": "maxCounts",
-          "type": {
-            "option": {
-              "defined": "MaxCounts"
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "bit i = ManagerSet.managers.members[i] approved"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "executed",
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
             }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "payoutFromTreasury",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "pendingFarmConfig",
+      "docs": [
+        "a FarmConfig / MaxCounts change queued by the manager (see Farm.config_timelock_sec)",
+        "lives in its own PDA so stakers can see it coming, applied by the permissionless apply_pending_config"
       ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "lamports",
-          "type": "u64"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "effectiveTs",
+            "docs": [
+              "can't be applied before this"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "config",
+            "docs": [
+              "None = left unchanged"
+            ],
+            "type": {
+              "option": {
+                "defined": "FarmConfig"
+              }
+            }
+          },
+          {
+            "name": "maxCounts",
+            "type": {
+              "option": {
+                "defined": "MaxCounts"
+              }
+            }
+          },
+          {
+            "name": "configTimelockSec",
+            "docs": [
+              "lowering the timelock has to go through the timelock too"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "addToBankWhitelist",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bank",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "addressToWhitelist",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "whitelistProof",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "protocolConfig",
+      "docs": [
+        "singleton PDA, every fee-charging ix reads the fee wallet and amounts from here"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "the only key that can update this config"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "feeWallet",
+            "docs": [
+              "where protocol fees go"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "fees",
+            "type": {
+              "defined": "ProtocolFees"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "farmRewardSlot",
+      "docs": [
+        "an extra reward stream on top of the farm's built-in reward_a / reward_b",
+        "lives in its own PDA (one per reward mint), so a farm can run as many of these as it wants"
       ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "whitelistType",
-          "type": "u8"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "docs": [
+              "sum of all farmer snapshots (see FarmerRewardSlot), the variable reward is split across these",
+              "can lag behind farm.rarity_points_staked until every farmer has been refreshed"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": {
+              "defined": "FarmReward"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "removeFromBankWhitelist",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bank",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "addressToRemove",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "whitelistProof",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "farmerRewardSlot",
+      "docs": [
+        "farmer-side counterpart of FarmRewardSlot, one per (slot, farmer)"
       ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpWl",
-          "type": "u8"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farmer",
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardSlot",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPointsStaked",
+            "docs": [
+              "farmer's (lock-weighted) rarity points as of the last refresh of this slot"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reward",
+            "type": {
+              "defined": "FarmerReward"
+            }
+          },
+          {
+            "name": "lastRefreshedTs",
+            "docs": [
+              "when this slot was last refreshed, 0 if never"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "reserved2",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          },
+          {
+            "name": "reserved3",
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "initFarmer",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "bank",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "treasurySplit",
+      "docs": [
+        "a fixed split of the farm treasury's lamports, paid out by the permissionless distribute_treasury",
+        "lives in its own PDA (one per farm) - only farms that want a split need one"
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "farm",
+            "type": "publicKey"
+          },
+          {
+            "name": "recipients",
+            "docs": [
+              "only the first recipient_count entries are used, shares add up to 100%"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "TreasuryRecipient"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "recipientCount",
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                64
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RarityConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "rarityPoints",
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "name": "stake",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "bank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpFarmer",
-          "type": "u8"
-        }
-      ]
+      "name": "Number128",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "n",
+            "type": "u128"
+          }
+        ]
+      }
     },
     {
-      "name": "unstake",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "bank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "bumpFarmer",
-          "type": "u8"
-        },
-        {
-          "name": "skipRewards",
-          "type": "bool"
-        }
-      ]
+      "name": "Number192",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "n",
+            "type": {
+              "array": [
+                "u64",
+                3
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "claim",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rewardAPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardAMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rewardADestination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rewardBDestination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpFarmer",
-          "type": "u8"
-        },
-        {
-          "name": "bumpPotA",
-          "type": "u8"
-        },
-        {
-          "name": "bumpPotB",
-          "type": "u8"
-        }
-      ]
+      "name": "FarmerReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "paidOutReward",
+            "docs": [
+              "total, not per rarity point. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "accruedReward",
+            "docs": [
+              "total, not per rarity point. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "variableRate",
+            "docs": [
+              "only one of these two (fixed and variable) will actually be used, per reward"
+            ],
+            "type": {
+              "defined": "FarmerVariableRateReward"
+            }
+          },
+          {
+            "name": "fixedRate",
+            "type": {
+              "defined": "FarmerFixedRateReward"
+            }
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "mirrors FarmReward.generation, if they differ the farm reward has been replaced"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "forfeitedReward",
+            "docs": [
+              "total lost to early unstake penalties, never claimable. Never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capPeriodStartTs",
+            "docs": [
+              "the current period under FarmReward.farmer_cap, and what's been accrued during it"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "capPeriodAccrued",
+            "type": "u64"
+          },
+          {
+            "name": "capSettledTs",
+            "docs": [
+              "when accrual was last settled against the cap, so it can be spread over the periods since"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "flashDeposit",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "bank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "vaultAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemBox",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemDepositReceipt",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemSource",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemRarity",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpFarmer",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVaultAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpRarity",
-          "type": "u8"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
+      "name": "FarmerVariableRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastRecordedAccruedRewardPerRarityPoint",
+            "docs": [
+              "used to keep track of how much of the variable reward has been updated for this farmer",
+              "(read more in variable rate config)"
+            ],
+            "type": {
+              "defined": "Number192"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerFixedRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beginStakingTs",
+            "docs": [
+              "this is the time the farmer staked",
+              "can be WAY BACK in the past, if we've rolled them multiple times"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "beginScheduleTs",
+            "docs": [
+              "this is the time the latest reward schedule they subscribed to begins",
+              "(this + promised duration = end_schedule_ts)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdatedTs",
+            "docs": [
+              "always set to upper bound, not just now_ts (except funding)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "promisedSchedule",
+            "docs": [
+              "when a farmer stakes with the fixed schedule, at the time of staking,",
+              "we promise them a schedule for a certain duration (eg 1 token/rarity point/s for 100s)",
+              "that then \"reserves\" a certain amount of funds so that they can't be promised to other farmers",
+              "only if the farmer unstakes, will the reserve be void, and the funds become available again",
+              "for either funding other farmers or withdrawing (when the reward is cancelled)"
+            ],
+            "type": {
+              "defined": "FixedRateSchedule"
+            }
+          },
+          {
+            "name": "promisedDuration",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "refreshFarmer",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
+      "name": "FarmConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minStakingPeriodSec",
+            "type": "u64"
+          },
+          {
+            "name": "cooldownPeriodSec",
+            "type": "u64"
+          },
+          {
+            "name": "unstakingFeeLamp",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "refreshFarmerSigned",
+      "name": "TokenFees",
       "docs": [
-        "this one needs to be called by the farmer themselves",
-        "it's useful if for some reason they can't re-enroll in another fixed reward cycle (eg reward exhausted)",
-        "but they want to be able to refresh themselves and claim their earned rewards up to this point"
-      ],
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": false,
-          "isSigner": true
-        }
-      ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
-        },
-        {
-          "name": "reenroll",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "authorizeFunder",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "funderToAuthorize",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationProof",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+        "optional fees in the farm's own token, paid into the farm treasury's token account for fee_mint",
+        "(on top of the lamport ones - see FarmConfig.unstaking_fee_lamp)"
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeFee",
+            "docs": [
+              "charged whenever a farmer starts staking (stake, restake)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unstakeFee",
+            "docs": [
+              "charged wherever the lamport unstaking fee is"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "deauthorizeFunder",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "funderToDeauthorize",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationProof",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "MaxCounts",
+      "docs": [
+        "refers to staked counts"
       ],
-      "args": [
-        {
-          "name": "bump",
-          "type": "u8"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxFarmers",
+            "type": "u32"
+          },
+          {
+            "name": "maxGems",
+            "type": "u32"
+          },
+          {
+            "name": "maxRarityPoints",
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "name": "fundReward",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationProof",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizedFunder",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rewardPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardSource",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "EarlyUnstakePenalty",
+      "docs": [
+        "farmers who unstake before min_tenure_sec forfeit penalty_bps of their outstanding reward_a / reward_b",
+        "(and can't claim until they reach it, else the penalty could be dodged by claiming first)",
+        "partial unstakes forfeit pro rata to the rarity points taken out"
       ],
-      "args": [
-        {
-          "name": "bumpProof",
-          "type": "u8"
-        },
-        {
-          "name": "bumpPot",
-          "type": "u8"
-        },
-        {
-          "name": "variableRateConfig",
-          "type": {
-            "option": {
-              "defined": "VariableRateConfig"
-            }
-          }
-        },
-        {
-          "name": "fixedRateConfig",
-          "type": {
-            "option": {
-              "defined": "FixedRateConfig"
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minTenureSec",
+            "docs": [
+              "continuous staking time (see Farmer.begin_staking_ts) after which no penalty applies"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "penaltyBps",
+            "docs": [
+              "0 = no penalty"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "destination",
+            "type": {
+              "defined": "PenaltyDestination"
             }
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "cancelReward",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rewardPot",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardDestination",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "rewardMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "FundsTracker",
+      "docs": [
+        "these numbers should only ever go up - ie they are cummulative"
       ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpPot",
-          "type": "u8"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalRefunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalAccruedToStakers",
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "name": "lockReward",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "rewardMint",
-          "isMut": false,
-          "isSigner": false
-        }
+      "name": "RefundTracker",
+      "docs": [
+        "per-funder refunds (see FunderContribution)",
+        "funding is tracked in rounds - each cancellation closes the current one, and what it refunds is",
+        "split across that round's funders, pro rata to what they contributed (claimed via claim_refund)"
       ],
-      "args": []
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fundingRound",
+            "docs": [
+              "bumped by every cancellation that has contributions to refund"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "roundContributions",
+            "docs": [
+              "contributed by all funders during the current round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundAmount",
+            "docs": [
+              "the next 4 fields describe the last closed round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "refundContributions",
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedContributions",
+            "docs": [
+              "contributions / tokens not yet claimed back by their funders"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unclaimedRefund",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TimeTracker",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "durationSec",
+            "docs": [
+              "total duration for which the reward has been funded",
+              "updated with each new funding round"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBeginTs",
+            "docs": [
+              "nothing accrues before this - can be in the future if funding was scheduled ahead of time"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardEndTs",
+            "type": "u64"
+          },
+          {
+            "name": "lockEndTs",
+            "docs": [
+              "this will be set = to reward_end_ts if farm manager decides to lock up their reward",
+              "gives stakers the certainty it won't be withdrawn"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardMint",
+            "docs": [
+              "the next 3 fields (mint, pot type) are set at farm init",
+              "and can only be changed by replace_reward, once the reward is ended, unlocked and drained"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardPot",
+            "docs": [
+              "where the reward is stored"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardType",
+            "type": {
+              "defined": "RewardType"
+            }
+          },
+          {
+            "name": "fixedRate",
+            "docs": [
+              "only one of these two (fixed and variable) will actually be used, per reward"
+            ],
+            "type": {
+              "defined": "FixedRateReward"
+            }
+          },
+          {
+            "name": "variableRate",
+            "type": {
+              "defined": "VariableRateReward"
+            }
+          },
+          {
+            "name": "funds",
+            "type": {
+              "defined": "FundsTracker"
+            }
+          },
+          {
+            "name": "times",
+            "type": {
+              "defined": "TimeTracker"
+            }
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "bumped every time the reward is replaced, farmers on an older generation get voided"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalForfeitedToTreasury",
+            "docs": [
+              "early unstake penalties (see EarlyUnstakePenalty) sitting in the pot, owed to the treasury",
+              "both are cumulative, the difference is what collect_penalties can still move"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalPaidToTreasury",
+            "type": "u64"
+          },
+          {
+            "name": "refunds",
+            "type": {
+              "defined": "RefundTracker"
+            }
+          },
+          {
+            "name": "totalPaidOut",
+            "docs": [
+              "claimed by farmers so far, only tracked since this was added (see sync_pot)",
+              "never goes down (ie is cumulative)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "farmerCap",
+            "docs": [
+              "optional caps on what a single farmer can accrue from this reward"
+            ],
+            "type": {
+              "defined": "RewardCap"
+            }
+          },
+          {
+            "name": "maxRoundingDust",
+            "docs": [
+              "upper bound on what variable accrual has rounded away - it's ceiled at the farm level and",
+              "floored at the farmer level, < 1 each per update. Never goes down (ie is cumulative)",
+              "lets replace_reward tell rounding dust apart from rewards farmers haven't claimed yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "reserved for future updates, has to be /8"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "addRaritiesToBank",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmManager",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpAuth",
-          "type": "u8"
-        },
-        {
-          "name": "rarityConfigs",
-          "type": {
-            "vec": {
-              "defined": "RarityConfig"
-            }
+      "name": "TierConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardRate",
+            "docs": [
+              "tokens/denominator/rarity point / sec"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "requiredTenure",
+            "docs": [
+              "min amount of time that needs to pass for the above rate to come into effect"
+            ],
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "flashDepositPnft",
-      "accounts": [
-        {
-          "name": "farm",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "farmAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "farmer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "identity",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "bank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "vault",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "vaultAuthority",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemBox",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemDepositReceipt",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemSource",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemMint",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemRarity",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemBank",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "feeAcc",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "associatedTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "gemMetadata",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "gemEdition",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "ownerTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "destTokenRecord",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "tokenMetadataProgram",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "instructions",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "authorizationRulesProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpFarmer",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVaultAuth",
-          "type": "u8"
-        },
-        {
-          "name": "bumpRarity",
-          "type": "u8"
-        },
-        {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "rulesAccPresent",
-          "type": "bool"
-        }
-      ]
-    }
-  ],
-  "accounts": [
+      "name": "FixedRateSchedule",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseRate",
+            "docs": [
+              "tokens/denominator / sec"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tiers",
+            "docs": [
+              "only the first tier_count entries are used, ordered by required_tenure (ascending)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "TierConfig"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "needed to slow down the payout schedule (else min would be 1 token/rarity point/s or 86k/rarity point/day",
+              "only used in fixed rate - in variable overall duration serves as sufficient speed regulator"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tierCount",
+            "type": "u8"
+          }
+        ]
+      }
+    },
     {
-      "name": "authorizationProof",
-      "docs": [
-        "if this PDA exists, this means the funder recorded below has been authorized by the",
-        "farm recorded below to fund rewards"
-      ],
+      "name": "FixedRateConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "authorizedFunder",
-            "type": "publicKey"
+            "name": "schedule",
+            "type": {
+              "defined": "FixedRateSchedule"
+            }
           },
           {
-            "name": "farm",
-            "type": "publicKey"
+            "name": "amount",
+            "docs": [
+              "total amount that is being sent with the ix - will be added ON TOP of existing available funding"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "durationSec",
+            "docs": [
+              "duration the funding is being committed for",
+              "eg if commit funding for 100s and a farmer shows up 3s in, they will be promised 97s at above schedule",
+              "set this carefully!",
+              "every farmer enrolled will be \"reserved\" an amount to cover the schedule for this duration"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "startTs",
+            "docs": [
+              "when the above duration starts, if not now",
+              "farmers enrolling before then have their schedule (and reserve) start at this time"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateReward",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "docs": [
+              "configured on funding"
+            ],
+            "type": {
+              "defined": "FixedRateSchedule"
+            }
+          },
+          {
+            "name": "reservedAmount",
+            "docs": [
+              "amount that has been promised to existing stakers and hence can't be withdrawn"
+            ],
+            "type": "u64"
           },
           {
             "name": "reserved",
@@ -3265,79 +11613,232 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "farmer",
+      "name": "VariableRateRewardV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "farm",
-            "type": "publicKey"
+            "name": "rewardRate",
+            "type": {
+              "defined": "Number128"
+            }
           },
           {
-            "name": "identity",
-            "docs": [
-              "the identity of the farmer = their public key"
-            ],
+            "name": "rewardLastUpdatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "accruedRewardPerRarityPoint",
+            "type": {
+              "defined": "Number128"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerVariableRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lastRecordedAccruedRewardPerRarityPoint",
+            "type": {
+              "defined": "Number128"
+            }
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "TimeTrackerV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "durationSec",
+            "type": "u64"
+          },
+          {
+            "name": "rewardEndTs",
+            "type": "u64"
+          },
+          {
+            "name": "lockEndTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateScheduleV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "baseRate",
+            "type": "u64"
+          },
+          {
+            "name": "tier1",
+            "type": {
+              "option": {
+                "defined": "TierConfig"
+              }
+            }
+          },
+          {
+            "name": "tier2",
+            "type": {
+              "option": {
+                "defined": "TierConfig"
+              }
+            }
+          },
+          {
+            "name": "tier3",
+            "type": {
+              "option": {
+                "defined": "TierConfig"
+              }
+            }
+          },
+          {
+            "name": "denominator",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FixedRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "schedule",
+            "type": {
+              "defined": "FixedRateScheduleV0"
+            }
+          },
+          {
+            "name": "reservedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmerFixedRateRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "beginStakingTs",
+            "type": "u64"
+          },
+          {
+            "name": "beginScheduleTs",
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "promisedSchedule",
+            "type": {
+              "defined": "FixedRateScheduleV0"
+            }
+          },
+          {
+            "name": "promisedDuration",
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "type": {
+              "array": [
+                "u8",
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FarmRewardV0",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "rewardMint",
             "type": "publicKey"
           },
           {
-            "name": "vault",
-            "docs": [
-              "vault storing all of the farmer's gems"
-            ],
+            "name": "rewardPot",
             "type": "publicKey"
           },
           {
-            "name": "state",
+            "name": "rewardType",
             "type": {
-              "defined": "FarmerState"
+              "defined": "RewardType"
             }
           },
           {
-            "name": "gemsStaked",
-            "docs": [
-              "total number of gems at the time when the vault is locked"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rarityPointsStaked",
-            "docs": [
-              "total number of gems * rarity of each gem (1 if un-appraised)"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "minStakingEndsTs",
-            "docs": [
-              "this will be updated when they decide to unstake taking into acc. config set at farm level"
-            ],
-            "type": "u64"
+            "name": "fixedRate",
+            "type": {
+              "defined": "FixedRateRewardV0"
+            }
           },
           {
-            "name": "cooldownEndsTs",
-            "docs": [
-              "this will be updated when they decide to unstake taking into acc. config set at farm level"
-            ],
-            "type": "u64"
+            "name": "variableRate",
+            "type": {
+              "defined": "VariableRateRewardV0"
+            }
           },
           {
-            "name": "rewardA",
+            "name": "funds",
             "type": {
-              "defined": "FarmerReward"
+              "defined": "FundsTracker"
             }
           },
           {
-            "name": "rewardB",
+            "name": "times",
             "type": {
-              "defined": "FarmerReward"
+              "defined": "TimeTrackerV0"
             }
           },
           {
             "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
             "type": {
               "array": [
                 "u8",
@@ -3349,7 +11850,7 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "farm",
+      "name": "FarmV0",
       "type": {
         "kind": "struct",
         "fields": [
@@ -3359,25 +11860,14 @@ export const IDL: GemFarm = {
           },
           {
             "name": "farmManager",
-            "docs": [
-              "authorizes funders, whitelists mints/creators, sets farm config params",
-              "can update itself to another Pubkey"
-            ],
             "type": "publicKey"
           },
           {
             "name": "farmTreasury",
-            "docs": [
-              "used for collecting any fees earned by the farm"
-            ],
             "type": "publicKey"
           },
           {
             "name": "farmAuthority",
-            "docs": [
-              "signs off on treasury payouts and on any operations related to the bank",
-              "(configured as bank manager)"
-            ],
             "type": "publicKey"
           },
           {
@@ -3395,9 +11885,6 @@ export const IDL: GemFarm = {
           },
           {
             "name": "bank",
-            "docs": [
-              "each farm controls a single bank. each farmer gets a vault in that bank"
-            ],
             "type": "publicKey"
           },
           {
@@ -3408,49 +11895,34 @@ export const IDL: GemFarm = {
           },
           {
             "name": "farmerCount",
-            "docs": [
-              "total count, including initialized but inactive farmers"
-            ],
             "type": "u64"
           },
           {
             "name": "stakedFarmerCount",
-            "docs": [
-              "currently staked farmer count"
-            ],
             "type": "u64"
           },
           {
             "name": "gemsStaked",
-            "docs": [
-              "currently staked gem count"
-            ],
             "type": "u64"
           },
           {
             "name": "rarityPointsStaked",
-            "docs": [
-              "currently staked gem count, where each gem is multiplied by its rarity score (1 if absent)"
-            ],
             "type": "u64"
           },
           {
             "name": "authorizedFunderCount",
-            "docs": [
-              "how many accounts can create funding schedules"
-            ],
             "type": "u64"
           },
           {
             "name": "rewardA",
             "type": {
-              "defined": "FarmReward"
+              "defined": "FarmRewardV0"
             }
           },
           {
             "name": "rewardB",
             "type": {
-              "defined": "FarmReward"
+              "defined": "FarmRewardV0"
             }
           },
           {
@@ -3461,9 +11933,6 @@ export const IDL: GemFarm = {
           },
           {
             "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
             "type": {
               "array": [
                 "u8",
@@ -3491,76 +11960,34 @@ export const IDL: GemFarm = {
           }
         ]
       }
-    }
-  ],
-  "types": [
-    {
-      "name": "RarityConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "rarityPoints",
-            "type": "u16"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Number128",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "n",
-            "type": "u128"
-          }
-        ]
-      }
     },
     {
-      "name": "FarmerReward",
+      "name": "FarmerRewardV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "paidOutReward",
-            "docs": [
-              "total, not per rarity point. Never goes down (ie is cumulative)"
-            ],
             "type": "u64"
           },
           {
             "name": "accruedReward",
-            "docs": [
-              "total, not per rarity point. Never goes down (ie is cumulative)"
-            ],
             "type": "u64"
           },
           {
             "name": "variableRate",
-            "docs": [
-              "only one of these two (fixed and variable) will actually be used, per reward"
-            ],
             "type": {
-              "defined": "FarmerVariableRateReward"
+              "defined": "FarmerVariableRateRewardV0"
             }
           },
           {
             "name": "fixedRate",
             "type": {
-              "defined": "FarmerFixedRateReward"
+              "defined": "FarmerFixedRateRewardV0"
             }
           },
           {
             "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
             "type": {
               "array": [
                 "u8",
@@ -3572,89 +11999,62 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "FarmerVariableRateReward",
+      "name": "FarmerV0",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "lastRecordedAccruedRewardPerRarityPoint",
-            "docs": [
-              "used to keep track of how much of the variable reward has been updated for this farmer",
-              "(read more in variable rate config)"
-            ],
-            "type": {
-              "defined": "Number128"
-            }
+            "name": "farm",
+            "type": "publicKey"
           },
           {
-            "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
+            "name": "identity",
+            "type": "publicKey"
+          },
+          {
+            "name": "vault",
+            "type": "publicKey"
+          },
+          {
+            "name": "state",
             "type": {
-              "array": [
-                "u8",
-                16
-              ]
+              "defined": "FarmerState"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "FarmerFixedRateReward",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "beginStakingTs",
-            "docs": [
-              "this is the time the farmer staked",
-              "can be WAY BACK in the past, if we've rolled them multiple times"
-            ],
+            "name": "gemsStaked",
             "type": "u64"
           },
           {
-            "name": "beginScheduleTs",
-            "docs": [
-              "this is the time the latest reward schedule they subscribed to begins",
-              "(this + promised duration = end_schedule_ts)"
-            ],
+            "name": "rarityPointsStaked",
             "type": "u64"
           },
           {
-            "name": "lastUpdatedTs",
-            "docs": [
-              "always set to upper bound, not just now_ts (except funding)"
-            ],
+            "name": "minStakingEndsTs",
             "type": "u64"
           },
           {
-            "name": "promisedSchedule",
-            "docs": [
-              "when a farmer stakes with the fixed schedule, at the time of staking,",
-              "we promise them a schedule for a certain duration (eg 1 token/rarity point/s for 100s)",
-              "that then \"reserves\" a certain amount of funds so that they can't be promised to other farmers",
-              "only if the farmer unstakes, will the reserve be void, and the funds become available again",
-              "for either funding other farmers or withdrawing (when the reward is cancelled)"
-            ],
+            "name": "cooldownEndsTs",
+            "type": "u64"
+          },
+          {
+            "name": "rewardA",
             "type": {
-              "defined": "FixedRateSchedule"
+              "defined": "FarmerRewardV0"
             }
           },
           {
-            "name": "promisedDuration",
-            "type": "u64"
+            "name": "rewardB",
+            "type": {
+              "defined": "FarmerRewardV0"
+            }
           },
           {
             "name": "reserved",
-            "docs": [
-              "reserved for future updates, has to be /8"
-            ],
             "type": {
               "array": [
                 "u8",
-                16
+                32
               ]
             }
           }
@@ -3662,93 +12062,142 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "FarmConfig",
+      "name": "LoyaltyBoostTier",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "minStakingPeriodSec",
-            "type": "u64"
-          },
-          {
-            "name": "cooldownPeriodSec",
+            "name": "requiredTenure",
+            "docs": [
+              "min continuous staking time for the multiplier below to kick in"
+            ],
             "type": "u64"
           },
           {
-            "name": "unstakingFeeLamp",
+            "name": "multiplierBps",
+            "docs": [
+              "eg 15000 = 1.5x the farmer's rarity points"
+            ],
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "MaxCounts",
+      "name": "LoyaltyBoost",
       "docs": [
-        "refers to staked counts"
+        "variable-rate rewards only (fixed-rate ones have their own tiers, see FixedRateSchedule)",
+        "farmers' points are multiplied by the highest tier they've reached,",
+        "the farm keeps the sum of those boosted points and splits the variable reward across it"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "maxFarmers",
-            "type": "u32"
+            "name": "tiers",
+            "docs": [
+              "only the first tier_count entries are used, ordered by required_tenure (ascending)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "LoyaltyBoostTier"
+                },
+                4
+              ]
+            }
           },
           {
-            "name": "maxGems",
-            "type": "u32"
+            "name": "tierCount",
+            "docs": [
+              "0 = no boost, variable rewards are split by raw rarity points"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Managers",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "only the first member_count entries are used"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
           },
           {
-            "name": "maxRarityPoints",
-            "type": "u32"
+            "name": "memberCount",
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "approvals needed for a proposal to go through"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "FundsTracker",
+      "name": "ProtocolFees",
       "docs": [
-        "these numbers should only ever go up - ie they are cummulative"
+        "what the protocol charges on top of any farm-level fees (see FarmConfig.unstaking_fee_lamp)",
+        "amounts are in lamports, 0 = free"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "totalFunded",
+            "name": "initFarm",
+            "docs": [
+              "originally 2.5 SOL"
+            ],
             "type": "u64"
           },
           {
-            "name": "totalRefunded",
+            "name": "initFarmer",
+            "docs": [
+              "originally 0.01 SOL"
+            ],
             "type": "u64"
           },
           {
-            "name": "totalAccruedToStakers",
+            "name": "enterFarm",
+            "docs": [
+              "init_farmer + stake in one go, originally 0.012 SOL"
+            ],
             "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "TimeTracker",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "durationSec",
+            "name": "stake",
             "docs": [
-              "total duration for which the reward has been funded",
-              "updated with each new funding round"
+              "stake, restake and flash deposits that start a new stake, originally 0.002 SOL"
             ],
             "type": "u64"
           },
           {
-            "name": "rewardEndTs",
+            "name": "unstake",
+            "docs": [
+              "charged on both unstake calls (staked -> cooldown -> unstaked), originally 0.001 SOL",
+              "partial_unstake and withdraw_unstaked_gem are the same two steps for a subset of gems, so pay the same",
+              "flash_withdraw pays it once"
+            ],
             "type": "u64"
           },
           {
-            "name": "lockEndTs",
+            "name": "flashDeposit",
             "docs": [
-              "this will be set = to reward_end_ts if farm manager decides to lock up their reward",
-              "gives stakers the certainty it won't be withdrawn"
+              "flash deposits into an existing stake, originally 0.001 SOL"
             ],
             "type": "u64"
           }
@@ -3756,57 +12205,36 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "FarmReward",
+      "name": "RewardCap",
+      "docs": [
+        "optional per-farmer caps on a single reward stream (see FarmReward.farmer_cap)",
+        "accrual settled across several periods is assumed to have been earned evenly over them"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "rewardMint",
-            "docs": [
-              "in v0 the next 3 fields (mint, pot type) are set ONLY once, at farm init",
-              "and can't ever be changed for security reasons",
-              "potentially in v1++ might find a way around it, but for now just use a new farm"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardPot",
+            "name": "periodSec",
             "docs": [
-              "where the reward is stored"
+              "0 = no per-period cap"
             ],
-            "type": "publicKey"
+            "type": "u64"
           },
           {
-            "name": "rewardType",
-            "type": {
-              "defined": "RewardType"
-            }
+            "name": "maxPerPeriod",
+            "type": "u64"
           },
           {
-            "name": "fixedRate",
+            "name": "maxLifetime",
             "docs": [
-              "only one of these two (fixed and variable) will actually be used, per reward"
+              "0 = no lifetime cap, compared against FarmerReward.accrued_reward"
             ],
-            "type": {
-              "defined": "FixedRateReward"
-            }
-          },
-          {
-            "name": "variableRate",
-            "type": {
-              "defined": "VariableRateReward"
-            }
-          },
-          {
-            "name": "funds",
-            "type": {
-              "defined": "FundsTracker"
-            }
+            "type": "u64"
           },
           {
-            "name": "times",
+            "name": "overflow",
             "type": {
-              "defined": "TimeTracker"
+              "defined": "CapOverflow"
             }
           },
           {
@@ -3817,7 +12245,7 @@ export const IDL: GemFarm = {
             "type": {
               "array": [
                 "u8",
-                32
+                7
               ]
             }
           }
@@ -3825,21 +12253,21 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "TierConfig",
+      "name": "LockOption",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "rewardRate",
+            "name": "lockSec",
             "docs": [
-              "tokens/denominator/rarity point / sec"
+              "replaces the farm's min_staking_period_sec for farmers who pick this lock"
             ],
             "type": "u64"
           },
           {
-            "name": "requiredTenure",
+            "name": "multiplierBps",
             "docs": [
-              "min amount of time that needs to pass for the above rate to come into effect"
+              "eg 20000 = the farmer's rarity points count double, for all reward types"
             ],
             "type": "u64"
           }
@@ -3847,135 +12275,99 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "FixedRateSchedule",
+      "name": "LockOptions",
+      "docs": [
+        "the menu of locks farmers can pick from when they stake",
+        "farmers who don't pick one get the farm's min_staking_period_sec at 1x, as before"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "baseRate",
+            "name": "options",
             "docs": [
-              "tokens/denominator / sec"
+              "only the first option_count entries are used, farmers pick by index"
             ],
-            "type": "u64"
-          },
-          {
-            "name": "tier1",
-            "type": {
-              "option": {
-                "defined": "TierConfig"
-              }
-            }
-          },
-          {
-            "name": "tier2",
-            "type": {
-              "option": {
-                "defined": "TierConfig"
-              }
-            }
-          },
-          {
-            "name": "tier3",
             "type": {
-              "option": {
-                "defined": "TierConfig"
-              }
+              "array": [
+                {
+                  "defined": "LockOption"
+                },
+                4
+              ]
             }
           },
           {
-            "name": "denominator",
-            "docs": [
-              "needed to slow down the payout schedule (else min would be 1 token/rarity point/s or 86k/rarity point/day",
-              "only used in fixed rate - in variable overall duration serves as sufficient speed regulator"
-            ],
-            "type": "u64"
+            "name": "optionCount",
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "FixedRateConfig",
+      "name": "TreasuryRecipient",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "schedule",
-            "type": {
-              "defined": "FixedRateSchedule"
-            }
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "total amount that is being sent with the ix - will be added ON TOP of existing available funding"
-            ],
-            "type": "u64"
+            "name": "destination",
+            "type": "publicKey"
           },
           {
-            "name": "durationSec",
-            "docs": [
-              "duration the funding is being committed for",
-              "eg if commit funding for 100s and a farmer shows up 3s in, they will be promised 97s at above schedule",
-              "set this carefully!",
-              "every farmer enrolled will be \"reserved\" an amount to cover the schedule for this duration"
-            ],
+            "name": "shareBps",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "FixedRateReward",
+      "name": "VariableRateConfig",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "schedule",
+            "name": "amount",
             "docs": [
-              "configured on funding"
+              "total amount of reward"
             ],
-            "type": {
-              "defined": "FixedRateSchedule"
-            }
+            "type": "u64"
           },
           {
-            "name": "reservedAmount",
+            "name": "durationSec",
             "docs": [
-              "amount that has been promised to existing stakers and hence can't be withdrawn"
+              "over which period it's active",
+              "only used by FundingMode::Reset, as are start_ts and the curve"
             ],
             "type": "u64"
           },
           {
-            "name": "reserved",
+            "name": "startTs",
             "docs": [
-              "reserved for future updates, has to be /8"
+              "when the period starts, if not now - nothing accrues before it"
             ],
             "type": {
-              "array": [
-                "u8",
-                32
-              ]
+              "option": "u64"
             }
-          }
-        ]
-      }
-    },
-    {
-      "name": "VariableRateConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
+          },
           {
-            "name": "amount",
+            "name": "emissionCurve",
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "fundingMode",
             "docs": [
-              "total amount of reward"
+              "anything other than Reset needs a running (or scheduled) reward with a constant curve"
             ],
-            "type": "u64"
+            "type": {
+              "defined": "FundingMode"
+            }
           },
           {
-            "name": "durationSec",
+            "name": "halvingPeriodSec",
             "docs": [
-              "over which period it's active"
+              "only used by EmissionCurve::Halving, has to be > 0 there"
             ],
             "type": "u64"
           }
@@ -3990,10 +12382,11 @@ export const IDL: GemFarm = {
           {
             "name": "rewardRate",
             "docs": [
-              "in tokens/s, = calculated as total reward pot at initialization / reward duration"
+              "in tokens/s, = calculated as total reward pot at initialization / reward duration",
+              "for decaying curves, this is the rate at the beginning of the reward"
             ],
             "type": {
-              "defined": "Number128"
+              "defined": "Number192"
             }
           },
           {
@@ -4014,24 +12407,57 @@ export const IDL: GemFarm = {
               "3) update their record of flag position, so that next time we don't count this distance again"
             ],
             "type": {
-              "defined": "Number128"
+              "defined": "Number192"
+            }
+          },
+          {
+            "name": "halvingPeriodSec",
+            "docs": [
+              "see VariableRateConfig, set on each funding"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "emissionCurve",
+            "type": {
+              "defined": "EmissionCurve"
             }
           },
           {
             "name": "reserved",
             "docs": [
-              "reserved for future updates, has to be /8"
+              "reserved for future updates, has to be /8 (together with emission_curve)"
             ],
             "type": {
               "array": [
                 "u8",
-                32
+                23
               ]
             }
           }
         ]
       }
     },
+    {
+      "name": "FundableReward",
+      "docs": [
+        "which of the farm's rewards a funder may fund"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Any"
+          },
+          {
+            "name": "RewardA"
+          },
+          {
+            "name": "RewardB"
+          }
+        ]
+      }
+    },
     {
       "name": "FarmerState",
       "type": {
@@ -4049,6 +12475,23 @@ export const IDL: GemFarm = {
         ]
       }
     },
+    {
+      "name": "PenaltyDestination",
+      "docs": [
+        "where rewards forfeited by an early unstake go"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "RewardPool"
+          },
+          {
+            "name": "Treasury"
+          }
+        ]
+      }
+    },
     {
       "name": "RewardType",
       "type": {
@@ -4064,21 +12507,176 @@ export const IDL: GemFarm = {
       }
     },
     {
-      "name": "FixedRateRewardTier",
+      "name": "ProposalAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CancelReward",
+            "fields": [
+              {
+                "name": "reward_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "CancelRewardSlot",
+            "fields": [
+              {
+                "name": "reward_slot",
+                "type": "publicKey"
+              },
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "PayoutFromTreasury",
+            "fields": [
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "lamports",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "PayoutTokensFromTreasury",
+            "fields": [
+              {
+                "name": "destination",
+                "type": "publicKey"
+              },
+              {
+                "name": "amount",
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "SetTreasurySplit",
+            "fields": [
+              {
+                "name": "recipients",
+                "type": {
+                  "array": [
+                    {
+                      "defined": "TreasuryRecipient"
+                    },
+                    8
+                  ]
+                }
+              },
+              {
+                "name": "recipient_count",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "ReplaceReward",
+            "fields": [
+              {
+                "name": "old_reward_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "new_reward_mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "receiver",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetFarmManager",
+            "fields": [
+              {
+                "name": "manager",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetManagers",
+            "fields": [
+              {
+                "name": "managers",
+                "type": {
+                  "defined": "Managers"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "CapOverflow",
+      "docs": [
+        "where accrual above a farmer's cap goes"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Redistribute"
+          },
+          {
+            "name": "Refund"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionCurve",
+      "docs": [
+        "how the emission rate evolves over the funded duration",
+        "u8-sized (unlike RewardType) so it can be carved out of VariableRateReward's reserve"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Base"
+            "name": "Constant"
+          },
+          {
+            "name": "Halving"
           },
           {
-            "name": "Tier1"
+            "name": "LinearDecay"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundingMode",
+      "docs": [
+        "what a new funding round does to the running schedule"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Reset"
           },
           {
-            "name": "Tier2"
+            "name": "ExtendAtCurrentRate"
           },
           {
-            "name": "Tier3"
+            "name": "AddToRate"
           }
         ]
       }
@@ -1,14 +1,19 @@
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
 import {
   FarmConfig,
+  feeAccount,
   findFarmerPDA,
+  findProtocolConfigPDA,
   findVaultPDA,
   FixedRateConfig,
   GemFarmClient,
   ITokenData,
+  LockOptions,
+  LoyaltyBoost,
   MaxCounts,
   NodeWallet,
   Numerical,
+  ProtocolFees,
   RarityConfig,
   RewardType,
   stringifyPKsAndBNs,
@@ -59,6 +64,16 @@ export const defaultFixedConfig = <FixedRateConfig>{
   durationSec: new BN(100),
 };
 
+//the fees the program used to hardcode
+export const defaultProtocolFees = <ProtocolFees>{
+  initFarm: new BN(2.5 * LAMPORTS_PER_SOL),
+  initFarmer: new BN(0.01 * LAMPORTS_PER_SOL),
+  enterFarm: new BN(0.012 * LAMPORTS_PER_SOL),
+  stake: new BN(0.002 * LAMPORTS_PER_SOL),
+  unstake: new BN(0.001 * LAMPORTS_PER_SOL),
+  flashDeposit: new BN(0.001 * LAMPORTS_PER_SOL),
+};
+
 // --------------------------------------- tester class
 
 export class GemFarmTester extends GemFarmClient {
@@ -112,6 +127,8 @@ export class GemFarmTester extends GemFarmClient {
     reward = Math.random() < 0.5 ? 'rewardA' : 'rewardB';
     console.log('running tests for', reward);
 
+    await this.prepProtocolConfig();
+
     this.bank = Keypair.generate();
     this.farm = Keypair.generate();
     this.farmManager = await this.nw.createFundedWallet(100 * LAMPORTS_PER_SOL);
@@ -153,6 +170,20 @@ export class GemFarmTester extends GemFarmClient {
     this.gem2PerGemRarity = gem2PerGemRarity;
   }
 
+  //the config is a singleton and all test files share one validator, so only the first caller inits it
+  async prepProtocolConfig() {
+    const [protocolConfig] = await findProtocolConfigPDA();
+    if (await this.conn.getAccountInfo(protocolConfig)) return;
+
+    //anchor deploys the program w/ the provider wallet as upgrade authority
+    await this.initProtocolConfig(
+      this.nw.wallet.publicKey,
+      this.nw.wallet.publicKey,
+      feeAccount,
+      defaultProtocolFees
+    );
+  }
+
   randomInt(min: number, max: number) {
     // min and max included
     return Math.floor(Math.random() * (max - min + 1) + min);
@@ -213,14 +244,20 @@ export class GemFarmTester extends GemFarmClient {
   async callUpdateFarm(
     farmConfig?: FarmConfig,
     newManager?: PublicKey,
-    maxCounts?: MaxCounts
+    maxCounts?: MaxCounts,
+    restakeKeepsTenure?: boolean,
+    loyaltyBoost?: LoyaltyBoost,
+    lockOptions?: LockOptions
   ) {
     return this.updateFarm(
       this.farm.publicKey,
       this.farmManager,
       farmConfig,
       newManager,
-      maxCounts
+      maxCounts,
+      restakeKeepsTenure,
+      loyaltyBoost,
+      lockOptions
     );
   }
 
@@ -259,14 +296,18 @@ export class GemFarmTester extends GemFarmClient {
     return this.initFarmer(this.farm.publicKey, identity, identity);
   }
 
-  async callStake(identity: Keypair) {
-    return this.stake(this.farm.publicKey, identity);
+  async callStake(identity: Keypair, lockOption?: number) {
+    return this.stake(this.farm.publicKey, identity, lockOption);
   }
 
   async callUnstake(identity: Keypair) {
     return this.unstake(this.farm.publicKey, identity);
   }
 
+  async callRestake(identity: Keypair) {
+    return this.restake(this.farm.publicKey, identity);
+  }
+
   async callDeposit(gems: Numerical, identity: Keypair) {
     const isFarmer1 =
       identity.publicKey.toBase58() ===
@@ -355,11 +396,18 @@ export class GemFarmTester extends GemFarmClient {
 
   // ----------------- funder
 
-  async callAuthorize() {
+  async callAuthorize(
+    maxAmount?: Numerical,
+    expiresTs?: Numerical,
+    allowedReward?: any
+  ) {
     return this.authorizeFunder(
       this.farm.publicKey,
       this.farmManager,
-      this.funder.publicKey
+      this.funder.publicKey,
+      maxAmount === undefined ? undefined : toBN(maxAmount),
+      expiresTs === undefined ? undefined : toBN(expiresTs),
+      allowedReward
     );
   }
 
@@ -10,12 +10,26 @@ import { LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
 import {
   FarmConfig,
   feeAccount,
+  LoyaltyBoost,
+  pause,
   RewardType,
   WhitelistType,
 } from '../../../src';
 
 chai.use(chaiAsPromised);
 
+//pads the tiers out to the fixed size the program expects
+function loyaltyBoost(tiers: [number, number][]): LoyaltyBoost {
+  const padded = tiers.map(([requiredTenure, multiplierBps]) => ({
+    requiredTenure: new BN(requiredTenure),
+    multiplierBps: new BN(multiplierBps),
+  }));
+  while (padded.length < 4) {
+    padded.push({ requiredTenure: new BN(0), multiplierBps: new BN(0) });
+  }
+  return { tiers: padded, tierCount: tiers.length };
+}
+
 const updatedFarmConfig = <FarmConfig>{
   minStakingPeriodSec: new BN(0),
   cooldownPeriodSec: new BN(0),
@@ -68,6 +82,64 @@ describe('misc', () => {
     );
   });
 
+  it('lets farmers keep their tenure when restaking', async () => {
+    await gf.callUpdateFarm(undefined, undefined, undefined, true);
+
+    const farmAcc = await gf.fetchFarm();
+    assert.isTrue(farmAcc.restakeKeepsTenure);
+
+    // the rest of the config is untouched
+    assert.equal(
+      farmAcc.config.unstakingFeeLamp.toNumber(),
+      LAMPORTS_PER_SOL / 2
+    );
+  });
+
+  it('sets and clears a loyalty boost', async () => {
+    await gf.callUpdateFarm(
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      loyaltyBoost([
+        [100, 15_000],
+        [1000, 20_000],
+      ])
+    );
+
+    let farmAcc = await gf.fetchFarm();
+    assert.equal(farmAcc.loyaltyBoost.tierCount, 2);
+    assert.equal(farmAcc.loyaltyBoost.tiers[1].requiredTenure.toNumber(), 1000);
+    assert.equal(farmAcc.loyaltyBoost.tiers[1].multiplierBps.toNumber(), 20_000);
+
+    //clear it again so the rest of the suite runs unboosted
+    await gf.callUpdateFarm(
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      loyaltyBoost([])
+    );
+
+    farmAcc = await gf.fetchFarm();
+    assert.equal(farmAcc.loyaltyBoost.tierCount, 0);
+  });
+
+  it('FAILS to set a loyalty boost with decreasing multipliers', async () => {
+    await expect(
+      gf.callUpdateFarm(
+        undefined,
+        undefined,
+        undefined,
+        undefined,
+        loyaltyBoost([
+          [100, 20_000],
+          [1000, 15_000],
+        ])
+      )
+    ).to.be.rejectedWith('InvalidLoyaltyBoost');
+  });
+
   // it('fails to double init an existing farm', async () => {
   //   await expect(
   //     gf.callInitFarm(defaultFarmConfig, RewardType.Fixed)
@@ -101,6 +173,28 @@ describe('misc', () => {
     ).to.be.rejectedWith('Signature verification failed');
   });
 
+  it('keeps tenure on restake only while the farm allows it', async () => {
+    await gf.callDeposit(gf.gem2Amount, gf.farmer2Identity);
+    const { farmer } = await gf.callStake(gf.farmer2Identity);
+    const { beginStakingTs } = await gf.fetchFarmerAcc(farmer);
+
+    //restakeKeepsTenure was turned on above
+    await pause(2000);
+    await gf.callUnstake(gf.farmer2Identity);
+    await gf.callRestake(gf.farmer2Identity);
+
+    let farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert(farmerAcc.beginStakingTs.eq(beginStakingTs));
+
+    await gf.callUpdateFarm(undefined, undefined, undefined, false);
+    await pause(2000);
+    await gf.callUnstake(gf.farmer2Identity);
+    await gf.callRestake(gf.farmer2Identity);
+
+    farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert(farmerAcc.beginStakingTs.gt(beginStakingTs));
+  });
+
   // --------------------------------------- whitelisting
 
   it('whitelists a creator', async () => {
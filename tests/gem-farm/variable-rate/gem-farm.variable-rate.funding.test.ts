@@ -6,7 +6,7 @@ import {
   GemFarmTester,
 } from '../gem-farm.tester';
 import { BN } from '@project-serum/anchor';
-import { pause, VariableRateConfig } from '../../../src';
+import { FundableReward, pause, VariableRateConfig } from '../../../src';
 
 chai.use(chaiAsPromised);
 
@@ -261,4 +261,33 @@ describe('funding (variable rate)', () => {
     await gf.verifyFunderAccContains(0);
     await gf.verifyPotContains(pot, 20000);
   });
+
+  it('FAILS to fund past the limits of a funder', async () => {
+    //cap below the funding amount
+    await gf.callAuthorize(5000);
+    await expect(gf.callFundReward(defaultVariableConfig)).to.be.rejectedWith(
+      'FundingCapExceeded'
+    );
+
+    //already expired
+    await gf.callAuthorize(0, 1);
+    await expect(gf.callFundReward(defaultVariableConfig)).to.be.rejectedWith(
+      'FunderExpired'
+    );
+
+    //only allowed to fund the other reward
+    await gf.callAuthorize(
+      0,
+      0,
+      gf.reward === 'rewardA' ? FundableReward.RewardB : FundableReward.RewardA
+    );
+    await expect(gf.callFundReward(defaultVariableConfig)).to.be.rejectedWith(
+      'FunderNotAllowedReward'
+    );
+
+    //re-authorizing w/o limits lifts them
+    await gf.callAuthorize();
+    const { pot } = await gf.callFundReward(defaultVariableConfig);
+    await gf.verifyPotContains(pot, 10000);
+  });
 });
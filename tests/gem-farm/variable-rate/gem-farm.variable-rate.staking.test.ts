@@ -1,14 +1,24 @@
-import chai, { assert } from 'chai';
+import chai, { assert, expect } from 'chai';
 import chaiAsPromised from 'chai-as-promised';
 import {
   defaultFarmConfig,
   defaultVariableConfig,
   GemFarmTester,
 } from '../gem-farm.tester';
-import { pause } from '../../../src';
+import { LockOptions, pause } from '../../../src';
+import { BN } from '@project-serum/anchor';
 
 chai.use(chaiAsPromised);
 
+//a single 100s lock that doubles the farmer's points, padded to MAX_LOCK_OPTIONS
+const lockOptions = <LockOptions>{
+  options: [
+    { lockSec: new BN(100), multiplierBps: new BN(20_000) },
+    ...Array(3).fill({ lockSec: new BN(0), multiplierBps: new BN(0) }),
+  ],
+  optionCount: 1,
+};
+
 describe('staking (variable rate)', () => {
   let gf = new GemFarmTester();
 
@@ -50,6 +60,36 @@ describe('staking (variable rate)', () => {
     await gf.verifyClaimedReward(gf.farmer2Identity);
   });
 
+  it('stakes with a lock option', async () => {
+    await gf.callUpdateFarm(
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      undefined,
+      lockOptions
+    );
+
+    //only option 0 exists
+    await expect(gf.callStake(gf.farmer1Identity, 1)).to.be.rejectedWith(
+      'InvalidLockOptions'
+    );
+
+    const { farmer } = await gf.callStake(gf.farmer1Identity, 0);
+
+    const farmerAcc = await gf.fetchFarmerAcc(farmer);
+    assert.equal(farmerAcc.lockPeriodSec.toNumber(), 100);
+    assert.equal(farmerAcc.lockMultiplierBps.toNumber(), 20_000);
+    assert(
+      farmerAcc.minStakingEndsTs.eq(farmerAcc.beginStakingTs.add(new BN(100)))
+    );
+
+    //the lock outlasts the farm's (0s) min staking period
+    await expect(gf.callUnstake(gf.farmer1Identity)).to.be.rejectedWith(
+      'MinStakingNotPassed'
+    );
+  });
+
   it('stakes -> accrues -> unstakes (twice) -> claims (multi farmer)', async () => {
     // ----------------- stake + accrue
     await gf.stakeAndVerify(gf.farmer1Identity);